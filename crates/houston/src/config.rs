@@ -19,6 +19,10 @@ pub struct Config {
     /// override_api_key is used for overriding the API key returned
     /// when loading a profile
     pub override_api_key: Option<String>,
+
+    /// credential_passphrase unlocks API keys kept in the
+    /// [`CredentialStore::EncryptedFile`](crate::CredentialStore::EncryptedFile) store
+    pub credential_passphrase: Option<String>,
 }
 
 impl Config {
@@ -59,9 +63,16 @@ impl Config {
         Ok(Config {
             home,
             override_api_key,
+            credential_passphrase: None,
         })
     }
 
+    /// Sets the passphrase used to encrypt and decrypt API keys
+    pub fn with_credential_passphrase(mut self, credential_passphrase: Option<String>) -> Self {
+        self.credential_passphrase = credential_passphrase;
+        self
+    }
+
     /// Removes all configuration files from filesystem
     pub fn clear(&self) -> Result<(), HoustonProblem> {
         tracing::debug!(home_dir = ?self.home);
@@ -92,6 +103,44 @@ impl Config {
     fn get_elv2_toml_path(&self) -> Utf8PathBuf {
         self.home.join("elv2_license.toml")
     }
+
+    /// Retrieves the settings for anonymous usage data from self.home.join("telemetry.toml"),
+    /// or the defaults if they were never saved
+    pub fn telemetry_settings(&self) -> Result<TelemetrySettings, HoustonProblem> {
+        let toml_path = self.get_telemetry_toml_path();
+        if !toml_path.exists() {
+            return Ok(TelemetrySettings::default());
+        }
+        let contents = Fs::read_file(toml_path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Writes the settings for anonymous usage data to self.home.join("telemetry.toml")
+    pub fn save_telemetry_settings(
+        &self,
+        settings: &TelemetrySettings,
+    ) -> Result<(), HoustonProblem> {
+        let contents = toml::to_string(settings)?;
+        Fs::write_file(self.get_telemetry_toml_path(), contents)?;
+        Ok(())
+    }
+
+    fn get_telemetry_toml_path(&self) -> Utf8PathBuf {
+        self.home.join("telemetry.toml")
+    }
+}
+
+/// Settings for the anonymous usage data Rover reports, which apply to every profile
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetrySettings {
+    /// Whether anonymous usage data has been turned off, as if `APOLLO_TELEMETRY_DISABLED` were
+    /// always set
+    #[serde(default)]
+    pub disabled: bool,
+
+    /// A file every reported payload is appended to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log: Option<Utf8PathBuf>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -106,7 +155,8 @@ mod tests {
     use assert_fs::TempDir;
     use camino::Utf8PathBuf;
 
-    use super::Config;
+    use super::{Config, TelemetrySettings};
+
     #[test]
     fn it_can_clear_global_config() {
         let tmp_home = TempDir::new().unwrap();
@@ -116,4 +166,32 @@ mod tests {
         config.clear().unwrap();
         assert!(!config.home.exists());
     }
+
+    #[test]
+    fn it_can_save_telemetry_settings() {
+        let tmp_home = TempDir::new().unwrap();
+        let tmp_path = Utf8PathBuf::try_from(tmp_home.path().to_path_buf()).unwrap();
+        let config = Config::new(Some(&tmp_path), None).unwrap();
+        assert_eq!(
+            config.telemetry_settings().unwrap(),
+            TelemetrySettings::default()
+        );
+
+        let settings = TelemetrySettings {
+            disabled: true,
+            log: Some(tmp_path.join("telemetry.log")),
+        };
+        config.save_telemetry_settings(&settings).unwrap();
+        assert_eq!(config.telemetry_settings().unwrap(), settings);
+    }
+
+    #[test]
+    fn it_errors_on_malformed_telemetry_settings() {
+        let tmp_home = TempDir::new().unwrap();
+        let tmp_path = Utf8PathBuf::try_from(tmp_home.path().to_path_buf()).unwrap();
+        let config = Config::new(Some(&tmp_path), None).unwrap();
+        std::fs::write(tmp_path.join("telemetry.toml"), "disabled = maybe").unwrap();
+
+        assert!(config.telemetry_settings().is_err());
+    }
 }
@@ -0,0 +1,175 @@
+use std::num::NonZeroU32;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use camino::Utf8PathBuf;
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use rover_std::Fs;
+use serde::{Deserialize, Serialize};
+
+use crate::{profile::Profile, Config, HoustonProblem};
+
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const PBKDF2_ITERATIONS: u32 = 600_000;
+
+/// An API key encrypted with AES-256-GCM, using a key derived from the user's passphrase with
+/// PBKDF2-HMAC-SHA256. Stored as toml at
+/// `$APOLLO_CONFIG_HOME/profiles/<profile_name>/.sensitive.enc`.
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedSensitive {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+fn path(profile_name: &str, config: &Config) -> Utf8PathBuf {
+    Profile::dir(profile_name, config).join(".sensitive.enc")
+}
+
+fn passphrase<'a>(profile_name: &str, config: &'a Config) -> Result<&'a str, HoustonProblem> {
+    config
+        .credential_passphrase
+        .as_deref()
+        .ok_or_else(|| HoustonProblem::MissingCredentialPassphrase(profile_name.to_string()))
+}
+
+fn key(passphrase: &str, salt: &[u8]) -> LessSafeKey {
+    let mut key = [0u8; KEY_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).expect("iterations are non-zero"),
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    LessSafeKey::new(UnboundKey::new(&AES_256_GCM, &key).expect("key is the right length"))
+}
+
+/// Encrypts and saves the API key for a profile.
+pub(crate) fn save(
+    profile_name: &str,
+    config: &Config,
+    api_key: &str,
+) -> Result<(), HoustonProblem> {
+    let passphrase = passphrase(profile_name, config)?;
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .and_then(|()| rng.fill(&mut nonce))
+        .map_err(|_| anyhow::anyhow!("Could not generate random bytes to encrypt the API key"))?;
+
+    let mut ciphertext = api_key.as_bytes().to_vec();
+    key(passphrase, &salt)
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(profile_name.as_bytes()),
+            &mut ciphertext,
+        )
+        .map_err(|_| anyhow::anyhow!("Could not encrypt the API key"))?;
+
+    let encrypted = EncryptedSensitive {
+        salt: STANDARD.encode(salt),
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    };
+    let path = path(profile_name, config);
+    if let Some(dirs) = &path.parent() {
+        Fs::create_dir_all(dirs)?;
+    }
+    Fs::write_file(&path, toml::to_string(&encrypted)?)?;
+    tracing::debug!(path = ?path, "saved encrypted API key");
+    Ok(())
+}
+
+/// Loads and decrypts the API key for a profile, or `None` if it doesn't have one.
+pub(crate) fn load(profile_name: &str, config: &Config) -> Result<Option<String>, HoustonProblem> {
+    let path = path(profile_name, config);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let passphrase = passphrase(profile_name, config)?;
+    let encrypted: EncryptedSensitive = toml::from_str(&Fs::read_file(&path)?)?;
+
+    let corrupt = || HoustonProblem::CorruptedProfile(profile_name.to_string());
+    let salt = STANDARD.decode(&encrypted.salt).map_err(|_| corrupt())?;
+    let nonce: [u8; NONCE_LEN] = STANDARD
+        .decode(&encrypted.nonce)
+        .ok()
+        .and_then(|nonce| nonce.try_into().ok())
+        .ok_or_else(corrupt)?;
+    let mut ciphertext = STANDARD
+        .decode(&encrypted.ciphertext)
+        .map_err(|_| corrupt())?;
+
+    let api_key = key(passphrase, &salt)
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(profile_name.as_bytes()),
+            &mut ciphertext,
+        )
+        .map_err(|_| HoustonProblem::CredentialDecryption(profile_name.to_string()))?;
+    String::from_utf8(api_key.to_vec())
+        .map(Some)
+        .map_err(|_| corrupt())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use camino::Utf8Path;
+
+    use super::{load, path, save};
+    use crate::{Config, HoustonProblem};
+
+    fn config(tmp_home: &TempDir, passphrase: Option<&str>) -> Config {
+        Config::new(Some(&Utf8Path::from_path(tmp_home.path()).unwrap()), None)
+            .unwrap()
+            .with_credential_passphrase(passphrase.map(str::to_string))
+    }
+
+    #[test]
+    fn it_can_save_and_load_an_api_key() {
+        let tmp_home = TempDir::new().unwrap();
+        let config = config(&tmp_home, Some("correct horse battery staple"));
+
+        save("default", &config, "user:gh.foo:key").unwrap();
+        let contents = std::fs::read_to_string(path("default", &config)).unwrap();
+        assert!(!contents.contains("user:gh.foo:key"));
+        assert_eq!(
+            load("default", &config).unwrap(),
+            Some("user:gh.foo:key".to_string())
+        );
+    }
+
+    #[test]
+    fn it_rejects_the_wrong_passphrase() {
+        let tmp_home = TempDir::new().unwrap();
+        save(
+            "default",
+            &config(&tmp_home, Some("correct horse battery staple")),
+            "user:gh.foo:key",
+        )
+        .unwrap();
+
+        let result = load("default", &config(&tmp_home, Some("hunter2")));
+        assert!(matches!(
+            result,
+            Err(HoustonProblem::CredentialDecryption(_))
+        ));
+    }
+
+    #[test]
+    fn it_requires_a_passphrase() {
+        let tmp_home = TempDir::new().unwrap();
+        let result = save("default", &config(&tmp_home, None), "user:gh.foo:key");
+        assert!(matches!(
+            result,
+            Err(HoustonProblem::MissingCredentialPassphrase(_))
+        ));
+    }
+}
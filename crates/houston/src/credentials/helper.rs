@@ -0,0 +1,153 @@
+use std::process::Command;
+
+use super::run_with_input;
+use crate::HoustonProblem;
+
+const STORE_NAME: &str = "credential helper";
+
+/// An external program that stores API keys, following the same conventions as git's
+/// `credential.helper`.
+///
+/// The helper is run with `get`, `store` or `erase` as its last argument, and is sent
+/// `key=value` lines on stdin, ending with a blank line:
+///
+/// ```text
+/// profile=default
+/// api_key=user:gh.foo:xxxx
+/// ```
+///
+/// `api_key` is only sent to `store`. In response to `get`, the helper prints an `api_key=`
+/// line, or nothing if it doesn't have a key for the profile.
+pub(crate) struct CredentialHelper<'a> {
+    helper: &'a str,
+}
+
+impl<'a> CredentialHelper<'a> {
+    pub(crate) const fn new(helper: &'a str) -> Self {
+        Self { helper }
+    }
+
+    /// The shell command to run. Like git, a bare name is taken to be the suffix of a
+    /// `rover-credential-` program.
+    fn command_line(&self, action: &str) -> String {
+        let helper = self.helper.trim();
+        if helper.contains(|c: char| c.is_whitespace() || c == '/' || c == '\\') {
+            format!("{helper} {action}")
+        } else {
+            format!("rover-credential-{helper} {action}")
+        }
+    }
+
+    fn run(&self, action: &str, input: &str) -> Result<String, HoustonProblem> {
+        let command_line = self.command_line(action);
+        let command = if cfg!(windows) {
+            let mut command = Command::new("cmd");
+            command.args(["/C", &command_line]);
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.args(["-c", &command_line]);
+            command
+        };
+        let output = run_with_input(command, input, STORE_NAME)?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            Err(HoustonProblem::CredentialStoreUnavailable {
+                store: STORE_NAME.to_string(),
+                reason: format!(
+                    "`{command_line}` failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            })
+        }
+    }
+
+    pub(crate) fn get(&self, profile_name: &str) -> Result<Option<String>, HoustonProblem> {
+        let output = self.run("get", &format!("profile={profile_name}\n\n"))?;
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(key, _)| *key == "api_key")
+            .map(|(_, api_key)| api_key.trim_end().to_string())
+            .filter(|api_key| !api_key.is_empty()))
+    }
+
+    pub(crate) fn store(&self, profile_name: &str, api_key: &str) -> Result<(), HoustonProblem> {
+        self.run(
+            "store",
+            &format!("profile={profile_name}\napi_key={api_key}\n\n"),
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn erase(&self, profile_name: &str) -> Result<(), HoustonProblem> {
+        self.run("erase", &format!("profile={profile_name}\n\n"))?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use assert_fs::TempDir;
+    use camino::Utf8PathBuf;
+
+    use super::CredentialHelper;
+
+    /// A helper that keeps keys in files named after the profile
+    fn stand_in(dir: &TempDir) -> Utf8PathBuf {
+        let dir = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        let script = dir.join("helper.sh");
+        std::fs::write(
+            &script,
+            format!(
+                r#"#!/bin/sh
+while IFS='=' read -r key value; do
+  [ -z "$key" ] && break
+  eval "$key=\$value"
+done
+file="{dir}/key-$profile"
+case "$1" in
+  get) [ -f "$file" ] && printf 'api_key=%s\n' "$(cat "$file")" ;;
+  store) printf '%s' "$api_key" > "$file" ;;
+  erase) rm -f "$file" ;;
+esac
+exit 0
+"#
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[test]
+    fn it_can_store_get_and_erase_keys() {
+        let tmp = TempDir::new().unwrap();
+        let script = stand_in(&tmp);
+        let helper = CredentialHelper::new(script.as_str());
+
+        assert_eq!(helper.get("default").unwrap(), None);
+        helper.store("default", "user:gh.foo:key").unwrap();
+        assert_eq!(
+            helper.get("default").unwrap(),
+            Some("user:gh.foo:key".to_string())
+        );
+        helper.erase("default").unwrap();
+        assert_eq!(helper.get("default").unwrap(), None);
+    }
+
+    #[test]
+    fn it_expands_bare_helper_names() {
+        assert_eq!(
+            CredentialHelper::new("vault").command_line("get"),
+            "rover-credential-vault get"
+        );
+        assert_eq!(
+            CredentialHelper::new("/opt/keys --verbose").command_line("get"),
+            "/opt/keys --verbose get"
+        );
+    }
+}
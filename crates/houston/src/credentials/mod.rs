@@ -0,0 +1,244 @@
+mod encrypted_file;
+mod helper;
+mod secret_tool;
+
+use std::{
+    fmt,
+    io::{self, Write},
+    process::{Command, Output, Stdio},
+};
+
+use camino::Utf8PathBuf;
+use rover_std::Fs;
+use serde::{Deserialize, Serialize};
+
+use crate::{profile::Sensitive, Config, CredentialOrigin, HoustonProblem};
+
+/// Where API keys are kept, chosen with `rover config auth` and saved to
+/// `$APOLLO_CONFIG_HOME/credentials.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "store", rename_all = "kebab-case")]
+pub enum CredentialStore {
+    /// Plaintext TOML at `$APOLLO_CONFIG_HOME/profiles/<profile_name>/.sensitive`.
+    #[default]
+    File,
+
+    /// The desktop keyring, through libsecret's `secret-tool` program, which has to be
+    /// installed separately.
+    SecretTool {
+        /// The `secret-tool` compatible program to run. Defaults to `secret-tool`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        program: Option<String>,
+    },
+
+    /// A file in the profile directory, encrypted with a passphrase.
+    EncryptedFile,
+
+    /// An external program speaking the credential-helper protocol, like git's
+    /// `credential.helper`.
+    Helper {
+        /// The helper command. A bare name like `foo` runs `rover-credential-foo`.
+        helper: String,
+    },
+}
+
+impl CredentialStore {
+    fn path(config: &Config) -> Utf8PathBuf {
+        config.home.join("credentials.toml")
+    }
+
+    /// Loads the configured credential store, falling back to [`CredentialStore::File`] if
+    /// none was configured.
+    pub fn load(config: &Config) -> Result<CredentialStore, HoustonProblem> {
+        let path = CredentialStore::path(config);
+        if !path.exists() {
+            return Ok(CredentialStore::default());
+        }
+        let contents = Fs::read_file(&path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Saves this credential store as the one to use from now on.
+    pub fn save(&self, config: &Config) -> Result<(), HoustonProblem> {
+        let contents = toml::to_string(self)?;
+        Fs::write_file(CredentialStore::path(config), contents)?;
+        Ok(())
+    }
+
+    /// Reads the API key for a profile, or `None` if this store doesn't have one.
+    pub(crate) fn get(
+        &self,
+        profile_name: &str,
+        config: &Config,
+    ) -> Result<Option<String>, HoustonProblem> {
+        match self {
+            CredentialStore::File => {
+                if Sensitive::path(profile_name, config).exists() {
+                    Ok(Some(Sensitive::load(profile_name, config)?.api_key))
+                } else {
+                    Ok(None)
+                }
+            }
+            CredentialStore::SecretTool { program } => {
+                secret_tool::SecretTool::new(program.as_deref()).lookup(profile_name)
+            }
+            CredentialStore::EncryptedFile => encrypted_file::load(profile_name, config),
+            CredentialStore::Helper { helper } => {
+                helper::CredentialHelper::new(helper).get(profile_name)
+            }
+        }
+    }
+
+    /// Makes sure the store can be used, by looking up the key for a profile, so a store
+    /// that isn't installed or is misconfigured is reported before it's saved.
+    pub fn check(&self, profile_name: &str) -> Result<(), HoustonProblem> {
+        match self {
+            // Both of these live in the profile directory, which is created when saving
+            CredentialStore::File | CredentialStore::EncryptedFile => Ok(()),
+            CredentialStore::SecretTool { program } => {
+                secret_tool::SecretTool::new(program.as_deref()).lookup(profile_name)?;
+                Ok(())
+            }
+            CredentialStore::Helper { helper } if helper.trim().is_empty() => {
+                Err(HoustonProblem::CredentialStoreUnavailable {
+                    store: self.to_string(),
+                    reason: "no helper was given".to_string(),
+                })
+            }
+            CredentialStore::Helper { helper } => {
+                helper::CredentialHelper::new(helper).get(profile_name)?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Stores the API key for a profile.
+    pub(crate) fn set(
+        &self,
+        profile_name: &str,
+        config: &Config,
+        api_key: &str,
+    ) -> Result<(), HoustonProblem> {
+        match self {
+            CredentialStore::File => Sensitive {
+                api_key: api_key.to_string(),
+            }
+            .save(profile_name, config),
+            CredentialStore::SecretTool { program } => {
+                secret_tool::SecretTool::new(program.as_deref()).store(profile_name, api_key)
+            }
+            CredentialStore::EncryptedFile => encrypted_file::save(profile_name, config, api_key),
+            CredentialStore::Helper { helper } => {
+                helper::CredentialHelper::new(helper).store(profile_name, api_key)
+            }
+        }
+    }
+
+    /// Removes the API key for a profile. The profile directory itself is removed separately.
+    pub(crate) fn delete(&self, profile_name: &str) -> Result<(), HoustonProblem> {
+        match self {
+            // Both of these live in the profile directory
+            CredentialStore::File | CredentialStore::EncryptedFile => Ok(()),
+            CredentialStore::SecretTool { program } => {
+                secret_tool::SecretTool::new(program.as_deref()).clear(profile_name)
+            }
+            CredentialStore::Helper { helper } => {
+                helper::CredentialHelper::new(helper).erase(profile_name)
+            }
+        }
+    }
+
+    /// Where a credential read from this store came from.
+    pub(crate) fn origin(&self, profile_name: &str) -> CredentialOrigin {
+        let profile_name = profile_name.to_string();
+        match self {
+            CredentialStore::File => CredentialOrigin::ConfigFile(profile_name),
+            CredentialStore::SecretTool { .. } => CredentialOrigin::SecretTool(profile_name),
+            CredentialStore::EncryptedFile => CredentialOrigin::EncryptedFile(profile_name),
+            CredentialStore::Helper { .. } => CredentialOrigin::CredentialHelper(profile_name),
+        }
+    }
+}
+
+/// Runs a credential program, writing `input` to its stdin and collecting its output.
+fn run_with_input(
+    mut command: Command,
+    input: &str,
+    store: &str,
+) -> Result<Output, HoustonProblem> {
+    let unavailable = |reason: String| HoustonProblem::CredentialStoreUnavailable {
+        store: store.to_string(),
+        reason,
+    };
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => unavailable(format!(
+                "`{}` is not installed, or isn't on your PATH",
+                command.get_program().to_string_lossy()
+            )),
+            _ => unavailable(format!("could not run {command:?}: {err}")),
+        })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|err| unavailable(format!("could not write to {command:?}: {err}")))?;
+    }
+    child
+        .wait_with_output()
+        .map_err(|err| unavailable(format!("{command:?} failed: {err}")))
+}
+
+impl fmt::Display for CredentialStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialStore::File => write!(f, "file"),
+            CredentialStore::SecretTool { .. } => write!(f, "secret-tool"),
+            CredentialStore::EncryptedFile => write!(f, "encrypted file"),
+            CredentialStore::Helper { helper } => write!(f, "credential helper `{helper}`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use camino::Utf8Path;
+
+    use super::CredentialStore;
+    use crate::Config;
+
+    #[test]
+    fn it_defaults_to_the_file_store() {
+        let tmp_home = TempDir::new().unwrap();
+        let config =
+            Config::new(Some(&Utf8Path::from_path(tmp_home.path()).unwrap()), None).unwrap();
+        assert_eq!(
+            CredentialStore::load(&config).unwrap(),
+            CredentialStore::File
+        );
+    }
+
+    #[test]
+    fn it_can_save_and_load_the_store() {
+        let tmp_home = TempDir::new().unwrap();
+        let config =
+            Config::new(Some(&Utf8Path::from_path(tmp_home.path()).unwrap()), None).unwrap();
+        let store = CredentialStore::Helper {
+            helper: "/usr/local/bin/keys".to_string(),
+        };
+        store.save(&config).unwrap();
+        assert_eq!(CredentialStore::load(&config).unwrap(), store);
+    }
+
+    #[test]
+    fn it_rejects_helpers_that_cannot_run() {
+        let store = CredentialStore::Helper {
+            helper: "/nonexistent/rover-credential-keys".to_string(),
+        };
+        assert!(store.check("default").is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::process::Command;
+
+use super::run_with_input;
+use crate::HoustonProblem;
+
+const DEFAULT_PROGRAM: &str = "secret-tool";
+const STORE_NAME: &str = "secret-tool";
+
+/// The attribute every Rover secret is stored under, alongside the profile name
+const SERVICE_ATTRIBUTE: &str = "apollo-rover";
+
+/// Keeps API keys in the desktop keyring (GNOME Keyring, KWallet, KeePassXC, ...) by running
+/// libsecret's `secret-tool` command-line program, or any program with the same interface.
+/// Rover doesn't talk to the Secret Service over D-Bus itself, so `secret-tool` has to be
+/// installed, e.g. from the `libsecret-tools` package on Debian and Ubuntu.
+pub(crate) struct SecretTool<'a> {
+    program: &'a str,
+}
+
+impl<'a> SecretTool<'a> {
+    pub(crate) fn new(program: Option<&'a str>) -> Self {
+        Self {
+            program: program.unwrap_or(DEFAULT_PROGRAM),
+        }
+    }
+
+    fn command(&self, action: &str, profile_name: &str) -> Command {
+        let mut command = Command::new(self.program);
+        command.arg(action);
+        if action == "store" {
+            command.arg(format!("--label=Apollo Rover API key ({profile_name})"));
+        }
+        command.args(["service", SERVICE_ATTRIBUTE, "profile", profile_name]);
+        command
+    }
+
+    fn failed(&self, action: &str, stderr: &[u8]) -> HoustonProblem {
+        HoustonProblem::CredentialStoreUnavailable {
+            store: STORE_NAME.to_string(),
+            reason: format!(
+                "`{} {action}` failed: {}",
+                self.program,
+                String::from_utf8_lossy(stderr).trim()
+            ),
+        }
+    }
+
+    pub(crate) fn lookup(&self, profile_name: &str) -> Result<Option<String>, HoustonProblem> {
+        let output = run_with_input(self.command("lookup", profile_name), "", STORE_NAME)?;
+        // `secret-tool lookup` exits with status 1 and prints nothing when there's no such secret
+        if !output.status.success() {
+            return if output.stderr.is_empty() {
+                Ok(None)
+            } else {
+                Err(self.failed("lookup", &output.stderr))
+            };
+        }
+        let api_key = String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string();
+        Ok(Some(api_key).filter(|api_key| !api_key.is_empty()))
+    }
+
+    pub(crate) fn store(&self, profile_name: &str, api_key: &str) -> Result<(), HoustonProblem> {
+        let output = run_with_input(self.command("store", profile_name), api_key, STORE_NAME)?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(self.failed("store", &output.stderr))
+        }
+    }
+
+    pub(crate) fn clear(&self, profile_name: &str) -> Result<(), HoustonProblem> {
+        let output = run_with_input(self.command("clear", profile_name), "", STORE_NAME)?;
+        if output.status.success() || output.stderr.is_empty() {
+            Ok(())
+        } else {
+            Err(self.failed("clear", &output.stderr))
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use assert_fs::TempDir;
+    use camino::Utf8PathBuf;
+
+    use super::SecretTool;
+
+    /// A stand-in for `secret-tool` that keeps secrets in files named after the profile
+    fn stand_in(dir: &TempDir) -> Utf8PathBuf {
+        let dir = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        let script = dir.join("secret-tool");
+        std::fs::write(
+            &script,
+            format!(
+                r#"#!/bin/sh
+action="$1"
+[ "$action" = "store" ] && shift
+profile="$5"
+file="{dir}/secret-$profile"
+case "$action" in
+  store) cat > "$file" ;;
+  lookup) [ -f "$file" ] || exit 1; cat "$file" ;;
+  clear) rm -f "$file" ;;
+esac
+"#
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[test]
+    fn it_can_store_look_up_and_clear_secrets() {
+        let tmp = TempDir::new().unwrap();
+        let program = stand_in(&tmp);
+        let secret_tool = SecretTool::new(Some(program.as_str()));
+
+        assert_eq!(secret_tool.lookup("default").unwrap(), None);
+        secret_tool.store("default", "user:gh.foo:key").unwrap();
+        assert_eq!(
+            secret_tool.lookup("default").unwrap(),
+            Some("user:gh.foo:key".to_string())
+        );
+        secret_tool.clear("default").unwrap();
+        assert_eq!(secret_tool.lookup("default").unwrap(), None);
+    }
+
+    #[test]
+    fn it_says_when_secret_tool_is_not_installed() {
+        let err = SecretTool::new(Some("/nonexistent/secret-tool"))
+            .lookup("default")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Could not use the secret-tool credential store: `/nonexistent/secret-tool` is not installed, or isn't on your PATH"
+        );
+    }
+}
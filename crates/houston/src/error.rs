@@ -38,6 +38,38 @@ pub enum HoustonProblem {
     #[error("The API key associated with profile '{0}' is corrupt.")]
     CorruptedProfile(String),
 
+    /// UnknownProfileSetting occurs when reading or writing a profile setting that doesn't exist.
+    #[error("There is no profile setting named '{0}'.")]
+    UnknownProfileSetting(String),
+
+    /// InvalidProfileSetting occurs when a profile setting is given a value of the wrong type.
+    #[error("'{value}' is not a valid value for '{key}': {reason}.")]
+    InvalidProfileSetting {
+        /// The setting's name
+        key: String,
+        /// The rejected value
+        value: String,
+        /// Why the value was rejected
+        reason: String,
+    },
+
+    /// CredentialStoreUnavailable occurs when the configured credential store can't be used.
+    #[error("Could not use the {store} credential store: {reason}")]
+    CredentialStoreUnavailable {
+        /// The credential store that failed
+        store: String,
+        /// Why it failed
+        reason: String,
+    },
+
+    /// MissingCredentialPassphrase occurs when an encrypted API key is used without a passphrase.
+    #[error("The API key for profile '{0}' is encrypted, and no passphrase was provided.")]
+    MissingCredentialPassphrase(String),
+
+    /// CredentialDecryption occurs when an encrypted API key can't be decrypted with the passphrase.
+    #[error("Could not decrypt the API key for profile '{0}'. Is the passphrase correct?")]
+    CredentialDecryption(String),
+
     /// PathNotUtf8 occurs when Houston encounters a file path that is not valid UTF-8
     #[error(transparent)]
     PathNotUtf8(#[from] camino::FromPathBufError),
@@ -3,11 +3,13 @@
 //! Utilities for configuring the rover CLI tool.
 
 mod config;
+mod credentials;
 mod error;
 mod profile;
 
-pub use config::Config;
+pub use config::{Config, TelemetrySettings};
+pub use credentials::CredentialStore;
 pub use error::HoustonProblem;
 pub use profile::mask_key;
 /// Utilities for saving, loading, and deleting configuration profiles.
-pub use profile::{Credential, CredentialOrigin, LoadOpts, Profile};
+pub use profile::{Credential, CredentialOrigin, LoadOpts, Profile, ProfileData, ProfileSettings};
@@ -1,13 +1,15 @@
 mod sensitive;
+mod settings;
 
 use std::fmt;
 
 use camino::Utf8PathBuf as PathBuf;
 use rover_std::Fs;
-use sensitive::Sensitive;
+pub(crate) use sensitive::Sensitive;
 use serde::{Deserialize, Serialize};
+pub use settings::ProfileSettings;
 
-use crate::{Config, HoustonProblem};
+use crate::{Config, CredentialStore, HoustonProblem};
 
 /// Collects configuration related to a profile.
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,6 +27,9 @@ pub struct LoadOpts {
 pub struct ProfileData {
     /// Apollo API Key
     pub api_key: Option<String>,
+
+    /// Non-sensitive defaults for the profile
+    pub settings: Option<ProfileSettings>,
 }
 
 /// Struct containing info about an API Key
@@ -45,6 +50,15 @@ pub enum CredentialOrigin {
 
     /// The credential is from a profile
     ConfigFile(String),
+
+    /// The credential is from a profile, kept in the desktop keyring through `secret-tool`
+    SecretTool(String),
+
+    /// The credential is from a profile, kept in a passphrase-encrypted file
+    EncryptedFile(String),
+
+    /// The credential is from a profile, kept by an external credential helper
+    CredentialHelper(String),
 }
 
 impl Profile {
@@ -52,14 +66,16 @@ impl Profile {
         config.home.join("profiles")
     }
 
-    fn dir(name: &str, config: &Config) -> PathBuf {
+    pub(crate) fn dir(name: &str, config: &Config) -> PathBuf {
         Profile::base_dir(config).join(name)
     }
 
-    /// Writes an api_key to the filesystem (`$APOLLO_CONFIG_HOME/profiles/<profile_name>/.sensitive`).
+    /// Writes an api_key to the configured [`CredentialStore`], which by default is the
+    /// filesystem (`$APOLLO_CONFIG_HOME/profiles/<profile_name>/.sensitive`).
     pub fn set_api_key(name: &str, config: &Config, api_key: &str) -> Result<(), HoustonProblem> {
         let data = ProfileData {
             api_key: Some(api_key.to_string()),
+            settings: None,
         };
         Profile::save(name, config, data)?;
         Ok(())
@@ -68,7 +84,7 @@ impl Profile {
     /// Returns an API key for interacting with Apollo services.
     ///
     /// Checks for the presence of an `APOLLO_KEY` env var, and returns its value
-    /// if it finds it. Otherwise looks for credentials on the file system.
+    /// if it finds it. Otherwise looks for credentials in the configured [`CredentialStore`].
     ///
     /// Takes an optional `profile` argument. Defaults to `"default"`.
     pub fn get_credential(name: &str, config: &Config) -> Result<Credential, HoustonProblem> {
@@ -79,10 +95,10 @@ impl Profile {
             },
             None => {
                 let opts = LoadOpts { sensitive: true };
-                let profile = Profile::load(name, config, opts)?;
+                let (profile, origin) = Profile::load(name, config, opts)?;
                 Credential {
                     api_key: profile.sensitive.api_key,
-                    origin: CredentialOrigin::ConfigFile(name.to_string()),
+                    origin,
                 }
             }
         };
@@ -92,26 +108,53 @@ impl Profile {
         Ok(credential)
     }
 
+    /// Returns the non-sensitive settings for a profile, which are empty if none were saved.
+    pub fn get_settings(name: &str, config: &Config) -> Result<ProfileSettings, HoustonProblem> {
+        ProfileSettings::load(name, config)
+    }
+
     /// Saves configuration options for a specific profile to the file system,
-    /// splitting sensitive information into a separate file.
+    /// handing sensitive information to the configured [`CredentialStore`].
     pub fn save(name: &str, config: &Config, data: ProfileData) -> Result<(), HoustonProblem> {
+        if let Some(settings) = data.settings {
+            settings.save(name, config)?;
+        }
         if let Some(api_key) = data.api_key {
-            Sensitive { api_key }.save(name, config)?;
+            let store = CredentialStore::load(config)?;
+            store.set(name, config, &api_key)?;
+            if store != CredentialStore::File {
+                // the profile directory is how we know a profile exists, even when its
+                // key is kept elsewhere, and a stale plaintext key shouldn't outlive the switch
+                Fs::create_dir_all(Profile::dir(name, config))?;
+                let legacy = Sensitive::path(name, config);
+                if legacy.exists() {
+                    Fs::remove_file(&legacy)?;
+                }
+            }
         }
         Ok(())
     }
 
     /// Loads and deserializes configuration from the file system for a
-    /// specific profile.
+    /// specific profile, along with where its credential came from.
     fn load(
         profile_name: &str,
         config: &Config,
         opts: LoadOpts,
-    ) -> Result<Profile, HoustonProblem> {
+    ) -> Result<(Profile, CredentialOrigin), HoustonProblem> {
         if Profile::dir(profile_name, config).exists() {
             if opts.sensitive {
-                let sensitive = Sensitive::load(profile_name, config)?;
-                return Ok(Profile { sensitive });
+                let store = CredentialStore::load(config)?;
+                let (sensitive, origin) = match store.get(profile_name, config)? {
+                    Some(api_key) => (Sensitive { api_key }, store.origin(profile_name)),
+                    // a plaintext key saved before switching stores is used until the profile is
+                    // authenticated again
+                    None => (
+                        Sensitive::load(profile_name, config)?,
+                        CredentialOrigin::ConfigFile(profile_name.to_string()),
+                    ),
+                };
+                return Ok((Profile { sensitive }, origin));
             }
             Err(HoustonProblem::NoNonSensitiveConfigFound(
                 profile_name.to_string(),
@@ -131,6 +174,9 @@ impl Profile {
     pub fn delete(name: &str, config: &Config) -> Result<(), HoustonProblem> {
         let dir = Profile::dir(name, config);
         tracing::debug!(dir = ?dir);
+        if let Err(err) = CredentialStore::load(config).and_then(|store| store.delete(name)) {
+            tracing::warn!("could not remove the API key for profile '{name}': {err}");
+        }
         Fs::remove_dir_all(dir)?;
         Ok(())
     }
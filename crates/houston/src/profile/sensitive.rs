@@ -13,7 +13,7 @@ pub struct Sensitive {
 }
 
 impl Sensitive {
-    fn path(profile_name: &str, config: &Config) -> Utf8PathBuf {
+    pub(crate) fn path(profile_name: &str, config: &Config) -> Utf8PathBuf {
         Profile::dir(profile_name, config).join(".sensitive")
     }
 
@@ -0,0 +1,322 @@
+use camino::Utf8PathBuf;
+use rover_std::Fs;
+use serde::{Deserialize, Serialize};
+
+use crate::{profile::Profile, Config, HoustonProblem};
+
+/// Non-sensitive defaults for a profile, used when the matching flag or environment variable
+/// isn't set. Saved as toml at `$APOLLO_CONFIG_HOME/profiles/<profile_name>/settings.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileSettings {
+    /// The GraphOS registry endpoint to talk to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_url: Option<String>,
+
+    /// The graph ref to use when a command's `GRAPH_REF` argument is left off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graph_ref: Option<String>,
+
+    /// The federation version to compose with when `--federation-version` is left off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub federation_version: Option<String>,
+
+    /// The timeout (in seconds) for HTTP(S) requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_timeout: Option<u64>,
+
+    /// Whether to accept invalid certificates when performing HTTPS requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accept_invalid_certs: Option<bool>,
+
+    /// Whether to accept invalid hostnames when performing HTTPS requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accept_invalid_hostnames: Option<bool>,
+
+    /// The proxy to send GraphOS requests through when `--proxy` and `HTTPS_PROXY` are unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// The most times to send a request, including the first attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_max_attempts: Option<u32>,
+
+    /// How long to keep retrying a request, like `90s`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_max_elapsed: Option<String>,
+
+    /// The delay before the first retry, like `500ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_base_delay: Option<String>,
+
+    /// The longest delay between two attempts, like `60s`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_max_delay: Option<String>,
+
+    /// Whether to randomize the delay between attempts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_jitter: Option<bool>,
+
+    /// The status codes and errors to retry, like `5xx,429,timeout`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<String>,
+
+    /// Whether to wait as long as a `Retry-After` response header asks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_honor_retry_after: Option<bool>,
+}
+
+impl ProfileSettings {
+    /// Every setting that can be read or written with [`ProfileSettings::get`] and
+    /// [`ProfileSettings::set`].
+    pub const KEYS: &'static [&'static str] = &[
+        "registry_url",
+        "graph_ref",
+        "federation_version",
+        "client_timeout",
+        "accept_invalid_certs",
+        "accept_invalid_hostnames",
+        "proxy",
+        "retry_max_attempts",
+        "retry_max_elapsed",
+        "retry_base_delay",
+        "retry_max_delay",
+        "retry_jitter",
+        "retry_on",
+        "retry_honor_retry_after",
+    ];
+
+    fn path(profile_name: &str, config: &Config) -> Utf8PathBuf {
+        Profile::dir(profile_name, config).join("settings.toml")
+    }
+
+    /// Serializes to toml and saves to `$APOLLO_CONFIG_HOME/profiles/<profile_name>/settings.toml`,
+    /// removing the file once every setting has been unset.
+    pub(crate) fn save(&self, profile_name: &str, config: &Config) -> Result<(), HoustonProblem> {
+        let path = ProfileSettings::path(profile_name, config);
+        if *self == ProfileSettings::default() {
+            if path.exists() {
+                Fs::remove_file(&path)?;
+            }
+            return Ok(());
+        }
+        if let Some(dirs) = &path.parent() {
+            Fs::create_dir_all(dirs)?;
+        }
+        Fs::write_file(&path, toml::to_string(self)?)?;
+        tracing::debug!(path = ?path);
+        Ok(())
+    }
+
+    /// Opens and deserializes `$APOLLO_CONFIG_HOME/profiles/<profile_name>/settings.toml`,
+    /// returning empty settings if it doesn't exist.
+    pub(crate) fn load(profile_name: &str, config: &Config) -> Result<Self, HoustonProblem> {
+        let path = ProfileSettings::path(profile_name, config);
+        if !path.exists() {
+            return Ok(ProfileSettings::default());
+        }
+        let data = Fs::read_file(&path)?;
+        tracing::debug!(path = ?path);
+        Ok(toml::from_str(&data)?)
+    }
+
+    /// Returns the value of a setting, or `None` if it hasn't been set.
+    pub fn get(&self, key: &str) -> Result<Option<String>, HoustonProblem> {
+        Ok(match key {
+            "registry_url" => self.registry_url.clone(),
+            "graph_ref" => self.graph_ref.clone(),
+            "federation_version" => self.federation_version.clone(),
+            "client_timeout" => self.client_timeout.map(|timeout| timeout.to_string()),
+            "accept_invalid_certs" => self.accept_invalid_certs.map(|value| value.to_string()),
+            "accept_invalid_hostnames" => {
+                self.accept_invalid_hostnames.map(|value| value.to_string())
+            }
+            "proxy" => self.proxy.clone(),
+            "retry_max_attempts" => self.retry_max_attempts.map(|value| value.to_string()),
+            "retry_max_elapsed" => self.retry_max_elapsed.clone(),
+            "retry_base_delay" => self.retry_base_delay.clone(),
+            "retry_max_delay" => self.retry_max_delay.clone(),
+            "retry_jitter" => self.retry_jitter.map(|value| value.to_string()),
+            "retry_on" => self.retry_on.clone(),
+            "retry_honor_retry_after" => {
+                self.retry_honor_retry_after.map(|value| value.to_string())
+            }
+            _ => return Err(HoustonProblem::UnknownProfileSetting(key.to_string())),
+        })
+    }
+
+    /// Sets a setting from its string representation.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), HoustonProblem> {
+        let invalid = |reason: String| HoustonProblem::InvalidProfileSetting {
+            key: key.to_string(),
+            value: value.to_string(),
+            reason,
+        };
+        match key {
+            "registry_url" => self.registry_url = Some(value.to_string()),
+            "graph_ref" => self.graph_ref = Some(value.to_string()),
+            "federation_version" => self.federation_version = Some(value.to_string()),
+            "client_timeout" => {
+                self.client_timeout = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid("expected a number of seconds".to_string()))?,
+                )
+            }
+            "accept_invalid_certs" => {
+                self.accept_invalid_certs = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid("expected `true` or `false`".to_string()))?,
+                )
+            }
+            "accept_invalid_hostnames" => {
+                self.accept_invalid_hostnames = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid("expected `true` or `false`".to_string()))?,
+                )
+            }
+            "proxy" => self.proxy = Some(value.to_string()),
+            "retry_max_attempts" => {
+                self.retry_max_attempts = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|attempts| *attempts > 0)
+                        .ok_or_else(|| invalid("expected a positive number".to_string()))?,
+                )
+            }
+            "retry_max_elapsed" => self.retry_max_elapsed = Some(value.to_string()),
+            "retry_base_delay" => self.retry_base_delay = Some(value.to_string()),
+            "retry_max_delay" => self.retry_max_delay = Some(value.to_string()),
+            "retry_jitter" => {
+                self.retry_jitter = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid("expected `true` or `false`".to_string()))?,
+                )
+            }
+            "retry_on" => self.retry_on = Some(value.to_string()),
+            "retry_honor_retry_after" => {
+                self.retry_honor_retry_after = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid("expected `true` or `false`".to_string()))?,
+                )
+            }
+            _ => return Err(HoustonProblem::UnknownProfileSetting(key.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Removes a setting, so the built-in default is used again.
+    pub fn unset(&mut self, key: &str) -> Result<(), HoustonProblem> {
+        match key {
+            "registry_url" => self.registry_url = None,
+            "graph_ref" => self.graph_ref = None,
+            "federation_version" => self.federation_version = None,
+            "client_timeout" => self.client_timeout = None,
+            "accept_invalid_certs" => self.accept_invalid_certs = None,
+            "accept_invalid_hostnames" => self.accept_invalid_hostnames = None,
+            "proxy" => self.proxy = None,
+            "retry_max_attempts" => self.retry_max_attempts = None,
+            "retry_max_elapsed" => self.retry_max_elapsed = None,
+            "retry_base_delay" => self.retry_base_delay = None,
+            "retry_max_delay" => self.retry_max_delay = None,
+            "retry_jitter" => self.retry_jitter = None,
+            "retry_on" => self.retry_on = None,
+            "retry_honor_retry_after" => self.retry_honor_retry_after = None,
+            _ => return Err(HoustonProblem::UnknownProfileSetting(key.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Returns every setting that has a value, in the order of [`ProfileSettings::KEYS`].
+    pub fn entries(&self) -> Vec<(String, String)> {
+        ProfileSettings::KEYS
+            .iter()
+            .filter_map(|key| {
+                self.get(key)
+                    .ok()
+                    .flatten()
+                    .map(|value| (key.to_string(), value))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use camino::Utf8Path;
+
+    use super::ProfileSettings;
+    use crate::{Config, HoustonProblem};
+
+    #[test]
+    fn it_can_set_and_get_settings() {
+        let mut settings = ProfileSettings::default();
+        settings.set("graph_ref", "my-graph@staging").unwrap();
+        settings.set("client_timeout", "90").unwrap();
+        settings.set("accept_invalid_certs", "true").unwrap();
+        settings.set("proxy", "http://proxy.internal:3128").unwrap();
+
+        assert_eq!(
+            settings.get("graph_ref").unwrap(),
+            Some("my-graph@staging".to_string())
+        );
+        assert_eq!(settings.client_timeout, Some(90));
+        assert_eq!(settings.accept_invalid_certs, Some(true));
+        assert_eq!(settings.get("registry_url").unwrap(), None);
+        assert_eq!(
+            settings.entries(),
+            vec![
+                ("graph_ref".to_string(), "my-graph@staging".to_string()),
+                ("client_timeout".to_string(), "90".to_string()),
+                ("accept_invalid_certs".to_string(), "true".to_string()),
+                (
+                    "proxy".to_string(),
+                    "http://proxy.internal:3128".to_string()
+                ),
+            ]
+        );
+
+        settings.unset("client_timeout").unwrap();
+        assert_eq!(settings.client_timeout, None);
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys_and_invalid_values() {
+        let mut settings = ProfileSettings::default();
+        assert!(matches!(
+            settings.set("api_key", "user:gh.foo:key"),
+            Err(HoustonProblem::UnknownProfileSetting(_))
+        ));
+        assert!(matches!(
+            settings.set("client_timeout", "soon"),
+            Err(HoustonProblem::InvalidProfileSetting { .. })
+        ));
+        assert!(matches!(
+            settings.set("retry_max_attempts", "0"),
+            Err(HoustonProblem::InvalidProfileSetting { .. })
+        ));
+    }
+
+    #[test]
+    fn it_can_save_and_load_settings() {
+        let tmp_home = TempDir::new().unwrap();
+        let config =
+            Config::new(Some(&Utf8Path::from_path(tmp_home.path()).unwrap()), None).unwrap();
+        assert_eq!(
+            ProfileSettings::load("default", &config).unwrap(),
+            ProfileSettings::default()
+        );
+
+        let mut settings = ProfileSettings::default();
+        settings
+            .set("registry_url", "https://graphos.example.com/api/graphql")
+            .unwrap();
+        settings.save("default", &config).unwrap();
+        assert_eq!(ProfileSettings::load("default", &config).unwrap(), settings);
+    }
+}
@@ -1,11 +1,12 @@
-use std::time::{Duration, Instant};
+use std::time::{Instant, SystemTime};
 
-use backon::Retryable;
 use graphql_client::{Error as GraphQLError, GraphQLQuery, Response as GraphQLResponse};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
     Client as ReqwestClient, Response, StatusCode,
 };
+use rover_http::{har, retry, RetryConfig};
+use tower::util::rng::HasherRng;
 
 use crate::error::{EndpointKind, RoverClientError};
 
@@ -15,21 +16,17 @@ pub(crate) const JSON_CONTENT_TYPE: &str = "application/json";
 pub struct GraphQLClient {
     graphql_endpoint: String,
     client: ReqwestClient,
-    retry_period: Duration,
+    retry: RetryConfig,
 }
 
 impl GraphQLClient {
     /// Construct a new [Client] from a `graphql_endpoint`.
     /// This client is used for generic GraphQL requests, such as introspection.
-    pub fn new(
-        graphql_endpoint: &str,
-        client: ReqwestClient,
-        retry_period: Duration,
-    ) -> GraphQLClient {
+    pub fn new(graphql_endpoint: &str, client: ReqwestClient, retry: RetryConfig) -> GraphQLClient {
         GraphQLClient {
             graphql_endpoint: graphql_endpoint.to_string(),
             client,
-            retry_period,
+            retry,
         }
     }
 
@@ -90,102 +87,85 @@ impl GraphQLClient {
         should_retry: bool,
         endpoint_kind: EndpointKind,
     ) -> Result<Response, RoverClientError> {
-        enum BackoffError<E> {
-            Permanent(E),
-            Transient(E),
-        }
-
         tracing::trace!(request_headers = ?header_map);
         tracing::debug!("Request Body: {}", request_body);
-        let graphql_operation = || async {
-            let response = self
+
+        let mut rng = HasherRng::default();
+        let start = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let response = match self
                 .client
                 .post(&self.graphql_endpoint)
                 .headers(header_map.clone())
                 .body(request_body.clone())
-                .send()
-                .await;
-
-            match response {
-                Err(client_error) => {
-                    if client_error.is_timeout() || client_error.is_connect() {
-                        Err(BackoffError::Transient(client_error))
-                    } else if client_error.is_body()
-                        || client_error.is_decode()
-                        || client_error.is_builder()
-                        || client_error.is_redirect()
-                    {
-                        Err(BackoffError::Permanent(client_error))
-                    } else if client_error.is_request() {
-                        if let Some(hyper_error) =
-                            get_source_error_type::<hyper::Error>(&client_error)
-                        {
-                            if hyper_error.is_incomplete_message() {
-                                Err(BackoffError::Transient(client_error))
-                            } else {
-                                Err(BackoffError::Permanent(client_error))
+                .build()
+            {
+                Ok(request) => self.send(request, attempt).await,
+                Err(build_error) => Err(build_error),
+            };
+
+            let (error, retry_after) = match response {
+                Ok(response) => match response.error_for_status_ref() {
+                    Ok(_) => return Ok(response),
+                    Err(status_error) => {
+                        let retry_after = retry::retry_after(response.headers());
+                        if response.status() == StatusCode::BAD_REQUEST {
+                            if let Ok(text) = response.text().await {
+                                tracing::debug!("{}", text);
                             }
-                        } else {
-                            Err(BackoffError::Permanent(client_error))
                         }
-                    } else {
-                        Err(BackoffError::Permanent(client_error))
+                        (status_error, retry_after)
                     }
+                },
+                Err(client_error) => (client_error, None),
+            };
+
+            let delay = if should_retry && self.retry.retry_on.reqwest_error(&error) {
+                self.retry
+                    .next_delay(attempt, start.elapsed(), retry_after, &mut rng)
+            } else {
+                None
+            };
+            match delay {
+                Some(delay) => {
+                    tracing::debug!("retrying request in {:?} (attempt {})", delay, attempt);
+                    tokio::time::sleep(delay).await;
                 }
-                Ok(success) => {
-                    if let Err(status_error) = success.error_for_status_ref() {
-                        if let Some(response_status) = status_error.status() {
-                            if response_status.is_server_error()
-                                || response_status.is_client_error()
-                                || response_status.is_redirection()
-                            {
-                                if matches!(response_status, StatusCode::BAD_REQUEST) {
-                                    if let Ok(text) = success.text().await {
-                                        tracing::debug!("{}", text);
-                                    }
-                                    Err(BackoffError::Permanent(status_error))
-                                } else {
-                                    Err(BackoffError::Transient(status_error))
-                                }
-                            } else {
-                                Err(BackoffError::Permanent(status_error))
-                            }
-                        } else {
-                            Err(BackoffError::Permanent(status_error))
-                        }
-                    } else {
-                        Ok(success)
-                    }
+                None => {
+                    return Err(RoverClientError::SendRequest {
+                        source: error,
+                        endpoint_kind,
+                    })
                 }
             }
-        };
-
-        if should_retry {
-            let backoff_strategy = backon::ExponentialBuilder::default()
-                .with_min_delay(Duration::from_millis(500))
-                .with_max_delay(Duration::from_secs(60))
-                .with_factor(1.5)
-                .without_max_times()
-                .with_jitter();
-
-            let start = Instant::now();
-            graphql_operation
-                .retry(backoff_strategy)
-                .when(|e| {
-                    matches!(e, BackoffError::Transient(_)) && start.elapsed() < self.retry_period
-                })
-                .await
-        } else {
-            graphql_operation().await
         }
-        .map_err(|e| match e {
-            BackoffError::Permanent(reqwest_error) | BackoffError::Transient(reqwest_error) => {
-                RoverClientError::SendRequest {
-                    source: reqwest_error,
-                    endpoint_kind,
-                }
+    }
+
+    /// Sends a request, recording it too when a HAR recorder has been installed.
+    #[tracing::instrument(
+        skip_all,
+        name = "http_request",
+        fields(
+            otel.name = %request.method(),
+            otel.kind = "client",
+            http.request.method = %request.method(),
+            url.full = %request.url(),
+            http.request.resend_count = attempt - 1,
+        )
+    )]
+    async fn send(&self, request: reqwest::Request, attempt: u32) -> reqwest::Result<Response> {
+        match (har::global(), request.try_clone()) {
+            (Some(recorder), Some(recorded)) => {
+                let started = SystemTime::now();
+                let result = self.client.execute(request).await;
+                recorder
+                    .record_reqwest(&recorded, started, attempt, result)
+                    .await
             }
-        })
+            _ => self.client.execute(request).await,
+        }
     }
 
     /// To be used internally or by other implementations of a GraphQL client.
@@ -255,30 +235,21 @@ fn handle_graphql_body_errors(errors: Vec<GraphQLError>) -> Result<(), RoverClie
     }
 }
 
-/// Downcasts the given err source into T.
-fn get_source_error_type<T: std::error::Error + 'static>(
-    err: &dyn std::error::Error,
-) -> Option<&T> {
-    let mut source = err.source();
-
-    while let Some(err) = source {
-        if let Some(hyper_err) = err.downcast_ref::<T>() {
-            return Some(hyper_err);
-        }
-
-        source = err.source();
-    }
-    None
-}
-
 #[cfg(test)]
 mod tests {
-    use std::error::Error;
+    use std::{error::Error, time::Duration};
 
     use httpmock::prelude::*;
 
     use super::*;
 
+    fn retry_config() -> RetryConfig {
+        RetryConfig {
+            max_elapsed: Duration::from_secs(3),
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn it_is_ok_on_empty_errors() {
         let errors = vec![];
@@ -332,8 +303,7 @@ mod tests {
         });
 
         let client = ReqwestClient::new();
-        let graphql_client =
-            GraphQLClient::new(&server.url(success_path), client, Duration::from_secs(3));
+        let graphql_client = GraphQLClient::new(&server.url(success_path), client, retry_config());
 
         let response = graphql_client
             .execute(
@@ -363,7 +333,7 @@ mod tests {
         let graphql_client = GraphQLClient::new(
             &server.url(internal_server_error_path),
             client,
-            Duration::from_secs(3),
+            retry_config(),
         );
 
         let response = graphql_client
@@ -392,7 +362,7 @@ mod tests {
 
         let client = ReqwestClient::new();
         let graphql_client =
-            GraphQLClient::new(&server.url(not_found_path), client, Duration::from_secs(3));
+            GraphQLClient::new(&server.url(not_found_path), client, retry_config());
 
         let response = graphql_client
             .execute(
@@ -426,8 +396,7 @@ mod tests {
             .timeout(Duration::from_secs(1))
             .build()
             .unwrap();
-        let graphql_client =
-            GraphQLClient::new(&server.url(timeout_path), client, Duration::from_secs(3));
+        let graphql_client = GraphQLClient::new(&server.url(timeout_path), client, retry_config());
 
         let response = graphql_client
             .execute(
@@ -447,4 +416,35 @@ mod tests {
         let reqwest_error = error.source().unwrap().source().unwrap();
         assert!(reqwest_error.to_string().contains("operation timed out"));
     }
+
+    #[tokio::test]
+    async fn test_max_attempts() {
+        let server = MockServer::start();
+        let unavailable_path = "/out-to-lunch";
+        let unavailable_mock = server.mock(|when, then| {
+            when.method(POST).path(unavailable_path);
+            then.status(503).body("back in five minutes");
+        });
+
+        let graphql_client = GraphQLClient::new(
+            &server.url(unavailable_path),
+            ReqwestClient::new(),
+            RetryConfig {
+                max_attempts: Some(1),
+                ..retry_config()
+            },
+        );
+
+        let response = graphql_client
+            .execute(
+                "{}".to_string(),
+                &HeaderMap::new(),
+                true,
+                EndpointKind::ApolloStudio,
+            )
+            .await;
+
+        assert_eq!(unavailable_mock.calls(), 1);
+        assert!(response.is_err());
+    }
 }
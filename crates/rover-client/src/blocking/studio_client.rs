@@ -1,4 +1,4 @@
-use std::{str::FromStr, time::Duration};
+use std::str::FromStr;
 
 use graphql_client::GraphQLQuery;
 use houston::{Credential, CredentialOrigin};
@@ -7,7 +7,7 @@ use reqwest::{
     Client as ReqwestClient,
 };
 use rover_graphql::{GraphQLLayer, GraphQLService};
-use rover_http::{retry::RetryPolicy, HttpService, ReqwestService};
+use rover_http::{retry::RetryPolicy, HttpService, ReqwestService, RetryConfig};
 use rover_studio::service::{HttpStudioServiceError, HttpStudioServiceLayer};
 use tower::{retry::RetryLayer, util::BoxCloneServiceLayer, ServiceBuilder, ServiceExt};
 use url::Url;
@@ -42,7 +42,7 @@ pub struct StudioClient {
     reqwest_client: ReqwestClient,
     version: String,
     is_sudo: bool,
-    retry_period: Duration,
+    retry: RetryConfig,
 }
 
 impl StudioClient {
@@ -54,16 +54,16 @@ impl StudioClient {
         version: &str,
         is_sudo: bool,
         client: ReqwestClient,
-        retry_period: Duration,
+        retry: RetryConfig,
     ) -> StudioClient {
         StudioClient {
             credential,
             graphql_endpoint: graphql_endpoint.to_string(),
             reqwest_client: client.clone(),
-            client: GraphQLClient::new(graphql_endpoint, client, retry_period),
+            client: GraphQLClient::new(graphql_endpoint, client, retry.clone()),
             version: version.to_string(),
             is_sudo,
-            retry_period,
+            retry,
         }
     }
 
@@ -143,7 +143,7 @@ impl StudioClient {
                 self.version.to_string(),
                 self.is_sudo,
             )?))
-            .layer(RetryLayer::new(RetryPolicy::new(self.retry_period)))
+            .layer(RetryLayer::new(RetryPolicy::new(self.retry.clone())))
             .service(
                 ReqwestService::builder()
                     .client(self.reqwest_client.clone())
@@ -24,19 +24,14 @@ pub async fn run(
 ) -> Result<ContractDescribeResponse, RoverClientError> {
     let graph_ref = input.graph_ref.clone();
     let response_data = client.post::<ContractDescribeQuery>(input.into()).await?;
-    let root_url = response_data.frontend_url_root.clone();
-    let description = get_description_from_response_data(response_data, graph_ref.clone())?;
-    Ok(ContractDescribeResponse {
-        description,
-        root_url,
-        graph_ref,
-    })
+    get_response_from_response_data(response_data, graph_ref)
 }
 
-fn get_description_from_response_data(
+fn get_response_from_response_data(
     response_data: QueryResponseData,
     graph_ref: GraphRef,
-) -> Result<String, RoverClientError> {
+) -> Result<ContractDescribeResponse, RoverClientError> {
+    let root_url = response_data.frontend_url_root;
     let graph = response_data.graph.ok_or(RoverClientError::GraphNotFound {
         graph_ref: graph_ref.clone(),
     })?;
@@ -45,7 +40,76 @@ fn get_description_from_response_data(
         graph_ref: graph_ref.clone(),
     })?;
 
-    variant
-        .contract_filter_config_description
-        .ok_or(RoverClientError::ExpectedContractVariant { graph_ref })
+    let (Some(description), Some(source_variant)) = (
+        variant.contract_filter_config_description,
+        variant.source_variant,
+    ) else {
+        return Err(RoverClientError::ExpectedContractVariant { graph_ref });
+    };
+    let (include_tags, exclude_tags) = variant
+        .contract_filter_config
+        .map(|filter| (filter.include, filter.exclude))
+        .unwrap_or_default();
+
+    Ok(ContractDescribeResponse {
+        description,
+        source_variant: source_variant.name,
+        include_tags,
+        exclude_tags,
+        root_url,
+        graph_ref,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn get_response_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variant": {
+                    "contractFilterConfigDescription": "Excludes @internal",
+                    "contractFilterConfig": {
+                        "include": [],
+                        "exclude": ["internal"]
+                    },
+                    "sourceVariant": { "name": "current" }
+                }
+            }
+        });
+        let data: QueryResponseData = serde_json::from_value(json_response).unwrap();
+        let graph_ref = GraphRef::new("mygraph", Some("public")).unwrap();
+        let response = get_response_from_response_data(data, graph_ref).unwrap();
+
+        assert_eq!(response.description, "Excludes @internal");
+        assert_eq!(response.source_variant, "current");
+        assert!(response.include_tags.is_empty());
+        assert_eq!(response.exclude_tags, vec!["internal".to_string()]);
+    }
+
+    #[test]
+    fn get_response_from_response_data_errs_on_non_contract() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variant": {
+                    "contractFilterConfigDescription": null,
+                    "contractFilterConfig": null,
+                    "sourceVariant": null
+                }
+            }
+        });
+        let data: QueryResponseData = serde_json::from_value(json_response).unwrap();
+        let graph_ref = GraphRef::new("mygraph", Some("current")).unwrap();
+        let err = get_response_from_response_data(data, graph_ref).unwrap_err();
+        assert!(matches!(
+            err,
+            RoverClientError::ExpectedContractVariant { .. }
+        ));
+    }
 }
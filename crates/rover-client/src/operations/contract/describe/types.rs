@@ -23,6 +23,16 @@ impl From<ContractDescribeInput> for QueryVariables {
 pub struct ContractDescribeResponse {
     pub description: String,
 
+    /// The variant the contract is filtered from
+    #[serde(skip_serializing)]
+    pub source_variant: String,
+
+    #[serde(skip_serializing)]
+    pub include_tags: Vec<String>,
+
+    #[serde(skip_serializing)]
+    pub exclude_tags: Vec<String>,
+
     #[serde(skip_serializing)]
     pub root_url: String,
 
@@ -1,4 +1,4 @@
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use graphql_client::*;
 use rover_studio::types::GraphRef;
@@ -16,8 +16,9 @@ use crate::{
     blocking::StudioClient,
     operations::graph::check_workflow::types::{CheckWorkflowInput, QueryResponseData},
     shared::{
-        CheckWorkflowResponse, CustomCheckResponse, Diagnostic, LintCheckResponse,
-        OperationCheckResponse, SchemaChange, Violation,
+        wait_for_check_workflow, CheckWorkflowResponse, CheckWorkflowSnapshot, CustomCheckResponse,
+        Diagnostic, LintCheckResponse, OperationCheckResponse, ProposedSchema, SchemaChange,
+        Violation, CHECK_WORKFLOW_POLL_INTERVAL,
     },
     RoverClientError,
 };
@@ -43,41 +44,60 @@ pub async fn run(
     input: CheckWorkflowInput,
     client: &StudioClient,
 ) -> Result<CheckWorkflowResponse, RoverClientError> {
-    let graph_ref = input.graph_ref.clone();
-    let mut url: Option<String> = None;
-    let now = Instant::now();
-    loop {
-        let result = client
-            .post::<GraphCheckWorkflowQuery>(input.clone().into())
-            .await;
-        match result {
-            Ok(data) => {
-                let graph = data.clone().graph.ok_or(RoverClientError::GraphNotFound {
-                    graph_ref: graph_ref.clone(),
-                })?;
-                if let Some(check_workflow) = graph.check_workflow {
-                    if !matches!(check_workflow.status, CheckWorkflowStatus::PENDING) {
-                        return get_check_response_from_data(data, graph_ref);
-                    }
-                }
-                url = get_target_url_from_data(data);
-            }
-            Err(e) => {
-                eprintln!(
-                    "error while checking status of check: {e}\nthis error may be transient... retrying"
-                );
-            }
-        }
-        if now.elapsed() > Duration::from_secs(input.checks_timeout_seconds) {
-            return Err(RoverClientError::ChecksTimeoutError { url });
-        }
-        std::thread::sleep(Duration::from_secs(5));
+    wait_for_check_workflow(
+        Duration::from_secs(input.checks_timeout_seconds),
+        CHECK_WORKFLOW_POLL_INTERVAL,
+        || client.post::<GraphCheckWorkflowQuery>(input.clone().into()),
+        |data| snapshot_from_data(data, input.clone()),
+    )
+    .await
+}
+
+/// Fetches the check workflow once, returning its result if it has finished
+/// or a link to it in Studio if it's still running.
+pub async fn snapshot(
+    input: CheckWorkflowInput,
+    client: &StudioClient,
+) -> Result<CheckWorkflowSnapshot, RoverClientError> {
+    let data = client
+        .post::<GraphCheckWorkflowQuery>(input.clone().into())
+        .await?;
+    snapshot_from_data(data, input)
+}
+
+fn snapshot_from_data(
+    data: QueryResponseData,
+    input: CheckWorkflowInput,
+) -> Result<CheckWorkflowSnapshot, RoverClientError> {
+    let graph_ref = input.graph_ref;
+    let check_workflow = data
+        .graph
+        .as_ref()
+        .ok_or(RoverClientError::GraphNotFound {
+            graph_ref: graph_ref.clone(),
+        })?
+        .check_workflow
+        .as_ref()
+        .ok_or_else(|| RoverClientError::AdhocError {
+            msg: format!(
+                "Could not find a check with workflow ID {} for {graph_ref}",
+                input.workflow_id
+            ),
+        })?;
+    if matches!(check_workflow.status, CheckWorkflowStatus::PENDING) {
+        Ok(CheckWorkflowSnapshot::Pending {
+            target_url: get_target_url_from_data(data),
+        })
+    } else {
+        get_check_response_from_data(data, graph_ref, input.proposed_schema)
+            .map(|check_response| CheckWorkflowSnapshot::Completed(Box::new(check_response)))
     }
 }
 
 fn get_check_response_from_data(
     data: QueryResponseData,
     graph_ref: GraphRef,
+    proposed_schema: Option<ProposedSchema>,
 ) -> Result<CheckWorkflowResponse, RoverClientError> {
     let graph = data.graph.ok_or(RoverClientError::GraphNotFound {
         graph_ref: graph_ref.clone(),
@@ -164,6 +184,7 @@ fn get_check_response_from_data(
         ),
         maybe_proposals_response: None,
         maybe_downstream_response: None,
+        maybe_proposed_schema: proposed_schema,
     };
 
     match check_workflow.status {
@@ -204,6 +225,11 @@ fn get_operations_response_from_result(
             let mut changes = Vec::with_capacity(result.changes.len());
             for change in result.changes {
                 changes.push(SchemaChange {
+                    coordinate: SchemaChange::coordinate(
+                        change.parent_node.and_then(|node| node.name),
+                        change.child_node.and_then(|node| node.name),
+                        change.arg_node.and_then(|node| node.name),
+                    ),
                     code: change.code,
                     severity: change.severity.into(),
                     description: change.description,
@@ -299,3 +325,74 @@ fn get_custom_response_from_result(
         None => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const TARGET_URL: &str = "https://studio.apollographql.com/graph/mygraph/checks/1234";
+
+    fn input() -> CheckWorkflowInput {
+        CheckWorkflowInput {
+            graph_ref: GraphRef::new("mygraph", Some("current")).unwrap(),
+            workflow_id: "1234".to_string(),
+            checks_timeout_seconds: 300,
+            proposed_schema: None,
+        }
+    }
+
+    fn data(check_workflow: Value) -> QueryResponseData {
+        serde_json::from_value(json!({ "graph": { "checkWorkflow": check_workflow } })).unwrap()
+    }
+
+    #[test]
+    fn snapshot_from_data_is_pending_with_the_target_url() {
+        let data = data(json!({
+            "status": "PENDING",
+            "tasks": [{
+                "__typename": "OperationsCheckTask",
+                "status": "PENDING",
+                "targetURL": TARGET_URL,
+                "result": null
+            }]
+        }));
+        assert_eq!(
+            snapshot_from_data(data, input()).unwrap(),
+            CheckWorkflowSnapshot::Pending {
+                target_url: Some(TARGET_URL.to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn snapshot_from_data_is_completed_with_the_check_response() {
+        let data = data(json!({
+            "status": "PASSED",
+            "tasks": [{
+                "__typename": "LintCheckTask",
+                "status": "PASSED",
+                "targetURL": TARGET_URL,
+                "result": {
+                    "diagnostics": [],
+                    "stats": { "errorsCount": 0, "warningsCount": 0 }
+                }
+            }]
+        }));
+        let CheckWorkflowSnapshot::Completed(check_response) =
+            snapshot_from_data(data, input()).unwrap()
+        else {
+            panic!("expected a completed check");
+        };
+        let lint_response = check_response.maybe_lint_response.unwrap();
+        assert_eq!(lint_response.target_url, Some(TARGET_URL.to_string()));
+        assert!(lint_response.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn snapshot_from_data_errs_on_unknown_workflows() {
+        let output = snapshot_from_data(data(Value::Null), input());
+        assert!(matches!(output, Err(RoverClientError::AdhocError { .. })));
+    }
+}
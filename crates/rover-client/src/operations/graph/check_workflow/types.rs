@@ -8,7 +8,7 @@ use rover_studio::types::GraphRef;
 use self::graph_check_workflow_query::CheckWorkflowTaskStatus;
 use crate::{
     operations::graph::check_workflow::runner::graph_check_workflow_query,
-    shared::{ChangeSeverity, CheckTaskStatus},
+    shared::{ChangeSeverity, CheckTaskStatus, ProposedSchema},
 };
 
 type QueryVariables = graph_check_workflow_query::Variables;
@@ -19,6 +19,7 @@ pub struct CheckWorkflowInput {
     pub graph_ref: GraphRef,
     pub workflow_id: String,
     pub checks_timeout_seconds: u64,
+    pub proposed_schema: Option<ProposedSchema>,
 }
 
 impl From<CheckWorkflowInput> for QueryVariables {
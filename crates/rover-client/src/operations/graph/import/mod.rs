@@ -0,0 +1,146 @@
+use apollo_federation_types::rover::{BuildError, BuildErrors};
+use graphql_client::GraphQLQuery;
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+
+use crate::{blocking::StudioClient, RoverClientError};
+
+type FederationVersion = String;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/graph/import/publish_subgraphs_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+struct GraphImportPublishSubgraphsMutation;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/graph/import/update_federation_version_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+struct GraphImportUpdateFederationVersionMutation;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GraphImportInput {
+    pub graph_ref: GraphRef,
+    pub subgraphs: Vec<ImportSubgraph>,
+    pub federation_version: Option<String>,
+    /// Recorded with the publish, to tell where the subgraphs came from
+    pub revision: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportSubgraph {
+    pub name: String,
+    pub url: Option<String>,
+    pub sdl: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct GraphImportResponse {
+    pub subgraphs_created: Vec<String>,
+    pub federation_version: Option<String>,
+    #[serde(skip_serializing)]
+    pub build_errors: BuildErrors,
+    pub launch_url: Option<String>,
+}
+
+/// Publishes every subgraph in a single batch, so the variant composes and launches once with
+/// all of them rather than once per subgraph. The variant is created if it doesn't exist yet.
+pub async fn run(
+    input: GraphImportInput,
+    client: &StudioClient,
+) -> Result<GraphImportResponse, RoverClientError> {
+    let (graph_id, variant) = input.graph_ref.into_parts();
+
+    // set the federation version before publishing, so the launch composes with it instead of
+    // the variant's current or default version
+    let mut federation_version_set = false;
+    if let Some(federation_version) = &input.federation_version {
+        federation_version_set =
+            update_federation_version(&graph_id, &variant, federation_version, client).await?;
+    }
+
+    let subgraphs = input
+        .subgraphs
+        .into_iter()
+        .map(
+            |subgraph| graph_import_publish_subgraphs_mutation::PublishSubgraphsSubgraphInput {
+                name: subgraph.name,
+                url: subgraph.url,
+                active_partial_schema:
+                    graph_import_publish_subgraphs_mutation::PartialSchemaInput {
+                        sdl: Some(subgraph.sdl),
+                        hash: None,
+                    },
+            },
+        )
+        .collect();
+    let data = client
+        .post::<GraphImportPublishSubgraphsMutation>(
+            graph_import_publish_subgraphs_mutation::Variables {
+                graph_id: graph_id.clone(),
+                variant: variant.clone(),
+                revision: input.revision,
+                subgraphs,
+            },
+        )
+        .await?;
+    let publish_response = data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphIdNotFound {
+            graph_id: graph_id.clone(),
+        })?
+        .publish_subgraphs
+        .ok_or_else(|| RoverClientError::MalformedResponse {
+            null_field: "graph.publishSubgraphs".to_string(),
+        })?;
+
+    // GraphOS didn't return the variant before it existed, so set the version now that it does
+    if let Some(federation_version) = &input.federation_version {
+        if !federation_version_set {
+            update_federation_version(&graph_id, &variant, federation_version, client).await?;
+        }
+    }
+
+    Ok(GraphImportResponse {
+        subgraphs_created: publish_response.subgraphs_created,
+        federation_version: input.federation_version,
+        build_errors: publish_response
+            .errors
+            .into_iter()
+            .flatten()
+            .map(|error| BuildError::composition_error(error.code, Some(error.message), None, None))
+            .collect(),
+        launch_url: publish_response.launch_url,
+    })
+}
+
+/// Returns whether GraphOS returned the variant to update
+async fn update_federation_version(
+    graph_id: &str,
+    variant: &str,
+    federation_version: &str,
+    client: &StudioClient,
+) -> Result<bool, RoverClientError> {
+    let data = client
+        .post::<GraphImportUpdateFederationVersionMutation>(
+            graph_import_update_federation_version_mutation::Variables {
+                graph_id: graph_id.to_string(),
+                variant: variant.to_string(),
+                federation_version: federation_version.to_string(),
+            },
+        )
+        .await?;
+    let graph = data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphIdNotFound {
+            graph_id: graph_id.to_string(),
+        })?;
+    Ok(graph.variant.is_some())
+}
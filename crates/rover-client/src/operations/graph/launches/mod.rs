@@ -0,0 +1,7 @@
+mod runner;
+mod types;
+
+pub use runner::run;
+pub use types::{
+    GraphLaunchesInput, GraphLaunchesResponse, LaunchInfo, LaunchState, LaunchSubgraphChange,
+};
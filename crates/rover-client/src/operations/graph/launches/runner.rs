@@ -0,0 +1,221 @@
+use futures::future::try_join_all;
+use graphql_client::*;
+use rover_studio::types::GraphRef;
+
+use self::graph_launches_query::{
+    GraphLaunchesQueryGraphVariantLaunchSummaries, LaunchStatus, SubgraphChangeType,
+};
+use crate::{
+    blocking::StudioClient,
+    operations::graph::launches::types::{
+        launch_superseded_variables, GraphLaunchesInput, GraphLaunchesResponse, LaunchInfo,
+        LaunchState, LaunchSubgraphChange, QueryResponseData,
+    },
+    RoverClientError,
+};
+
+type Timestamp = String;
+
+#[derive(GraphQLQuery)]
+// The paths are relative to the directory where your `Cargo.toml` is located.
+// Both json and the GraphQL schema language are supported as sources for the schema
+#[graphql(
+    query_path = "src/operations/graph/launches/launches_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+/// This struct is used to generate the module containing `Variables` and
+/// `ResponseData` structs.
+/// Snake case of this name is the mod name. i.e. graph_launches_query
+pub(crate) struct GraphLaunchesQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    query_path = "src/operations/graph/launches/launch_superseded_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+/// Snake case of this name is the mod name. i.e. graph_launch_superseded_query
+pub(crate) struct GraphLaunchSupersededQuery;
+
+/// Lists the most recent launches for a variant, newest first
+pub async fn run(
+    input: GraphLaunchesInput,
+    client: &StudioClient,
+) -> Result<GraphLaunchesResponse, RoverClientError> {
+    let graph_ref = input.graph_ref.clone();
+    let response_data = client.post::<GraphLaunchesQuery>(input.into()).await?;
+    let root_url = response_data.frontend_url_root.clone();
+    let summaries = get_launches_from_response_data(response_data, &graph_ref)?;
+
+    // a superseded launch keeps the LAUNCH_INITIATED status forever, so launches that look like
+    // they're still running need a second look
+    let launches = try_join_all(summaries.into_iter().map(|summary| {
+        let graph_ref = graph_ref.clone();
+        async move {
+            let mut launch = launch_info(summary);
+            if launch.status == LaunchState::InProgress
+                && is_superseded(&graph_ref, &launch.id, client).await?
+            {
+                launch.status = LaunchState::Superseded;
+            }
+            Ok::<_, RoverClientError>(launch)
+        }
+    }))
+    .await?;
+
+    Ok(GraphLaunchesResponse {
+        graph_ref,
+        root_url,
+        launches,
+    })
+}
+
+async fn is_superseded(
+    graph_ref: &GraphRef,
+    launch_id: &str,
+    client: &StudioClient,
+) -> Result<bool, RoverClientError> {
+    let response_data = client
+        .post::<GraphLaunchSupersededQuery>(launch_superseded_variables(graph_ref, launch_id))
+        .await?;
+    Ok(response_data
+        .graph
+        .and_then(|graph| graph.variant)
+        .and_then(|variant| variant.launch)
+        .is_some_and(|launch| launch.superseded_at.is_some()))
+}
+
+fn get_launches_from_response_data(
+    response_data: QueryResponseData,
+    graph_ref: &GraphRef,
+) -> Result<Vec<GraphLaunchesQueryGraphVariantLaunchSummaries>, RoverClientError> {
+    let graph = response_data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphNotFound {
+            graph_ref: graph_ref.clone(),
+        })?;
+    let variant = graph
+        .variant
+        .ok_or_else(|| RoverClientError::NoSchemaForVariant {
+            graph_ref: graph_ref.clone(),
+            valid_variants: graph
+                .variants
+                .iter()
+                .map(|variant| variant.name.clone())
+                .collect(),
+            frontend_url_root: response_data.frontend_url_root.clone(),
+        })?;
+    Ok(variant.launch_summaries.unwrap_or_default())
+}
+
+fn launch_info(summary: GraphLaunchesQueryGraphVariantLaunchSummaries) -> LaunchInfo {
+    let status = match summary.status {
+        LaunchStatus::LAUNCH_COMPLETED => LaunchState::Completed,
+        LaunchStatus::LAUNCH_FAILED => LaunchState::Failed,
+        LaunchStatus::LAUNCH_INITIATED | LaunchStatus::Other(_) => LaunchState::InProgress,
+    };
+    let subgraph_changes = summary
+        .subgraph_changes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|change| LaunchSubgraphChange {
+            name: change.name,
+            change: match change.type_ {
+                SubgraphChangeType::ADDITION => "added".to_string(),
+                SubgraphChangeType::DELETION => "removed".to_string(),
+                SubgraphChangeType::MODIFICATION => "modified".to_string(),
+                SubgraphChangeType::Other(other) => other.to_lowercase(),
+            },
+        })
+        .collect();
+    LaunchInfo {
+        id: summary.id,
+        created_at: summary.created_at.parse().ok(),
+        status,
+        subgraph_changes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn get_launches_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com/",
+            "graph": {
+                "variants": [{ "name": "current" }],
+                "variant": {
+                    "launchSummaries": [
+                        {
+                            "id": "launch-2",
+                            "createdAt": "2024-03-02T10:00:00.000Z",
+                            "status": "LAUNCH_FAILED",
+                            "subgraphChanges": [
+                                { "name": "reviews", "type": "ADDITION" }
+                            ]
+                        },
+                        {
+                            "id": "launch-1",
+                            "createdAt": "2024-03-01T10:00:00.000Z",
+                            "status": "LAUNCH_COMPLETED",
+                            "subgraphChanges": [
+                                { "name": "products", "type": "MODIFICATION" },
+                                { "name": "accounts", "type": "DELETION" }
+                            ]
+                        }
+                    ]
+                }
+            }
+        });
+        let data: QueryResponseData = serde_json::from_value(json_response).unwrap();
+        let launches: Vec<LaunchInfo> = get_launches_from_response_data(data, &mock_graph_ref())
+            .unwrap()
+            .into_iter()
+            .map(launch_info)
+            .collect();
+
+        assert_eq!(launches.len(), 2);
+        assert_eq!(launches[0].status, LaunchState::Failed);
+        assert_eq!(
+            launches[0].subgraph_changes,
+            vec![LaunchSubgraphChange {
+                name: "reviews".to_string(),
+                change: "added".to_string(),
+            }]
+        );
+        assert_eq!(
+            launches[1].created_at,
+            Some("2024-03-01T10:00:00.000Z".parse().unwrap())
+        );
+        assert_eq!(launches[1].status, LaunchState::Completed);
+        assert_eq!(launches[1].subgraph_changes[1].change, "removed");
+    }
+
+    #[test]
+    fn get_launches_from_response_data_errs_with_no_variant() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com/",
+            "graph": {
+                "variants": [{ "name": "current" }],
+                "variant": null
+            }
+        });
+        let data: QueryResponseData = serde_json::from_value(json_response).unwrap();
+        let output = get_launches_from_response_data(data, &mock_graph_ref());
+        assert!(matches!(
+            output,
+            Err(RoverClientError::NoSchemaForVariant { .. })
+        ));
+    }
+
+    fn mock_graph_ref() -> GraphRef {
+        GraphRef::new("mygraph", Some("staging")).unwrap()
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+
+use crate::operations::graph::launches::runner::{
+    graph_launch_superseded_query, graph_launches_query,
+};
+
+pub(crate) type QueryResponseData = graph_launches_query::ResponseData;
+type QueryVariables = graph_launches_query::Variables;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GraphLaunchesInput {
+    pub graph_ref: GraphRef,
+    pub limit: i64,
+}
+
+impl From<GraphLaunchesInput> for QueryVariables {
+    fn from(input: GraphLaunchesInput) -> Self {
+        let (graph_id, variant) = input.graph_ref.into_parts();
+        Self {
+            graph_id,
+            variant,
+            limit: input.limit,
+        }
+    }
+}
+
+pub(crate) fn launch_superseded_variables(
+    graph_ref: &GraphRef,
+    launch_id: &str,
+) -> graph_launch_superseded_query::Variables {
+    let (graph_id, variant) = graph_ref.clone().into_parts();
+    graph_launch_superseded_query::Variables {
+        graph_id,
+        variant,
+        launch_id: launch_id.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+pub struct GraphLaunchesResponse {
+    #[serde(skip_serializing)]
+    pub graph_ref: GraphRef,
+
+    #[serde(skip_serializing)]
+    pub root_url: String,
+
+    /// Most recent first
+    pub launches: Vec<LaunchInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+pub struct LaunchInfo {
+    pub id: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub status: LaunchState,
+    /// The subgraphs whose changes triggered the launch
+    pub subgraph_changes: Vec<LaunchSubgraphChange>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchState {
+    Completed,
+    Failed,
+    InProgress,
+    Superseded,
+}
+
+impl fmt::Display for LaunchState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            LaunchState::Completed => "completed",
+            LaunchState::Failed => "failed",
+            LaunchState::InProgress => "in progress",
+            LaunchState::Superseded => "superseded",
+        };
+        write!(f, "{state}")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Eq, PartialEq)]
+pub struct LaunchSubgraphChange {
+    pub name: String,
+    /// One of `added`, `removed`, or `modified`
+    pub change: String,
+}
+
+impl fmt::Display for LaunchSubgraphChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.change)
+    }
+}
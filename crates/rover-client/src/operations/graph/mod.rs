@@ -19,5 +19,11 @@ pub mod delete;
 /// "graph lint" command execution
 pub mod lint;
 
+/// "graph launches" command execution
+pub mod launches;
+
+/// "graph import" command execution
+pub mod import;
+
 /// internal module for getting info about variants
 pub(crate) mod variant;
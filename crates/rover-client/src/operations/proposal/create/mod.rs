@@ -0,0 +1,302 @@
+use std::time::{Duration, Instant};
+
+use graphql_client::GraphQLQuery;
+use rover_studio::types::GraphRef;
+
+use crate::{
+    blocking::StudioClient,
+    operations::proposal::{
+        create::create_proposal_mutation::CreateProposalMutationGraphCreateProposal as CreateResult,
+        describe::{self, DescribeProposalInput},
+        types::ProposalInfo,
+        update::{self, ProposalStatus, ProposalSubgraph, UpdateProposalInput},
+    },
+    RoverClientError,
+};
+
+/// How long to wait for a new proposal's first launch, which its first revision builds on
+const FIRST_LAUNCH_TIMEOUT: Duration = Duration::from_secs(120);
+const FIRST_LAUNCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/create/create_proposal_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+struct CreateProposalMutation;
+
+#[derive(Clone, Debug)]
+pub struct CreateProposalInput {
+    /// The variant the proposal suggests changes to
+    pub graph_ref: GraphRef,
+    pub display_name: String,
+    pub description: Option<String>,
+    /// Published as the proposal's first revision, if any are given
+    pub subgraphs: Vec<ProposalSubgraph>,
+    /// Describes the first revision
+    pub summary: String,
+    pub status: Option<ProposalStatus>,
+}
+
+/// Creates a proposal against a variant, then applies its first revision and status, if any.
+///
+/// GraphOS launches a new proposal's variant with the source variant's schemas, and the first
+/// revision has to build on that launch, so it's only published once the launch is done.
+pub async fn run(
+    input: CreateProposalInput,
+    client: &StudioClient,
+) -> Result<ProposalInfo, RoverClientError> {
+    let (graph_id, source_variant) = input.graph_ref.clone().into_parts();
+    let data = client
+        .post::<CreateProposalMutation>(create_proposal_mutation::Variables {
+            graph_id: graph_id.clone(),
+            input: create_proposal_mutation::CreateProposalInput {
+                description: input.description,
+                display_name: input.display_name,
+                source_variant_name: source_variant,
+            },
+        })
+        .await?;
+    let graph = data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphIdNotFound {
+            graph_id: graph_id.clone(),
+        })?;
+    let proposal_variant = match graph.create_proposal {
+        CreateResult::GraphVariant(variant) => variant.name,
+        CreateResult::CreateProposalError(error) => {
+            return Err(RoverClientError::AdhocError { msg: error.message })
+        }
+        CreateResult::PermissionError(error) => {
+            return Err(RoverClientError::AdhocError { msg: error.message })
+        }
+        CreateResult::ValidationError(error) => {
+            return Err(RoverClientError::AdhocError { msg: error.message })
+        }
+    };
+
+    let graph_ref = GraphRef::new(graph_id, Some(proposal_variant))?;
+    let (proposal, latest_launch_id) = describe::fetch(
+        DescribeProposalInput {
+            graph_ref: graph_ref.clone(),
+        },
+        client,
+    )
+    .await?;
+    if input.subgraphs.is_empty() && input.status.is_none() {
+        return Ok(proposal);
+    }
+
+    // the proposal exists from here on, so errors point to it rather than leaving it orphaned
+    let incomplete = |err: RoverClientError| {
+        RoverClientError::AdhocError {
+        msg: format!(
+            "Created proposal {}, but could not finish setting it up: {err}. Run `rover proposal update {graph_ref}` to try again.",
+            proposal.url
+        ),
+    }
+    };
+    if !input.subgraphs.is_empty() && latest_launch_id.is_none() {
+        wait_for_first_launch(&graph_ref, client, FIRST_LAUNCH_POLL_INTERVAL)
+            .await
+            .map_err(incomplete)?;
+    }
+    update::run(
+        UpdateProposalInput {
+            graph_ref: graph_ref.clone(),
+            description: None,
+            subgraphs: input.subgraphs,
+            summary: input.summary,
+            status: input.status,
+        },
+        client,
+    )
+    .await
+    .map_err(incomplete)
+}
+
+async fn wait_for_first_launch(
+    graph_ref: &GraphRef,
+    client: &StudioClient,
+    poll_interval: Duration,
+) -> Result<(), RoverClientError> {
+    let start = Instant::now();
+    loop {
+        tokio::time::sleep(poll_interval).await;
+        let (_, latest_launch_id) = describe::fetch(
+            DescribeProposalInput {
+                graph_ref: graph_ref.clone(),
+            },
+            client,
+        )
+        .await?;
+        if latest_launch_id.is_some() {
+            return Ok(());
+        }
+        if start.elapsed() > FIRST_LAUNCH_TIMEOUT {
+            return Err(RoverClientError::AdhocError {
+                msg: format!(
+                    "its first launch didn't finish within {} seconds",
+                    FIRST_LAUNCH_TIMEOUT.as_secs()
+                ),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::proposal::tests::{client, proposal_json};
+
+    fn mock_create(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("CreateProposalMutation")
+                .body_includes(r#""sourceVariantName":"current""#);
+            then.status(200).json_body(json!({
+                "data": {
+                    "graph": {
+                        "createProposal": { "__typename": "GraphVariant", "name": "p-101" }
+                    }
+                }
+            }));
+        })
+    }
+
+    fn mock_describe<'a>(server: &'a MockServer, latest_launch: Option<&str>) -> httpmock::Mock<'a> {
+        let latest_launch = latest_launch.map(|id| json!({ "id": id }));
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("DescribeProposalQuery")
+                .body_includes(r#""variant":"p-101""#);
+            then.status(200).json_body(json!({
+                "data": {
+                    "frontendUrlRoot": "https://studio.apollographql.com",
+                    "graph": {
+                        "variant": {
+                            "latestLaunch": latest_launch,
+                            "proposal": proposal_json("p-101")
+                        }
+                    }
+                }
+            }));
+        })
+    }
+
+    fn input(subgraphs: Vec<ProposalSubgraph>) -> CreateProposalInput {
+        CreateProposalInput {
+            graph_ref: GraphRef::new("mygraph", Some("current")).unwrap(),
+            display_name: "Add reviews".to_string(),
+            description: None,
+            subgraphs,
+            summary: "Updated reviews".to_string(),
+            status: None,
+        }
+    }
+
+    fn reviews_subgraph() -> ProposalSubgraph {
+        ProposalSubgraph {
+            name: "reviews".to_string(),
+            url: None,
+            sdl: "type Query { reviews: [String] }".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_creates_an_empty_proposal() {
+        let server = MockServer::start();
+        let create = mock_create(&server);
+        let describe = mock_describe(&server, None);
+
+        let proposal = run(input(Vec::new()), &client(&server)).await.unwrap();
+
+        create.assert();
+        describe.assert();
+        assert_eq!(
+            proposal.url,
+            "https://studio.apollographql.com/graph/mygraph/proposal/p-101/home"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_publishes_the_first_revision_on_the_first_launch() {
+        let server = MockServer::start();
+        mock_create(&server);
+        mock_describe(&server, Some("launch-1"));
+        let publish = server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("PublishProposalSubgraphsMutation")
+                .body_includes(r#""previousLaunchId":"launch-1""#);
+            then.status(200).json_body(json!({
+                "data": {
+                    "graph": {
+                        "variant": {
+                            "proposal": {
+                                "__typename": "ProposalMutation",
+                                "publishSubgraphs": { "__typename": "Proposal" }
+                            }
+                        }
+                    }
+                }
+            }));
+        });
+
+        let proposal = run(input(vec![reviews_subgraph()]), &client(&server))
+            .await
+            .unwrap();
+
+        publish.assert();
+        assert_eq!(proposal.variant, "p-101");
+    }
+
+    #[tokio::test]
+    async fn it_points_to_the_proposal_when_the_first_revision_fails() {
+        let server = MockServer::start();
+        mock_create(&server);
+        mock_describe(&server, Some("launch-1"));
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("PublishProposalSubgraphsMutation");
+            then.status(200).json_body(json!({
+                "data": {
+                    "graph": {
+                        "variant": {
+                            "proposal": {
+                                "__typename": "ValidationError",
+                                "message": "Invalid schema"
+                            }
+                        }
+                    }
+                }
+            }));
+        });
+
+        let err = run(input(vec![reviews_subgraph()]), &client(&server))
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("https://studio.apollographql.com/graph/mygraph/proposal/p-101/home"));
+        assert!(err.contains("Invalid schema"));
+    }
+
+    #[tokio::test]
+    async fn it_waits_for_the_first_launch() {
+        let server = MockServer::start();
+        let describe = mock_describe(&server, Some("launch-1"));
+        let graph_ref = GraphRef::new("mygraph", Some("p-101")).unwrap();
+
+        wait_for_first_launch(&graph_ref, &client(&server), Duration::ZERO)
+            .await
+            .unwrap();
+
+        describe.assert();
+    }
+}
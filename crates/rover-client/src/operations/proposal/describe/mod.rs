@@ -0,0 +1,138 @@
+use graphql_client::GraphQLQuery;
+use rover_studio::types::GraphRef;
+
+use crate::{
+    blocking::StudioClient,
+    operations::proposal::types::{impl_into_proposal_info, IntoProposalInfo, ProposalInfo},
+    RoverClientError,
+};
+
+type Timestamp = String;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/describe/describe_proposal_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+struct DescribeProposalQuery;
+
+impl_into_proposal_info!(
+    describe_proposal_query,
+    DescribeProposalQueryGraphVariantProposal
+);
+
+#[derive(Clone)]
+pub struct DescribeProposalInput {
+    /// The proposal's own variant, like `my-graph@p-101`
+    pub graph_ref: GraphRef,
+}
+
+impl From<DescribeProposalInput> for describe_proposal_query::Variables {
+    fn from(value: DescribeProposalInput) -> Self {
+        let (graph_id, variant) = value.graph_ref.into_parts();
+        describe_proposal_query::Variables { graph_id, variant }
+    }
+}
+
+pub async fn run(
+    input: DescribeProposalInput,
+    client: &StudioClient,
+) -> Result<ProposalInfo, RoverClientError> {
+    Ok(fetch(input, client).await?.0)
+}
+
+/// The proposal, along with the ID of its variant's latest launch, which new revisions build on
+pub(crate) async fn fetch(
+    input: DescribeProposalInput,
+    client: &StudioClient,
+) -> Result<(ProposalInfo, Option<String>), RoverClientError> {
+    let graph_ref = input.graph_ref.clone();
+    let data = client.post::<DescribeProposalQuery>(input.into()).await?;
+    proposal_from_response_data(data, graph_ref)
+}
+
+fn proposal_from_response_data(
+    data: describe_proposal_query::ResponseData,
+    graph_ref: GraphRef,
+) -> Result<(ProposalInfo, Option<String>), RoverClientError> {
+    let graph = data.graph.ok_or_else(|| RoverClientError::GraphNotFound {
+        graph_ref: graph_ref.clone(),
+    })?;
+    let variant = graph.variant.ok_or_else(|| RoverClientError::AdhocError {
+        msg: format!("Could not find proposal '{graph_ref}'"),
+    })?;
+    let proposal = variant
+        .proposal
+        .ok_or_else(|| RoverClientError::AdhocError {
+            msg: format!("'{graph_ref}' is a variant, not a proposal"),
+        })?;
+    Ok((
+        proposal.into_proposal_info(&data.frontend_url_root, graph_ref.graph_id()),
+        variant.latest_launch.map(|launch| launch.id),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::proposal::{tests::proposal_json, types::ProposalReviewInfo};
+
+    #[test]
+    fn proposal_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variant": {
+                    "latestLaunch": { "id": "launch-1" },
+                    "proposal": proposal_json("p-101")
+                }
+            }
+        });
+        let data: describe_proposal_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let graph_ref = GraphRef::new("mygraph", Some("p-101")).unwrap();
+        let (proposal, latest_launch_id) = proposal_from_response_data(data, graph_ref).unwrap();
+
+        assert_eq!(latest_launch_id, Some("launch-1".to_string()));
+        assert_eq!(proposal.status, "open");
+        assert_eq!(proposal.source_variant, "current");
+        assert_eq!(
+            proposal.url,
+            "https://studio.apollographql.com/graph/mygraph/proposal/p-101/home"
+        );
+        assert_eq!(
+            proposal.reviews,
+            vec![ProposalReviewInfo {
+                reviewer: Some("Ada".to_string()),
+                decision: "approved".to_string(),
+            }]
+        );
+        assert_eq!(proposal.review_status(), "1 approved, 0 not approved");
+    }
+
+    #[test]
+    fn proposal_from_response_data_errs_on_plain_variant() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variant": {
+                    "latestLaunch": null,
+                    "proposal": null
+                }
+            }
+        });
+        let data: describe_proposal_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let graph_ref = GraphRef::new("mygraph", Some("current")).unwrap();
+        let err = proposal_from_response_data(data, graph_ref).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'mygraph@current' is a variant, not a proposal"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use graphql_client::GraphQLQuery;
+use serde::Serialize;
+
+use crate::{
+    blocking::StudioClient,
+    operations::proposal::{
+        list::list_proposals_query::ProposalsFilterInput,
+        types::{impl_into_proposal_info, IntoProposalInfo, ProposalInfo},
+    },
+    RoverClientError,
+};
+
+pub use crate::operations::proposal::list::list_proposals_query::ProposalStatus;
+
+type Timestamp = String;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/list/list_proposals_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+struct ListProposalsQuery;
+
+impl_into_proposal_info!(
+    list_proposals_query,
+    ListProposalsQueryGraphProposalsProposals
+);
+
+#[derive(Clone)]
+pub struct ListProposalsInput {
+    pub graph_id: String,
+    /// Only list proposals against these variants
+    pub source_variants: Option<Vec<String>>,
+    /// Only list proposals with these statuses
+    pub statuses: Option<Vec<ProposalStatus>>,
+    pub limit: i64,
+}
+
+impl From<ListProposalsInput> for list_proposals_query::Variables {
+    fn from(value: ListProposalsInput) -> Self {
+        list_proposals_query::Variables {
+            graph_id: value.graph_id,
+            filter: Some(ProposalsFilterInput {
+                source_variants: value.source_variants,
+                status: value.statuses,
+                subgraphs: None,
+            }),
+            limit: Some(value.limit),
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct ListProposalsResponse {
+    pub graph_id: String,
+    pub proposals: Vec<ProposalInfo>,
+    /// How many proposals match the filters, which can be more than were listed
+    pub total_count: i64,
+}
+
+pub async fn run(
+    input: ListProposalsInput,
+    client: &StudioClient,
+) -> Result<ListProposalsResponse, RoverClientError> {
+    let graph_id = input.graph_id.clone();
+    let data = client.post::<ListProposalsQuery>(input.into()).await?;
+    let graph = data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphIdNotFound {
+            graph_id: graph_id.clone(),
+        })?;
+    let proposals = graph
+        .proposals
+        .proposals
+        .into_iter()
+        .map(|proposal| proposal.into_proposal_info(&data.frontend_url_root, &graph_id))
+        .collect();
+    Ok(ListProposalsResponse {
+        graph_id,
+        proposals,
+        total_count: graph.proposals.total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::proposal::tests::{client, proposal_json};
+
+    #[tokio::test]
+    async fn it_lists_proposals_matching_the_filters() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/graphql")
+                .body_includes("ListProposalsQuery")
+                .body_includes(r#""sourceVariants":["current"]"#)
+                .body_includes(r#""status":["OPEN","DRAFT"]"#);
+            then.status(200).json_body(json!({
+                "data": {
+                    "frontendUrlRoot": "https://studio.apollographql.com",
+                    "graph": {
+                        "proposals": {
+                            "totalCount": 3,
+                            "proposals": [proposal_json("p-101"), proposal_json("p-102")]
+                        }
+                    }
+                }
+            }));
+        });
+
+        let response = run(
+            ListProposalsInput {
+                graph_id: "mygraph".to_string(),
+                source_variants: Some(vec!["current".to_string()]),
+                statuses: Some(vec![ProposalStatus::OPEN, ProposalStatus::DRAFT]),
+                limit: 2,
+            },
+            &client(&server),
+        )
+        .await
+        .unwrap();
+
+        mock.assert();
+        assert_eq!(response.total_count, 3);
+        let variants: Vec<&str> = response
+            .proposals
+            .iter()
+            .map(|proposal| proposal.variant.as_str())
+            .collect();
+        assert_eq!(variants, ["p-101", "p-102"]);
+        assert_eq!(
+            response.proposals[1].url,
+            "https://studio.apollographql.com/graph/mygraph/proposal/p-102/home"
+        );
+        assert_eq!(
+            response.proposals[0].review_status(),
+            "1 approved, 0 not approved"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_errs_on_unknown_graphs() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/api/graphql");
+            then.status(200).json_body(json!({
+                "data": { "frontendUrlRoot": "https://studio.apollographql.com", "graph": null }
+            }));
+        });
+
+        let err = run(
+            ListProposalsInput {
+                graph_id: "mygraph".to_string(),
+                source_variants: None,
+                statuses: None,
+                limit: 10,
+            },
+            &client(&server),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, RoverClientError::GraphIdNotFound { .. }));
+    }
+}
@@ -0,0 +1,57 @@
+pub mod create;
+pub mod describe;
+pub mod list;
+pub mod types;
+pub mod update;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use houston::{Credential, CredentialOrigin};
+    use httpmock::MockServer;
+    use rover_http::RetryConfig;
+
+    use crate::blocking::StudioClient;
+
+    /// A client that sends every request to `server`, without retrying
+    pub(crate) fn client(server: &MockServer) -> StudioClient {
+        StudioClient::new(
+            Credential {
+                api_key: "user:gh.foo:key".to_string(),
+                origin: CredentialOrigin::EnvVar,
+            },
+            &server.url("/api/graphql"),
+            "0.0.0",
+            false,
+            reqwest::Client::new(),
+            RetryConfig {
+                max_attempts: Some(1),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// The `proposal` fields every proposal query selects, for mocked responses
+    pub(crate) fn proposal_json(variant: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "3f1c",
+            "displayName": "Add reviews",
+            "description": "Adds the reviews subgraph",
+            "status": "OPEN",
+            "createdAt": "2024-03-01T10:00:00Z",
+            "sourceVariant": { "name": "current" },
+            "backingVariant": { "name": variant },
+            "reviews": [
+                {
+                    "decision": "APPROVED",
+                    "isDismissed": false,
+                    "createdBy": { "__typename": "User", "name": "Ada" }
+                },
+                {
+                    "decision": "NOT_APPROVED",
+                    "isDismissed": true,
+                    "createdBy": null
+                }
+            ]
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct ProposalInfo {
+    pub id: String,
+    pub display_name: String,
+    pub description: String,
+    /// One of `draft`, `open`, `approved`, `closed`, or `implemented`
+    pub status: String,
+    pub created_at: Option<DateTime<Utc>>,
+    /// The variant the proposal suggests changes to
+    pub source_variant: String,
+    /// The proposal's own variant, which holds the proposed subgraph schemas
+    pub variant: String,
+    /// Reviews that haven't been dismissed
+    pub reviews: Vec<ProposalReviewInfo>,
+    pub url: String,
+}
+
+impl ProposalInfo {
+    /// A summary of the reviews, like `2 approved, 1 not approved`
+    pub fn review_status(&self) -> String {
+        if self.reviews.is_empty() {
+            return "no reviews".to_string();
+        }
+        let approved = self
+            .reviews
+            .iter()
+            .filter(|review| review.decision == "approved")
+            .count();
+        format!(
+            "{} approved, {} not approved",
+            approved,
+            self.reviews.len() - approved
+        )
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct ProposalReviewInfo {
+    pub reviewer: Option<String>,
+    /// Either `approved` or `not approved`
+    pub decision: String,
+}
+
+pub(crate) fn proposal_url(frontend_url_root: &str, graph_id: &str, variant: &str) -> String {
+    format!("{frontend_url_root}/graph/{graph_id}/proposal/{variant}/home")
+}
+
+/// Converts a proposal returned by one of the proposal queries. They all select the same
+/// fields, but graphql-client generates a separate type for each query.
+pub(crate) trait IntoProposalInfo {
+    fn into_proposal_info(self, frontend_url_root: &str, graph_id: &str) -> ProposalInfo;
+}
+
+/// Implements [`IntoProposalInfo`] for the proposal type `$remote` generated in the query
+/// module `$query`
+macro_rules! impl_into_proposal_info {
+    ($query:ident, $remote:ident) => {
+        impl $crate::operations::proposal::types::IntoProposalInfo for $query::$remote {
+            fn into_proposal_info(
+                self,
+                frontend_url_root: &str,
+                graph_id: &str,
+            ) -> $crate::operations::proposal::types::ProposalInfo {
+                use $crate::operations::proposal::types::{
+                    proposal_url, ProposalInfo, ProposalReviewInfo,
+                };
+                use $query::{ProposalStatus, ReviewDecision};
+
+                let status = match self.status {
+                    ProposalStatus::DRAFT => "draft".to_string(),
+                    ProposalStatus::OPEN => "open".to_string(),
+                    ProposalStatus::APPROVED => "approved".to_string(),
+                    ProposalStatus::CLOSED => "closed".to_string(),
+                    ProposalStatus::IMPLEMENTED => "implemented".to_string(),
+                    ProposalStatus::Other(other) => other.to_lowercase(),
+                };
+                let reviews = self
+                    .reviews
+                    .into_iter()
+                    .filter(|review| !review.is_dismissed)
+                    .map(|review| ProposalReviewInfo {
+                        reviewer: review.created_by.map(|reviewer| reviewer.name),
+                        decision: match review.decision {
+                            ReviewDecision::APPROVED => "approved".to_string(),
+                            ReviewDecision::NOT_APPROVED => "not approved".to_string(),
+                            ReviewDecision::Other(other) => other.to_lowercase(),
+                        },
+                    })
+                    .collect();
+                ProposalInfo {
+                    url: proposal_url(frontend_url_root, graph_id, &self.backing_variant.name),
+                    id: self.id,
+                    display_name: self.display_name,
+                    description: self.description,
+                    status,
+                    created_at: self.created_at.parse().ok(),
+                    source_variant: self.source_variant.name,
+                    variant: self.backing_variant.name,
+                    reviews,
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_into_proposal_info;
@@ -0,0 +1,390 @@
+use graphql_client::GraphQLQuery;
+use rover_studio::types::GraphRef;
+
+use crate::{
+    blocking::StudioClient,
+    operations::proposal::{
+        describe::{self, DescribeProposalInput},
+        types::ProposalInfo,
+    },
+    RoverClientError,
+};
+
+pub use crate::operations::proposal::update::update_proposal_status_mutation::ProposalStatus;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/update/publish_proposal_subgraphs_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+struct PublishProposalSubgraphsMutation;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/update/update_proposal_description_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+struct UpdateProposalDescriptionMutation;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/proposal/update/update_proposal_status_mutation.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    variables_derives = "Clone, Debug",
+    deprecated = "warn"
+)]
+pub(crate) struct UpdateProposalStatusMutation;
+
+/// A subgraph schema to propose
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalSubgraph {
+    pub name: String,
+    pub url: Option<String>,
+    pub sdl: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateProposalInput {
+    /// The proposal's own variant, like `my-graph@p-101`
+    pub graph_ref: GraphRef,
+    pub description: Option<String>,
+    /// Published together as a single new revision of the proposal
+    pub subgraphs: Vec<ProposalSubgraph>,
+    /// Describes the new revision
+    pub summary: String,
+    pub status: Option<ProposalStatus>,
+}
+
+/// Applies each requested change to a proposal in turn, then returns the updated proposal
+pub async fn run(
+    input: UpdateProposalInput,
+    client: &StudioClient,
+) -> Result<ProposalInfo, RoverClientError> {
+    let graph_ref = input.graph_ref;
+    let (graph_id, variant) = graph_ref.clone().into_parts();
+    let (_, latest_launch_id) = describe::fetch(
+        DescribeProposalInput {
+            graph_ref: graph_ref.clone(),
+        },
+        client,
+    )
+    .await?;
+
+    if !input.subgraphs.is_empty() {
+        // each revision builds on the one before it
+        let previous_launch_id = latest_launch_id.ok_or_else(|| RoverClientError::AdhocError {
+            msg: format!(
+                "'{graph_ref}' has no launches to build a new revision on yet. Try again once its first launch completes."
+            ),
+        })?;
+        publish_subgraphs(
+            &graph_id,
+            &variant,
+            previous_launch_id,
+            input.subgraphs,
+            input.summary,
+            client,
+        )
+        .await?;
+    }
+    if let Some(description) = input.description {
+        update_description(&graph_id, &variant, description, client).await?;
+    }
+    if let Some(status) = input.status {
+        update_status(&graph_id, &variant, status, client).await?;
+    }
+
+    describe::run(DescribeProposalInput { graph_ref }, client).await
+}
+
+async fn publish_subgraphs(
+    graph_id: &str,
+    variant: &str,
+    previous_launch_id: String,
+    subgraphs: Vec<ProposalSubgraph>,
+    summary: String,
+    client: &StudioClient,
+) -> Result<(), RoverClientError> {
+    use publish_proposal_subgraphs_mutation::{
+        PartialSchemaInput, PublishProposalSubgraphsInput,
+        PublishProposalSubgraphsMutationGraphVariantProposal as ProposalResult,
+        PublishProposalSubgraphsMutationGraphVariantProposalOnProposalMutationPublishSubgraphs as PublishResult,
+        PublishSubgraphsSubgraphInput,
+    };
+
+    let data = client
+        .post::<PublishProposalSubgraphsMutation>(publish_proposal_subgraphs_mutation::Variables {
+            graph_id: graph_id.to_string(),
+            variant: variant.to_string(),
+            input: PublishProposalSubgraphsInput {
+                git_context: None,
+                previous_launch_id,
+                revision: "".to_string(),
+                subgraph_inputs: subgraphs
+                    .into_iter()
+                    .map(|subgraph| PublishSubgraphsSubgraphInput {
+                        name: subgraph.name,
+                        url: subgraph.url,
+                        active_partial_schema: PartialSchemaInput {
+                            sdl: Some(subgraph.sdl),
+                            hash: None,
+                        },
+                    })
+                    .collect(),
+                summary,
+            },
+        })
+        .await?;
+    let proposal = data
+        .graph
+        .and_then(|graph| graph.variant)
+        .map(|variant| variant.proposal)
+        .ok_or_else(|| proposal_not_found(graph_id, variant))?;
+    match proposal {
+        ProposalResult::ProposalMutation(mutation) => match mutation.publish_subgraphs {
+            PublishResult::Proposal => Ok(()),
+            PublishResult::NotFoundError(error) => Err(adhoc(error.message)),
+            PublishResult::PermissionError(error) => Err(adhoc(error.message)),
+            PublishResult::ValidationError(error) => Err(adhoc(error.message)),
+        },
+        ProposalResult::NotFoundError(error) => Err(adhoc(error.message)),
+        ProposalResult::PermissionError(error) => Err(adhoc(error.message)),
+        ProposalResult::ValidationError(error) => Err(adhoc(error.message)),
+    }
+}
+
+async fn update_description(
+    graph_id: &str,
+    variant: &str,
+    description: String,
+    client: &StudioClient,
+) -> Result<(), RoverClientError> {
+    use update_proposal_description_mutation::{
+        UpdateDescriptionInput,
+        UpdateProposalDescriptionMutationGraphVariantProposal as ProposalResult,
+        UpdateProposalDescriptionMutationGraphVariantProposalOnProposalMutationUpdateDescription as UpdateResult,
+    };
+
+    let data = client
+        .post::<UpdateProposalDescriptionMutation>(
+            update_proposal_description_mutation::Variables {
+                graph_id: graph_id.to_string(),
+                variant: variant.to_string(),
+                input: UpdateDescriptionInput { description },
+            },
+        )
+        .await?;
+    let proposal = data
+        .graph
+        .and_then(|graph| graph.variant)
+        .map(|variant| variant.proposal)
+        .ok_or_else(|| proposal_not_found(graph_id, variant))?;
+    match proposal {
+        ProposalResult::ProposalMutation(mutation) => match mutation.update_description {
+            UpdateResult::Proposal => Ok(()),
+            UpdateResult::PermissionError(error) => Err(adhoc(error.message)),
+            UpdateResult::ValidationError(error) => Err(adhoc(error.message)),
+        },
+        ProposalResult::NotFoundError(error) => Err(adhoc(error.message)),
+        ProposalResult::PermissionError(error) => Err(adhoc(error.message)),
+        ProposalResult::ValidationError(error) => Err(adhoc(error.message)),
+    }
+}
+
+async fn update_status(
+    graph_id: &str,
+    variant: &str,
+    status: ProposalStatus,
+    client: &StudioClient,
+) -> Result<(), RoverClientError> {
+    use update_proposal_status_mutation::{
+        UpdateProposalStatusMutationGraphVariantProposal as ProposalResult,
+        UpdateProposalStatusMutationGraphVariantProposalOnProposalMutationUpdateStatus as UpdateResult,
+    };
+
+    let data = client
+        .post::<UpdateProposalStatusMutation>(update_proposal_status_mutation::Variables {
+            graph_id: graph_id.to_string(),
+            variant: variant.to_string(),
+            status,
+        })
+        .await?;
+    let proposal = data
+        .graph
+        .and_then(|graph| graph.variant)
+        .map(|variant| variant.proposal)
+        .ok_or_else(|| proposal_not_found(graph_id, variant))?;
+    match proposal {
+        ProposalResult::ProposalMutation(mutation) => match mutation.update_status {
+            UpdateResult::Proposal => Ok(()),
+            UpdateResult::PermissionError(error) => Err(adhoc(error.message)),
+            UpdateResult::ValidationError(error) => Err(adhoc(error.message)),
+        },
+        ProposalResult::NotFoundError(error) => Err(adhoc(error.message)),
+        ProposalResult::PermissionError(error) => Err(adhoc(error.message)),
+        ProposalResult::ValidationError(error) => Err(adhoc(error.message)),
+    }
+}
+
+fn proposal_not_found(graph_id: &str, variant: &str) -> RoverClientError {
+    RoverClientError::AdhocError {
+        msg: format!("Could not find proposal '{graph_id}@{variant}'"),
+    }
+}
+
+const fn adhoc(msg: String) -> RoverClientError {
+    RoverClientError::AdhocError { msg }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::proposal::tests::{client, proposal_json};
+
+    fn describe_response(latest_launch: Option<&str>) -> serde_json::Value {
+        json!({
+            "data": {
+                "frontendUrlRoot": "https://studio.apollographql.com",
+                "graph": {
+                    "variant": {
+                        "latestLaunch": latest_launch.map(|id| json!({ "id": id })),
+                        "proposal": proposal_json("p-101")
+                    }
+                }
+            }
+        })
+    }
+
+    fn mutation_response(field: &str) -> serde_json::Value {
+        let mut proposal = json!({ "__typename": "ProposalMutation" });
+        proposal[field] = json!({ "__typename": "Proposal" });
+        json!({ "data": { "graph": { "variant": { "proposal": proposal } } } })
+    }
+
+    fn input(subgraphs: Vec<ProposalSubgraph>) -> UpdateProposalInput {
+        UpdateProposalInput {
+            graph_ref: GraphRef::new("mygraph", Some("p-101")).unwrap(),
+            description: Some("Adds the reviews subgraph".to_string()),
+            subgraphs,
+            summary: "Updated reviews".to_string(),
+            status: Some(ProposalStatus::OPEN),
+        }
+    }
+
+    fn reviews_subgraph() -> ProposalSubgraph {
+        ProposalSubgraph {
+            name: "reviews".to_string(),
+            url: Some("http://reviews".to_string()),
+            sdl: "type Query { reviews: [String] }".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_publishes_a_revision_on_the_latest_launch_then_updates_the_proposal() {
+        let server = MockServer::start();
+        let describe = server.mock(|when, then| {
+            when.method(POST).body_includes("DescribeProposalQuery");
+            then.status(200)
+                .json_body(describe_response(Some("launch-1")));
+        });
+        let publish = server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("PublishProposalSubgraphsMutation")
+                .body_includes(r#""previousLaunchId":"launch-1""#)
+                .body_includes(r#""summary":"Updated reviews""#);
+            then.status(200)
+                .json_body(mutation_response("publishSubgraphs"));
+        });
+        let description = server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("UpdateProposalDescriptionMutation");
+            then.status(200)
+                .json_body(mutation_response("updateDescription"));
+        });
+        let status = server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("UpdateProposalStatusMutation")
+                .body_includes(r#""status":"OPEN""#);
+            then.status(200)
+                .json_body(mutation_response("updateStatus"));
+        });
+
+        let proposal = run(input(vec![reviews_subgraph()]), &client(&server))
+            .await
+            .unwrap();
+
+        publish.assert();
+        description.assert();
+        status.assert();
+        // once for the latest launch, once for the updated proposal
+        describe.assert_calls(2);
+        assert_eq!(proposal.variant, "p-101");
+    }
+
+    #[tokio::test]
+    async fn it_errs_before_publishing_without_a_launch() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).body_includes("DescribeProposalQuery");
+            then.status(200).json_body(describe_response(None));
+        });
+        let mutations = server.mock(|when, then| {
+            when.method(POST).body_includes("Mutation");
+            then.status(500);
+        });
+
+        let err = run(input(vec![reviews_subgraph()]), &client(&server))
+            .await
+            .unwrap_err();
+
+        mutations.assert_calls(0);
+        assert!(err.to_string().contains("has no launches"));
+    }
+
+    #[tokio::test]
+    async fn it_surfaces_errors_from_the_proposal() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).body_includes("DescribeProposalQuery");
+            then.status(200)
+                .json_body(describe_response(Some("launch-1")));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("UpdateProposalDescriptionMutation");
+            then.status(200).json_body(json!({
+                "data": {
+                    "graph": {
+                        "variant": {
+                            "proposal": {
+                                "__typename": "PermissionError",
+                                "message": "Only the proposal's author can edit it"
+                            }
+                        }
+                    }
+                }
+            }));
+        });
+
+        let err = run(
+            UpdateProposalInput {
+                status: None,
+                ..input(Vec::new())
+            },
+            &client(&server),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.to_string(), "Only the proposal's author can edit it");
+    }
+}
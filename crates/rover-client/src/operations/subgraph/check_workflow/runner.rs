@@ -1,4 +1,4 @@
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use apollo_federation_types::rover::BuildError;
 use graphql_client::*;
@@ -20,9 +20,10 @@ use crate::{
     blocking::StudioClient,
     operations::subgraph::check_workflow::types::QueryResponseData,
     shared::{
-        CheckWorkflowResponse, CustomCheckResponse, Diagnostic, DownstreamCheckResponse,
-        LintCheckResponse, OperationCheckResponse, ProposalsCheckResponse,
-        ProposalsCheckSeverityLevel, ProposalsCoverage, RelatedProposal, SchemaChange, Violation,
+        wait_for_check_workflow, CheckWorkflowResponse, CheckWorkflowSnapshot, CustomCheckResponse,
+        Diagnostic, DownstreamCheckResponse, LintCheckResponse, OperationCheckResponse,
+        ProposalsCheckResponse, ProposalsCheckSeverityLevel, ProposalsCoverage, ProposedSchema,
+        RelatedProposal, SchemaChange, Violation, CHECK_WORKFLOW_POLL_INTERVAL,
     },
     RoverClientError,
 };
@@ -49,35 +50,55 @@ pub async fn run(
     subgraph: String,
     client: &StudioClient,
 ) -> Result<CheckWorkflowResponse, RoverClientError> {
-    let graph_ref = input.graph_ref.clone();
-    let mut url: Option<String> = None;
-    let now = Instant::now();
-    loop {
-        let result = client
-            .post::<SubgraphCheckWorkflowQuery>(input.clone().into())
-            .await;
-        match result {
-            Ok(data) => {
-                let graph = data.clone().graph.ok_or(RoverClientError::GraphNotFound {
-                    graph_ref: graph_ref.clone(),
-                })?;
-                if let Some(check_workflow) = graph.check_workflow {
-                    if !matches!(check_workflow.status, CheckWorkflowStatus::PENDING) {
-                        return get_check_response_from_data(data, graph_ref, subgraph);
-                    }
-                }
-                url = get_target_url_from_data(data);
-            }
-            Err(e) => {
-                eprintln!(
-                    "error while checking status of check: {e}\nthis error may be transient... retrying"
-                );
-            }
-        }
-        if now.elapsed() > Duration::from_secs(input.checks_timeout_seconds) {
-            return Err(RoverClientError::ChecksTimeoutError { url });
-        }
-        std::thread::sleep(Duration::from_secs(5));
+    wait_for_check_workflow(
+        Duration::from_secs(input.checks_timeout_seconds),
+        CHECK_WORKFLOW_POLL_INTERVAL,
+        || client.post::<SubgraphCheckWorkflowQuery>(input.clone().into()),
+        |data| snapshot_from_data(data, subgraph.clone(), input.clone()),
+    )
+    .await
+}
+
+/// Fetches the check workflow once, returning its result if it has finished
+/// or a link to it in Studio if it's still running.
+pub async fn snapshot(
+    input: CheckWorkflowInput,
+    subgraph: String,
+    client: &StudioClient,
+) -> Result<CheckWorkflowSnapshot, RoverClientError> {
+    let data = client
+        .post::<SubgraphCheckWorkflowQuery>(input.clone().into())
+        .await?;
+    snapshot_from_data(data, subgraph, input)
+}
+
+fn snapshot_from_data(
+    data: QueryResponseData,
+    subgraph: String,
+    input: CheckWorkflowInput,
+) -> Result<CheckWorkflowSnapshot, RoverClientError> {
+    let graph_ref = input.graph_ref;
+    let check_workflow = data
+        .graph
+        .as_ref()
+        .ok_or(RoverClientError::GraphNotFound {
+            graph_ref: graph_ref.clone(),
+        })?
+        .check_workflow
+        .as_ref()
+        .ok_or_else(|| RoverClientError::AdhocError {
+            msg: format!(
+                "Could not find a check with workflow ID {} for {graph_ref}",
+                input.workflow_id
+            ),
+        })?;
+    if matches!(check_workflow.status, CheckWorkflowStatus::PENDING) {
+        Ok(CheckWorkflowSnapshot::Pending {
+            target_url: get_target_url_from_data(data),
+        })
+    } else {
+        get_check_response_from_data(data, graph_ref, subgraph, input.proposed_schema)
+            .map(|check_response| CheckWorkflowSnapshot::Completed(Box::new(check_response)))
     }
 }
 
@@ -85,6 +106,7 @@ fn get_check_response_from_data(
     data: QueryResponseData,
     graph_ref: GraphRef,
     subgraph: String,
+    proposed_schema: Option<ProposedSchema>,
 ) -> Result<CheckWorkflowResponse, RoverClientError> {
     let graph = data.graph.ok_or(RoverClientError::GraphNotFound {
         graph_ref: graph_ref.clone(),
@@ -230,6 +252,7 @@ fn get_check_response_from_data(
             downstream_target_url,
             downstream_result,
         ),
+        maybe_proposed_schema: proposed_schema,
     };
 
     match check_workflow.status {
@@ -265,6 +288,11 @@ fn get_operations_response_from_result(
             let mut changes = Vec::with_capacity(result.changes.len());
             for change in result.changes {
                 changes.push(SchemaChange {
+                    coordinate: SchemaChange::coordinate(
+                        change.parent_node.and_then(|node| node.name),
+                        change.child_node.and_then(|node| node.name),
+                        change.arg_node.and_then(|node| node.name),
+                    ),
                     code: change.code,
                     severity: change.severity.into(),
                     description: change.description,
@@ -490,7 +518,7 @@ mod tests {
         let graph_ref = "test-graph@test-variant".parse().unwrap();
         let subgraph = "test-subgraph".to_string();
 
-        let result = get_check_response_from_data(data, graph_ref, subgraph);
+        let result = get_check_response_from_data(data, graph_ref, subgraph, None);
 
         assert!(result.is_ok());
         let response = result.unwrap();
@@ -512,7 +540,7 @@ mod tests {
         let graph_ref: GraphRef = "test-graph@test-variant".parse().unwrap();
         let subgraph = "test-subgraph".to_string();
 
-        let result = get_check_response_from_data(data, graph_ref.clone(), subgraph);
+        let result = get_check_response_from_data(data, graph_ref.clone(), subgraph, None);
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -557,7 +585,7 @@ mod tests {
         let graph_ref: GraphRef = "test-graph@test-variant".parse().unwrap();
         let subgraph = "test-subgraph".to_string();
 
-        let result = get_check_response_from_data(data, graph_ref.clone(), subgraph.clone());
+        let result = get_check_response_from_data(data, graph_ref.clone(), subgraph.clone(), None);
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -591,7 +619,7 @@ mod tests {
         let graph_ref: GraphRef = "test-graph@test-variant".parse().unwrap();
         let subgraph = "test-subgraph".to_string();
 
-        let result = get_check_response_from_data(data, graph_ref, subgraph);
+        let result = get_check_response_from_data(data, graph_ref, subgraph, None);
 
         // Should succeed instead of returning MalformedResponse error
         assert!(result.is_ok());
@@ -619,7 +647,7 @@ mod tests {
         let graph_ref: GraphRef = "test-graph@test-variant".parse().unwrap();
         let subgraph = "test-subgraph".to_string();
 
-        let result = get_check_response_from_data(data, graph_ref, subgraph);
+        let result = get_check_response_from_data(data, graph_ref, subgraph, None);
 
         // Should succeed instead of returning MalformedResponse error
         assert!(result.is_ok());
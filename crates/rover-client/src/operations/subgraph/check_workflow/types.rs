@@ -6,7 +6,7 @@ use rover_studio::types::GraphRef;
 use self::subgraph_check_workflow_query::CheckWorkflowTaskStatus;
 use crate::{
     operations::subgraph::check_workflow::runner::subgraph_check_workflow_query,
-    shared::{ChangeSeverity, CheckTaskStatus},
+    shared::{ChangeSeverity, CheckTaskStatus, ProposedSchema},
 };
 
 type QueryVariables = subgraph_check_workflow_query::Variables;
@@ -19,6 +19,7 @@ pub struct CheckWorkflowInput {
     pub graph_ref: GraphRef,
     pub workflow_id: String,
     pub checks_timeout_seconds: u64,
+    pub proposed_schema: Option<ProposedSchema>,
 }
 
 impl From<CheckWorkflowInput> for QueryVariables {
@@ -4,4 +4,4 @@ mod types;
 
 pub use runner::run;
 pub use service::{SubgraphFetchAll, SubgraphFetchAllRequest};
-pub use types::{SubgraphFetchAllInput, SubgraphFetchAllResponse};
+pub use types::{Subgraph, SubgraphFetchAllInput, SubgraphFetchAllResponse};
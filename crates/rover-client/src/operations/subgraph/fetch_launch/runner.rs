@@ -0,0 +1,203 @@
+use graphql_client::*;
+use rover_studio::types::GraphRef;
+
+use self::subgraph_fetch_launch_query::SubgraphFetchLaunchQueryGraphVariantLaunchBuildInput;
+use crate::{
+    blocking::StudioClient,
+    operations::subgraph::fetch_launch::SubgraphFetchLaunchInput,
+    shared::{FetchResponse, Sdl, SdlType},
+    RoverClientError,
+};
+
+/// this is because of the custom GraphQLDocument scalar in the schema
+type GraphQLDocument = String;
+type SHA256 = String;
+
+#[derive(GraphQLQuery)]
+// The paths are relative to the directory where your `Cargo.toml` is located.
+// Both json and the GraphQL schema language are supported as sources for the schema
+#[graphql(
+    query_path = "src/operations/subgraph/fetch_launch/fetch_launch_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+/// This struct is used to generate the module containing `Variables` and
+/// `ResponseData` structs.
+/// Snake case of this name is the mod name. i.e. subgraph_fetch_launch_query
+pub(crate) struct SubgraphFetchLaunchQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    query_path = "src/operations/subgraph/fetch_launch/fetch_launch_doc_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+/// Snake case of this name is the mod name. i.e. subgraph_fetch_launch_doc_query
+pub(crate) struct SubgraphFetchLaunchDocQuery;
+
+/// Fetches a subgraph's schema as it was when a specific launch was built. Launches only record
+/// the hash of each subgraph schema, so the SDL itself is looked up by that hash.
+pub async fn run(
+    input: SubgraphFetchLaunchInput,
+    client: &StudioClient,
+) -> Result<FetchResponse, RoverClientError> {
+    let graph_ref = input.graph_ref.clone();
+    let subgraph_name = input.subgraph_name.clone();
+    let launch_id = input.launch_id.clone();
+    let response_data = client
+        .post::<SubgraphFetchLaunchQuery>(input.into())
+        .await?;
+    let subgraph =
+        get_subgraph_from_response_data(response_data, &graph_ref, &subgraph_name, &launch_id)?;
+
+    let doc_response_data = client
+        .post::<SubgraphFetchLaunchDocQuery>(subgraph_fetch_launch_doc_query::Variables {
+            graph_id: graph_ref.graph_id().to_string(),
+            hash: Some(subgraph.hash.clone()),
+        })
+        .await?;
+    let sdl = doc_response_data
+        .graph
+        .and_then(|graph| graph.doc)
+        .map(|doc| doc.source)
+        .ok_or_else(|| RoverClientError::AdhocError {
+            msg: format!(
+                "Could not find the schema for subgraph '{subgraph_name}' in launch '{launch_id}'"
+            ),
+        })?;
+
+    Ok(FetchResponse {
+        sdl: Sdl {
+            contents: sdl,
+            r#type: SdlType::Subgraph {
+                routing_url: subgraph.routing_url,
+            },
+        },
+    })
+}
+
+#[derive(Debug, PartialEq)]
+struct LaunchSubgraph {
+    hash: String,
+    routing_url: Option<String>,
+}
+
+fn get_subgraph_from_response_data(
+    response_data: subgraph_fetch_launch_query::ResponseData,
+    graph_ref: &GraphRef,
+    subgraph_name: &str,
+    launch_id: &str,
+) -> Result<LaunchSubgraph, RoverClientError> {
+    let graph = response_data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphNotFound {
+            graph_ref: graph_ref.clone(),
+        })?;
+
+    let Some(variant) = graph.variant else {
+        return Err(RoverClientError::NoSchemaForVariant {
+            graph_ref: graph_ref.clone(),
+            valid_variants: graph
+                .variants
+                .into_iter()
+                .map(|variant| variant.name)
+                .collect(),
+            frontend_url_root: response_data.frontend_url_root,
+        });
+    };
+    let launch = variant.launch.ok_or_else(|| RoverClientError::AdhocError {
+        msg: format!("Could not find launch '{launch_id}' for '{graph_ref}'"),
+    })?;
+
+    match launch.build_input {
+        SubgraphFetchLaunchQueryGraphVariantLaunchBuildInput::CompositionBuildInput(input) => {
+            let valid_subgraphs = input
+                .subgraphs
+                .iter()
+                .map(|subgraph| subgraph.name.clone())
+                .collect();
+            input
+                .subgraphs
+                .into_iter()
+                .find(|subgraph| subgraph.name == subgraph_name)
+                .map(|subgraph| LaunchSubgraph {
+                    hash: subgraph.hash,
+                    routing_url: Some(subgraph.routing_url),
+                })
+                .ok_or_else(|| RoverClientError::NoSubgraphInGraph {
+                    invalid_subgraph: subgraph_name.to_string(),
+                    valid_subgraphs,
+                })
+        }
+        // contract launches are built by filtering a supergraph, not by composing subgraphs
+        SubgraphFetchLaunchQueryGraphVariantLaunchBuildInput::FilterBuildInput => {
+            Err(RoverClientError::ExpectedFederatedGraph {
+                graph_ref: graph_ref.clone(),
+                can_operation_convert: false,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn get_subgraph_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variants": [{ "name": "current" }],
+                "variant": {
+                    "launch": {
+                        "buildInput": {
+                            "__typename": "CompositionBuildInput",
+                            "subgraphs": [
+                                {
+                                    "name": "products",
+                                    "hash": "abc123",
+                                    "routingURL": "https://products.example.com"
+                                },
+                                {
+                                    "name": "reviews",
+                                    "hash": "def456",
+                                    "routingURL": "https://reviews.example.com"
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        });
+        let data: subgraph_fetch_launch_query::ResponseData =
+            serde_json::from_value(json_response.clone()).unwrap();
+        let output =
+            get_subgraph_from_response_data(data, &mock_graph_ref(), "reviews", "launch-1");
+        assert_eq!(
+            output.unwrap(),
+            LaunchSubgraph {
+                hash: "def456".to_string(),
+                routing_url: Some("https://reviews.example.com".to_string()),
+            }
+        );
+
+        let data: subgraph_fetch_launch_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let output =
+            get_subgraph_from_response_data(data, &mock_graph_ref(), "accounts", "launch-1");
+        assert!(matches!(
+            output,
+            Err(RoverClientError::NoSubgraphInGraph { valid_subgraphs, .. })
+                if valid_subgraphs == vec!["products".to_string(), "reviews".to_string()]
+        ));
+    }
+
+    fn mock_graph_ref() -> GraphRef {
+        GraphRef::new("mygraph", Some("current")).unwrap()
+    }
+}
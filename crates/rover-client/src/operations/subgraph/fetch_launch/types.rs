@@ -0,0 +1,23 @@
+use rover_studio::types::GraphRef;
+
+use crate::operations::subgraph::fetch_launch::runner::subgraph_fetch_launch_query;
+
+type QueryVariables = subgraph_fetch_launch_query::Variables;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubgraphFetchLaunchInput {
+    pub graph_ref: GraphRef,
+    pub subgraph_name: String,
+    pub launch_id: String,
+}
+
+impl From<SubgraphFetchLaunchInput> for QueryVariables {
+    fn from(input: SubgraphFetchLaunchInput) -> Self {
+        let (graph_id, variant) = input.graph_ref.into_parts();
+        Self {
+            graph_id,
+            variant,
+            launch_id: input.launch_id,
+        }
+    }
+}
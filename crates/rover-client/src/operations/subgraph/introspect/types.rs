@@ -1,11 +1,13 @@
-use std::{collections::HashMap, time::Duration};
+use std::collections::HashMap;
+
+use rover_http::RetryConfig;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SubgraphIntrospectInput {
     pub headers: HashMap<String, String>,
     pub endpoint: url::Url,
     pub should_retry: bool,
-    pub retry_period: Duration,
+    pub retry: RetryConfig,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
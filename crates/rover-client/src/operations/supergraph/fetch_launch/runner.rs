@@ -0,0 +1,159 @@
+use apollo_federation_types::rover::BuildError;
+use graphql_client::*;
+use rover_studio::types::GraphRef;
+
+use self::supergraph_fetch_launch_query::SupergraphFetchLaunchQueryGraphVariantLaunchBuildResult;
+use crate::{
+    blocking::StudioClient,
+    operations::supergraph::fetch_launch::SupergraphFetchLaunchInput,
+    shared::{FetchResponse, Sdl, SdlType},
+    RoverClientError,
+};
+
+/// this is because of the custom GraphQLDocument scalar in the schema
+type GraphQLDocument = String;
+
+#[derive(GraphQLQuery)]
+// The paths are relative to the directory where your `Cargo.toml` is located.
+// Both json and the GraphQL schema language are supported as sources for the schema
+#[graphql(
+    query_path = "src/operations/supergraph/fetch_launch/fetch_launch_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+/// This struct is used to generate the module containing `Variables` and
+/// `ResponseData` structs.
+/// Snake case of this name is the mod name. i.e. supergraph_fetch_launch_query
+pub(crate) struct SupergraphFetchLaunchQuery;
+
+/// Fetches the core schema that a specific launch built, rather than the latest one
+pub async fn run(
+    input: SupergraphFetchLaunchInput,
+    client: &StudioClient,
+) -> Result<FetchResponse, RoverClientError> {
+    let graph_ref = input.graph_ref.clone();
+    let launch_id = input.launch_id.clone();
+    let response_data = client
+        .post::<SupergraphFetchLaunchQuery>(input.into())
+        .await?;
+    get_supergraph_sdl_from_response_data(response_data, graph_ref, &launch_id)
+}
+
+fn get_supergraph_sdl_from_response_data(
+    response_data: supergraph_fetch_launch_query::ResponseData,
+    graph_ref: GraphRef,
+    launch_id: &str,
+) -> Result<FetchResponse, RoverClientError> {
+    let graph = response_data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphNotFound {
+            graph_ref: graph_ref.clone(),
+        })?;
+
+    let Some(variant) = graph.variant else {
+        return Err(RoverClientError::NoSchemaForVariant {
+            graph_ref,
+            valid_variants: graph
+                .variants
+                .into_iter()
+                .map(|variant| variant.name)
+                .collect(),
+            frontend_url_root: response_data.frontend_url_root,
+        });
+    };
+    let launch = variant.launch.ok_or_else(|| RoverClientError::AdhocError {
+        msg: format!("Could not find launch '{launch_id}' for '{graph_ref}'"),
+    })?;
+
+    match launch.build.and_then(|build| build.result) {
+        Some(SupergraphFetchLaunchQueryGraphVariantLaunchBuildResult::BuildFailure(failure)) => {
+            Err(RoverClientError::NoSupergraphBuilds {
+                graph_ref,
+                source: failure
+                    .error_messages
+                    .into_iter()
+                    .map(|error| {
+                        BuildError::composition_error(error.code, Some(error.message), None, None)
+                    })
+                    .collect(),
+            })
+        }
+        Some(SupergraphFetchLaunchQueryGraphVariantLaunchBuildResult::BuildSuccess(success)) => {
+            Ok(FetchResponse {
+                sdl: Sdl {
+                    contents: success.core_schema.core_document,
+                    r#type: SdlType::Supergraph,
+                },
+            })
+        }
+        None => Err(RoverClientError::AdhocError {
+            msg: format!("Launch '{launch_id}' for '{graph_ref}' has not finished building yet"),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn get_supergraph_sdl_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variants": [{ "name": "current" }],
+                "variant": {
+                    "launch": {
+                        "build": {
+                            "result": {
+                                "__typename": "BuildSuccess",
+                                "coreSchema": {
+                                    "coreDocument": "type Query { hello: String }",
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        });
+        let data: supergraph_fetch_launch_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let output = get_supergraph_sdl_from_response_data(data, mock_graph_ref(), "launch-1");
+
+        assert_eq!(
+            output.unwrap(),
+            FetchResponse {
+                sdl: Sdl {
+                    contents: "type Query { hello: String }".to_string(),
+                    r#type: SdlType::Supergraph,
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn get_supergraph_sdl_from_response_data_errs_on_unknown_launch() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variants": [{ "name": "current" }],
+                "variant": { "launch": null },
+            },
+        });
+        let data: supergraph_fetch_launch_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let output = get_supergraph_sdl_from_response_data(data, mock_graph_ref(), "launch-1");
+
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "Could not find launch 'launch-1' for 'mygraph@current'"
+        );
+    }
+
+    fn mock_graph_ref() -> GraphRef {
+        GraphRef::new("mygraph", Some("current")).unwrap()
+    }
+}
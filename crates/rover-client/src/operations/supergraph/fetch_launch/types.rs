@@ -0,0 +1,22 @@
+use rover_studio::types::GraphRef;
+
+use crate::operations::supergraph::fetch_launch::runner::supergraph_fetch_launch_query;
+
+type QueryVariables = supergraph_fetch_launch_query::Variables;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SupergraphFetchLaunchInput {
+    pub graph_ref: GraphRef,
+    pub launch_id: String,
+}
+
+impl From<SupergraphFetchLaunchInput> for QueryVariables {
+    fn from(input: SupergraphFetchLaunchInput) -> Self {
+        let (graph_id, variant) = input.graph_ref.into_parts();
+        Self {
+            graph_id,
+            variant,
+            launch_id: input.launch_id,
+        }
+    }
+}
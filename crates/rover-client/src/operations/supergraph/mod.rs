@@ -1,2 +1,5 @@
 /// "supergraph fetch"
 pub mod fetch;
+
+/// "supergraph fetch --launch-id"
+pub mod fetch_launch;
@@ -0,0 +1,244 @@
+use apollo_federation_types::rover::BuildErrors;
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+
+use crate::{
+    blocking::StudioClient,
+    operations::{
+        graph::import::{self, GraphImportInput, ImportSubgraph},
+        subgraph::fetch_all::{self, SubgraphFetchAllInput},
+        variant::{
+            describe::find_variant,
+            list::{self, ListVariantsInput},
+        },
+    },
+    RoverClientError,
+};
+
+#[derive(Clone)]
+pub struct CreateVariantInput {
+    /// The variant to create
+    pub graph_ref: GraphRef,
+    /// The existing variant in the same graph whose subgraphs are copied
+    pub source_variant: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct CreateVariantResponse {
+    pub subgraphs_created: Vec<String>,
+    pub federation_version: Option<String>,
+    #[serde(skip_serializing)]
+    pub build_errors: BuildErrors,
+    pub launch_url: Option<String>,
+}
+
+/// Creates a variant by publishing every subgraph of an existing variant to it. GraphOS has no
+/// way to create an empty variant; a variant comes into existence with its first publish.
+pub async fn run(
+    input: CreateVariantInput,
+    client: &StudioClient,
+) -> Result<CreateVariantResponse, RoverClientError> {
+    let (graph_id, variant) = input.graph_ref.clone().into_parts();
+    let source_ref = GraphRef::new(graph_id.clone(), Some(input.source_variant.clone()))?;
+
+    let list_response = list::run(
+        ListVariantsInput {
+            graph_id: graph_id.clone(),
+        },
+        client,
+    )
+    .await?;
+    if list_response
+        .variants
+        .iter()
+        .any(|existing| existing.name == variant)
+    {
+        return Err(RoverClientError::AdhocError {
+            msg: format!("Variant '{}' already exists", input.graph_ref),
+        });
+    }
+    let source = find_variant(list_response, source_ref.clone())?.variant;
+    if source.contract.is_some() {
+        return Err(RoverClientError::AdhocError {
+            msg: format!(
+                "'{source_ref}' is a contract variant and can't be copied. Create contract variants with `rover contract publish` instead."
+            ),
+        });
+    }
+
+    let source_subgraphs = fetch_all::run(
+        SubgraphFetchAllInput {
+            graph_ref: source_ref.clone(),
+        },
+        client,
+    )
+    .await?
+    .subgraphs;
+    if source_subgraphs.is_empty() {
+        return Err(RoverClientError::AdhocError {
+            msg: format!("'{source_ref}' has no subgraphs to copy"),
+        });
+    }
+
+    let import_response = import::run(
+        GraphImportInput {
+            graph_ref: input.graph_ref,
+            subgraphs: source_subgraphs
+                .into_iter()
+                .map(|subgraph| ImportSubgraph {
+                    name: subgraph.name().clone(),
+                    url: subgraph.url().clone(),
+                    sdl: subgraph.sdl().clone(),
+                })
+                .collect(),
+            // a new variant would otherwise build with the default federation version
+            federation_version: source.federation_version,
+            revision: format!("copied from {source_ref}"),
+        },
+        client,
+    )
+    .await?;
+
+    Ok(CreateVariantResponse {
+        subgraphs_created: import_response.subgraphs_created,
+        federation_version: import_response.federation_version,
+        build_errors: import_response.build_errors,
+        launch_url: import_response.launch_url,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+    use crate::operations::proposal::tests::client;
+
+    fn mock_source(server: &MockServer) {
+        server.mock(|when, then| {
+            when.method(POST).body_includes("ListVariantsQuery");
+            then.status(200).json_body(json!({
+                "data": {
+                    "frontendUrlRoot": "https://studio.apollographql.com",
+                    "graph": {
+                        "variants": [{
+                            "name": "current",
+                            "createdAt": "2024-03-01T10:00:00Z",
+                            "federationVersion": "2.9",
+                            "isProposal": false,
+                            "sourceVariant": null,
+                            "contractFilterConfigDescription": null,
+                            "persistedQueryList": null,
+                            "subgraphs": [{ "name": "products" }]
+                        }]
+                    }
+                }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(POST).body_includes("SubgraphFetchAllQuery");
+            then.status(200).json_body(json!({
+                "data": {
+                    "variant": {
+                        "__typename": "GraphVariant",
+                        "subgraphs": [{
+                            "name": "products",
+                            "url": "http://products",
+                            "activePartialSchema": { "sdl": "type Query { products: [String] }" }
+                        }],
+                        "latestLaunch": null,
+                        "sourceVariant": null
+                    }
+                }
+            }));
+        });
+    }
+
+    fn mock_publish(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("GraphImportPublishSubgraphsMutation")
+                .body_includes(r#""variant":"staging""#)
+                .body_includes(r#""revision":"copied from mygraph@current""#);
+            then.status(200).json_body(json!({
+                "data": {
+                    "graph": {
+                        "publishSubgraphs": {
+                            "errors": [],
+                            "subgraphsCreated": ["products"],
+                            "launchUrl": "https://studio.apollographql.com/launches/1"
+                        }
+                    }
+                }
+            }));
+        })
+    }
+
+    fn mock_update_federation_version(
+        server: &MockServer,
+        variant: serde_json::Value,
+    ) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST)
+                .body_includes("GraphImportUpdateFederationVersionMutation")
+                .body_includes(r#""federation_version":"2.9""#);
+            then.status(200)
+                .json_body(json!({ "data": { "graph": { "variant": variant } } }));
+        })
+    }
+
+    fn input() -> CreateVariantInput {
+        CreateVariantInput {
+            graph_ref: GraphRef::new("mygraph", Some("staging")).unwrap(),
+            source_variant: "current".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_sets_the_federation_version_before_publishing() {
+        let server = MockServer::start();
+        mock_source(&server);
+        let publish = mock_publish(&server);
+        let update = mock_update_federation_version(
+            &server,
+            json!({ "updateVariantFederationVersion": { "federationVersion": "2.9" } }),
+        );
+
+        let response = run(input(), &client(&server)).await.unwrap();
+
+        publish.assert();
+        update.assert_calls(1);
+        assert_eq!(response.subgraphs_created, vec!["products".to_string()]);
+        assert_eq!(response.federation_version, Some("2.9".to_string()));
+    }
+
+    #[tokio::test]
+    async fn it_sets_the_federation_version_after_publishing_if_the_variant_was_missing() {
+        let server = MockServer::start();
+        mock_source(&server);
+        let publish = mock_publish(&server);
+        let update = mock_update_federation_version(&server, serde_json::Value::Null);
+
+        run(input(), &client(&server)).await.unwrap();
+
+        publish.assert();
+        update.assert_calls(2);
+    }
+
+    #[tokio::test]
+    async fn it_refuses_to_overwrite_an_existing_variant() {
+        let server = MockServer::start();
+        mock_source(&server);
+        let publish = mock_publish(&server);
+        let input = CreateVariantInput {
+            graph_ref: GraphRef::new("mygraph", Some("current")).unwrap(),
+            source_variant: "current".to_string(),
+        };
+
+        let err = run(input, &client(&server)).await.unwrap_err();
+
+        publish.assert_calls(0);
+        assert!(err.to_string().contains("already exists"));
+    }
+}
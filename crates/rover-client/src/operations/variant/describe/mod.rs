@@ -0,0 +1,66 @@
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+
+use crate::{
+    blocking::StudioClient,
+    operations::variant::list::{self, ListVariantsInput, ListVariantsResponse, VariantInfo},
+    RoverClientError,
+};
+
+#[derive(Clone)]
+pub struct DescribeVariantInput {
+    pub graph_ref: GraphRef,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct DescribeVariantResponse {
+    #[serde(skip_serializing)]
+    pub graph_ref: GraphRef,
+    #[serde(flatten)]
+    pub variant: VariantInfo,
+    #[serde(skip_serializing)]
+    pub frontend_url_root: String,
+}
+
+/// Describes a single variant. This reuses the variant listing so that an unknown variant can be
+/// reported alongside the variants that do exist.
+pub async fn run(
+    input: DescribeVariantInput,
+    client: &StudioClient,
+) -> Result<DescribeVariantResponse, RoverClientError> {
+    let list_response = list::run(
+        ListVariantsInput {
+            graph_id: input.graph_ref.graph_id().to_string(),
+        },
+        client,
+    )
+    .await?;
+    find_variant(list_response, input.graph_ref)
+}
+
+pub(crate) fn find_variant(
+    list_response: ListVariantsResponse,
+    graph_ref: GraphRef,
+) -> Result<DescribeVariantResponse, RoverClientError> {
+    let valid_variants = list_response
+        .variants
+        .iter()
+        .map(|variant| variant.name.clone())
+        .collect();
+    match list_response
+        .variants
+        .into_iter()
+        .find(|variant| &variant.name == graph_ref.variant())
+    {
+        Some(variant) => Ok(DescribeVariantResponse {
+            graph_ref,
+            variant,
+            frontend_url_root: list_response.frontend_url_root,
+        }),
+        None => Err(RoverClientError::NoSchemaForVariant {
+            graph_ref,
+            valid_variants,
+            frontend_url_root: list_response.frontend_url_root,
+        }),
+    }
+}
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use graphql_client::GraphQLQuery;
+use serde::Serialize;
+
+use crate::{
+    blocking::StudioClient,
+    operations::variant::list::list_variants_query::ListVariantsQueryGraphVariants,
+    RoverClientError,
+};
+
+type RemoteVariant = ListVariantsQueryGraphVariants;
+type Timestamp = String;
+
+#[derive(GraphQLQuery, Debug)]
+#[graphql(
+    query_path = "src/operations/variant/list/list_variants_query.graphql",
+    schema_path = ".schema/schema.graphql",
+    response_derives = "Eq, PartialEq, Debug, Serialize, Deserialize",
+    deprecated = "warn"
+)]
+struct ListVariantsQuery;
+
+#[derive(Clone)]
+pub struct ListVariantsInput {
+    pub graph_id: String,
+}
+
+impl From<ListVariantsInput> for list_variants_query::Variables {
+    fn from(value: ListVariantsInput) -> Self {
+        list_variants_query::Variables {
+            graph_id: value.graph_id,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct ListVariantsResponse {
+    pub graph_id: String,
+    /// Sorted by name. Proposal variants are left out, since they're managed as proposals.
+    pub variants: Vec<VariantInfo>,
+    #[serde(skip_serializing)]
+    pub frontend_url_root: String,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct VariantInfo {
+    pub name: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub federation_version: Option<String>,
+    /// Only set for contract variants
+    pub contract: Option<VariantContract>,
+    pub persisted_query_list: Option<VariantPersistedQueryList>,
+    pub subgraphs: Vec<String>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct VariantContract {
+    pub source_variant: String,
+    pub filter_description: Option<String>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct VariantPersistedQueryList {
+    pub id: String,
+    pub name: String,
+}
+
+pub async fn run(
+    input: ListVariantsInput,
+    client: &StudioClient,
+) -> Result<ListVariantsResponse, RoverClientError> {
+    let graph_id = input.graph_id.clone();
+    let data = client.post::<ListVariantsQuery>(input.into()).await?;
+    list_variants_from_response_data(data, graph_id)
+}
+
+fn list_variants_from_response_data(
+    data: list_variants_query::ResponseData,
+    graph_id: String,
+) -> Result<ListVariantsResponse, RoverClientError> {
+    let graph = data
+        .graph
+        .ok_or_else(|| RoverClientError::GraphIdNotFound {
+            graph_id: graph_id.clone(),
+        })?;
+    let mut variants: Vec<VariantInfo> = graph
+        .variants
+        .into_iter()
+        .filter(|variant| variant.is_proposal != Some(true))
+        .map(VariantInfo::from)
+        .collect();
+    variants.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ListVariantsResponse {
+        graph_id,
+        variants,
+        frontend_url_root: data.frontend_url_root,
+    })
+}
+
+impl From<RemoteVariant> for VariantInfo {
+    fn from(value: RemoteVariant) -> Self {
+        let contract = value.source_variant.map(|source| VariantContract {
+            source_variant: source.name,
+            filter_description: value.contract_filter_config_description,
+        });
+        Self {
+            name: value.name,
+            created_at: value.created_at.parse().ok(),
+            federation_version: value.federation_version,
+            contract,
+            persisted_query_list: value.persisted_query_list.map(|list| {
+                VariantPersistedQueryList {
+                    id: list.id,
+                    name: list.name,
+                }
+            }),
+            subgraphs: value
+                .subgraphs
+                .unwrap_or_default()
+                .into_iter()
+                .map(|subgraph| subgraph.name)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn list_variants_from_response_data_works() {
+        let json_response = json!({
+            "frontendUrlRoot": "https://studio.apollographql.com",
+            "graph": {
+                "variants": [
+                    {
+                        "name": "staging",
+                        "createdAt": "2024-03-01T10:00:00Z",
+                        "federationVersion": "2.9",
+                        "isProposal": false,
+                        "sourceVariant": null,
+                        "contractFilterConfigDescription": null,
+                        "persistedQueryList": { "id": "pql-1", "name": "staging list" },
+                        "subgraphs": [{ "name": "products" }, { "name": "reviews" }]
+                    },
+                    {
+                        "name": "p-101",
+                        "createdAt": "2024-03-02T10:00:00Z",
+                        "federationVersion": "2.9",
+                        "isProposal": true,
+                        "sourceVariant": null,
+                        "contractFilterConfigDescription": null,
+                        "persistedQueryList": null,
+                        "subgraphs": []
+                    },
+                    {
+                        "name": "public",
+                        "createdAt": "2024-03-03T10:00:00Z",
+                        "federationVersion": null,
+                        "isProposal": false,
+                        "sourceVariant": { "name": "staging" },
+                        "contractFilterConfigDescription": "Excludes @internal",
+                        "persistedQueryList": null,
+                        "subgraphs": null
+                    }
+                ]
+            }
+        });
+        let data: list_variants_query::ResponseData =
+            serde_json::from_value(json_response).unwrap();
+        let response = list_variants_from_response_data(data, "mygraph".to_string()).unwrap();
+
+        let names: Vec<&str> = response
+            .variants
+            .iter()
+            .map(|variant| variant.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["public", "staging"]);
+        assert_eq!(
+            response.variants[0].contract,
+            Some(VariantContract {
+                source_variant: "staging".to_string(),
+                filter_description: Some("Excludes @internal".to_string()),
+            })
+        );
+        assert_eq!(response.variants[1].subgraphs.len(), 2);
+        assert_eq!(
+            response.variants[1].persisted_query_list,
+            Some(VariantPersistedQueryList {
+                id: "pql-1".to_string(),
+                name: "staging list".to_string(),
+            })
+        );
+    }
+}
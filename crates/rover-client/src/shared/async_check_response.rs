@@ -1,6 +1,16 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
 use serde::Serialize;
 use serde_json::{json, Value};
 
+use crate::{shared::CheckWorkflowResponse, RoverClientError};
+
+/// How long to wait between fetches of a check workflow that hasn't finished yet
+pub(crate) const CHECK_WORKFLOW_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 /// CheckRequestSuccessResult is the return type of the
 /// `graph` and `subgraph` async check operations
 
@@ -18,3 +28,132 @@ impl CheckRequestSuccessResult {
         })
     }
 }
+
+/// CheckWorkflowSnapshot is the state of a check workflow at the moment
+/// it was fetched, without waiting for it to finish
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CheckWorkflowSnapshot {
+    Pending { target_url: Option<String> },
+    Completed(Box<CheckWorkflowResponse>),
+}
+
+/// Fetches a check workflow until it finishes, or until `timeout` has passed.
+///
+/// Failed fetches may be transient, so they're retried. Errors from `into_snapshot`, such as
+/// a missing graph or a failed check, are returned as they are.
+pub(crate) async fn wait_for_check_workflow<T, Fut>(
+    timeout: Duration,
+    poll_interval: Duration,
+    mut fetch: impl FnMut() -> Fut,
+    mut into_snapshot: impl FnMut(T) -> Result<CheckWorkflowSnapshot, RoverClientError>,
+) -> Result<CheckWorkflowResponse, RoverClientError>
+where
+    Fut: Future<Output = Result<T, RoverClientError>>,
+{
+    let mut url = None;
+    let now = Instant::now();
+    loop {
+        match fetch().await {
+            Ok(data) => match into_snapshot(data)? {
+                CheckWorkflowSnapshot::Completed(check_response) => return Ok(*check_response),
+                CheckWorkflowSnapshot::Pending { target_url } => url = target_url,
+            },
+            Err(e) => {
+                eprintln!(
+                    "error while checking status of check: {e}\nthis error may be transient... retrying"
+                );
+            }
+        }
+        if now.elapsed() > timeout {
+            return Err(RoverClientError::ChecksTimeoutError { url });
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn check_response() -> CheckWorkflowResponse {
+        CheckWorkflowResponse {
+            default_target_url: "https://studio.apollographql.com/graph/mygraph/checks".to_string(),
+            maybe_core_schema_modified: None,
+            maybe_operations_response: None,
+            maybe_lint_response: None,
+            maybe_custom_response: None,
+            maybe_proposals_response: None,
+            maybe_downstream_response: None,
+            maybe_proposed_schema: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_polls_until_the_check_completes() {
+        let fetches = Cell::new(0);
+        let response = wait_for_check_workflow(
+            Duration::from_secs(60),
+            Duration::ZERO,
+            || {
+                fetches.set(fetches.get() + 1);
+                let fetch = fetches.get();
+                async move {
+                    match fetch {
+                        1 => Err(RoverClientError::AdhocError {
+                            msg: "connection reset".to_string(),
+                        }),
+                        2 => Ok(false),
+                        _ => Ok(true),
+                    }
+                }
+            },
+            |completed| {
+                Ok(if completed {
+                    CheckWorkflowSnapshot::Completed(Box::new(check_response()))
+                } else {
+                    CheckWorkflowSnapshot::Pending { target_url: None }
+                })
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(response, check_response());
+        assert_eq!(fetches.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn it_times_out_with_the_last_known_url() {
+        let err = wait_for_check_workflow(
+            Duration::ZERO,
+            Duration::ZERO,
+            || async { Ok(()) },
+            |()| {
+                Ok(CheckWorkflowSnapshot::Pending {
+                    target_url: Some("https://studio.apollographql.com/check".to_string()),
+                })
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            RoverClientError::ChecksTimeoutError { url: Some(url) }
+                if url == "https://studio.apollographql.com/check"
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_returns_errors_from_the_snapshot() {
+        let err = wait_for_check_workflow(
+            Duration::from_secs(60),
+            Duration::ZERO,
+            || async { Ok(()) },
+            |()| Err(RoverClientError::UnknownCheckWorkflowStatus),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, RoverClientError::UnknownCheckWorkflowStatus));
+    }
+}
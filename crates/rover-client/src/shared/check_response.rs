@@ -24,6 +24,11 @@ pub struct CheckWorkflowResponse {
     pub maybe_custom_response: Option<CustomCheckResponse>,
 
     pub maybe_downstream_response: Option<DownstreamCheckResponse>,
+
+    // None here means the schema wasn't available to Rover, like when
+    // fetching the result of a check started in an earlier run.
+    #[serde(skip)]
+    pub maybe_proposed_schema: Option<ProposedSchema>,
 }
 
 impl CheckWorkflowResponse {
@@ -124,6 +129,105 @@ impl CheckWorkflowResponse {
         json_result
     }
 
+    /// Renders the result of every task as Markdown, for pasting into a pull request comment.
+    /// Tasks that didn't pass are expanded.
+    pub fn get_markdown(&self) -> String {
+        let statuses = [
+            self.maybe_operations_response
+                .as_ref()
+                .map(|response| &response.task_status),
+            self.maybe_lint_response
+                .as_ref()
+                .map(|response| &response.task_status),
+            self.maybe_proposals_response
+                .as_ref()
+                .map(|response| &response.task_status),
+            self.maybe_custom_response
+                .as_ref()
+                .map(|response| &response.task_status),
+            self.maybe_downstream_response
+                .as_ref()
+                .map(|response| &response.task_status),
+        ];
+        let passed = statuses
+            .iter()
+            .flatten()
+            .all(|status| matches!(status, CheckTaskStatus::PASSED));
+
+        let mut msg = String::new();
+        msg.push_str(if passed {
+            "### ✅ Schema check passed\n\n"
+        } else {
+            "### ❌ Schema check failed\n\n"
+        });
+
+        if let Some(core_schema_modified) = self.maybe_core_schema_modified {
+            msg.push_str(if core_schema_modified {
+                "There were no changes detected in the composed API schema, but the core schema was modified.\n\n"
+            } else {
+                "There were no changes detected in the composed schema.\n\n"
+            });
+        }
+
+        if let Some(operations_response) = &self.maybe_operations_response {
+            msg.push_str(&Self::markdown_section(
+                "Operation Check",
+                &operations_response.task_status,
+                &operations_response.get_markdown(),
+            ));
+        }
+
+        if let Some(lint_response) = &self.maybe_lint_response {
+            msg.push_str(&Self::markdown_section(
+                "Linter Check",
+                &lint_response.task_status,
+                &lint_response.get_markdown(),
+            ));
+        }
+
+        if let Some(proposals_response) = &self.maybe_proposals_response {
+            msg.push_str(&Self::markdown_section(
+                "Proposals Check",
+                &proposals_response.task_status,
+                &proposals_response.get_markdown(),
+            ));
+        }
+
+        if let Some(custom_response) = &self.maybe_custom_response {
+            msg.push_str(&Self::markdown_section(
+                "Custom Check",
+                &custom_response.task_status,
+                &custom_response.get_markdown(),
+            ));
+        }
+
+        if let Some(downstream_response) = &self.maybe_downstream_response {
+            msg.push_str(&Self::markdown_section(
+                "Downstream Check",
+                &downstream_response.task_status,
+                &downstream_response.get_markdown(),
+            ));
+        }
+
+        msg.push_str(&format!(
+            "[View the check in GraphOS Studio]({})\n",
+            self.default_target_url
+        ));
+
+        msg
+    }
+
+    fn markdown_section(title: &str, status: &CheckTaskStatus, body: &str) -> String {
+        let open = match status {
+            CheckTaskStatus::PASSED => "",
+            _ => " open",
+        };
+        format!(
+            "<details{open}>\n<summary><b>{title}</b> {}</summary>\n\n{body}\n</details>\n\n",
+            status.markdown_badge()
+        )
+    }
+
     fn task_title(title: &str, status: CheckTaskStatus) -> String {
         format!(
             "\n{} [{}]:\n",
@@ -171,6 +275,10 @@ impl OperationCheckResponse {
         }
     }
 
+    pub fn changes(&self) -> &[SchemaChange] {
+        &self.changes
+    }
+
     pub fn get_table(&self) -> String {
         let mut table = Table::new();
         table.load_preset(UTF8_FULL);
@@ -195,9 +303,9 @@ impl OperationCheckResponse {
         let mut msg = String::new();
 
         msg.push_str(&format!(
-            "Compared {} schema changes against {} operations.",
-            self.changes.len(),
-            self.operation_check_count
+            "Compared {} against {}.",
+            count_of(self.changes.len() as u64, "schema change"),
+            count_of(self.operation_check_count, "operation")
         ));
 
         msg.push('\n');
@@ -212,6 +320,40 @@ impl OperationCheckResponse {
         msg
     }
 
+    pub fn get_markdown(&self) -> String {
+        let mut msg = format!(
+            "Compared {} against {}.\n",
+            count_of(self.changes.len() as u64, "schema change"),
+            count_of(self.operation_check_count, "operation")
+        );
+
+        if !self.changes.is_empty() {
+            msg.push_str("\n| Change | Code | Coordinate | Description |\n|---|---|---|---|\n");
+            for change in &self.changes {
+                let badge = match change.severity {
+                    ChangeSeverity::FAIL => "🔴 FAIL",
+                    ChangeSeverity::PASS => "🟢 PASS",
+                };
+                msg.push_str(&format!(
+                    "| {badge} | `{}` | {} | {} |\n",
+                    change.code,
+                    change
+                        .coordinate
+                        .as_ref()
+                        .map(|coordinate| format!("`{coordinate}`"))
+                        .unwrap_or_default(),
+                    markdown_cell(&change.description)
+                ));
+            }
+        }
+
+        msg.push_str(&markdown_link(
+            "View operation check details",
+            &self.target_url,
+        ));
+        msg
+    }
+
     pub fn get_json(&self) -> Value {
         json!(self)
     }
@@ -290,6 +432,41 @@ impl LintCheckResponse {
         msg
     }
 
+    pub fn get_markdown(&self) -> String {
+        let mut msg = format!(
+            "Found {} and {}.\n",
+            count_of(self.errors_count, "error"),
+            count_of(self.warnings_count, "warning")
+        );
+
+        if !self.diagnostics.is_empty() {
+            msg.push_str(
+                "\n| Level | Rule | Coordinate | Line | Description |\n|---|---|---|---|---|\n",
+            );
+            for diagnostic in &self.diagnostics {
+                // not every diagnostic points at a schema element
+                let coordinate = match diagnostic.coordinate.as_str() {
+                    "" => String::new(),
+                    coordinate => format!("`{coordinate}`"),
+                };
+                msg.push_str(&format!(
+                    "| {} | `{}` | {} | {} | {} |\n",
+                    level_badge(&diagnostic.level),
+                    diagnostic.rule,
+                    coordinate,
+                    diagnostic.start_line,
+                    markdown_cell(&diagnostic.message)
+                ));
+            }
+        }
+
+        msg.push_str(&markdown_link(
+            "View linter check details",
+            &self.target_url,
+        ));
+        msg
+    }
+
     pub fn get_json(&self) -> Value {
         json!(self)
     }
@@ -377,6 +554,27 @@ impl ProposalsCheckResponse {
         msg
     }
 
+    pub fn get_markdown(&self) -> String {
+        let mut msg = format!("{}\n", self.get_msg());
+
+        if !self.related_proposals.is_empty() {
+            msg.push_str("\n| Status | Proposal Name |\n|---|---|\n");
+            for proposal in &self.related_proposals {
+                msg.push_str(&format!(
+                    "| {} | {} |\n",
+                    proposal.status,
+                    markdown_cell(&proposal.display_name)
+                ));
+            }
+        }
+
+        msg.push_str(&markdown_link(
+            "View proposal check details",
+            &self.target_url,
+        ));
+        msg
+    }
+
     pub fn get_json(&self) -> Value {
         json!(self)
     }
@@ -450,6 +648,34 @@ impl CustomCheckResponse {
         msg
     }
 
+    pub fn get_markdown(&self) -> String {
+        let mut msg = String::new();
+
+        if self.violations.is_empty() {
+            msg.push_str("No custom check violations found.\n");
+        } else {
+            msg.push_str("| Level | Rule | Line | Message |\n|---|---|---|---|\n");
+            for violation in &self.violations {
+                msg.push_str(&format!(
+                    "| {} | `{}` | {} | {} |\n",
+                    level_badge(&violation.level),
+                    violation.rule,
+                    violation
+                        .start_line
+                        .map(|line| line.to_string())
+                        .unwrap_or_default(),
+                    markdown_cell(&violation.message)
+                ));
+            }
+        }
+
+        msg.push_str(&markdown_link(
+            "View custom check details",
+            &self.target_url,
+        ));
+        msg
+    }
+
     pub fn get_json(&self) -> Value {
         json!(self)
     }
@@ -497,6 +723,25 @@ impl DownstreamCheckResponse {
         msg
     }
 
+    pub fn get_markdown(&self) -> String {
+        let mut msg = String::new();
+
+        if self.blocking_variants.is_empty() {
+            msg.push_str("No blocking downstream variants failed their checks.\n");
+        } else {
+            msg.push_str("These blocking downstream variants failed their checks:\n\n");
+            for variant in &self.blocking_variants {
+                msg.push_str(&format!("- `{variant}`\n"));
+            }
+        }
+
+        msg.push_str(&markdown_link(
+            "View downstream check details",
+            &self.target_url,
+        ));
+        msg
+    }
+
     pub fn get_json(&self) -> Value {
         json!(self)
     }
@@ -521,6 +766,46 @@ impl AsRef<str> for CheckTaskStatus {
     }
 }
 
+impl CheckTaskStatus {
+    const fn markdown_badge(&self) -> &'static str {
+        match self {
+            CheckTaskStatus::BLOCKED => "⛔ BLOCKED",
+            CheckTaskStatus::FAILED => "❌ FAILED",
+            CheckTaskStatus::PASSED => "✅ PASSED",
+            CheckTaskStatus::PENDING => "⏳ PENDING",
+        }
+    }
+}
+
+/// Marks a lint diagnostic or custom check violation level, like `ERROR`, with a colored circle
+fn level_badge(level: &str) -> String {
+    let circle = match level {
+        "ERROR" => "🔴",
+        "WARNING" => "🟡",
+        _ => "⚪",
+    };
+    format!("{circle} {level}")
+}
+
+/// Escapes text for a Markdown table cell, which has to stay on one line
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn count_of(count: u64, noun: &str) -> String {
+    match count {
+        1 => format!("1 {noun}"),
+        _ => format!("{count} {noun}s"),
+    }
+}
+
+fn markdown_link(text: &str, url: &Option<String>) -> String {
+    match url {
+        Some(url) => format!("\n[{text}]({url})\n"),
+        None => String::new(),
+    }
+}
+
 /// ChangeSeverity indicates whether a proposed change
 /// in a GraphQL schema passed or failed the check
 #[derive(Debug, Serialize, Clone, Eq, PartialEq)]
@@ -565,6 +850,37 @@ pub struct SchemaChange {
 
     /// The severity of a given change
     pub severity: ChangeSeverity,
+
+    /// The schema coordinate of the changed element
+    /// e.g. 'Query.products(limit:)'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinate: Option<String>,
+}
+
+impl SchemaChange {
+    /// Builds a schema coordinate from the type, field or value, and argument a change affected
+    pub fn coordinate(
+        parent: Option<String>,
+        child: Option<String>,
+        arg: Option<String>,
+    ) -> Option<String> {
+        let mut coordinate = parent?;
+        if let Some(child) = child {
+            coordinate.push('.');
+            coordinate.push_str(&child);
+            if let Some(arg) = arg {
+                coordinate.push_str(&format!("({arg}:)"));
+            }
+        }
+        Some(coordinate)
+    }
+}
+
+/// The schema a check was run against, so check results can point at lines in it
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProposedSchema {
+    pub file_name: String,
+    pub sdl: String,
 }
 
 /// CheckConfig is used as an input to check operations
@@ -615,3 +931,118 @@ impl Display for Period {
         write!(f, "{period}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_renders_markdown_for_every_task() {
+        let check_response = CheckWorkflowResponse {
+            default_target_url: "https://studio.apollographql.com/graph/my-graph/checks"
+                .to_string(),
+            maybe_core_schema_modified: Some(true),
+            maybe_operations_response: Some(OperationCheckResponse::try_new(
+                CheckTaskStatus::FAILED,
+                Some("https://studio.apollographql.com/operations".to_string()),
+                12,
+                vec![SchemaChange {
+                    code: "FIELD_REMOVED".to_string(),
+                    description: "type `Query`: field `products` removed".to_string(),
+                    severity: ChangeSeverity::FAIL,
+                    coordinate: Some("Query.products".to_string()),
+                }],
+            )),
+            maybe_lint_response: Some(LintCheckResponse {
+                task_status: CheckTaskStatus::PASSED,
+                target_url: None,
+                diagnostics: vec![
+                    Diagnostic {
+                        level: "WARNING".to_string(),
+                        message: "Fields must use camelCase | snake_case is not allowed"
+                            .to_string(),
+                        coordinate: "Query.all_products".to_string(),
+                        start_line: 3,
+                        start_byte_offset: 20,
+                        end_byte_offset: 32,
+                        rule: "FIELD_NAMES_SHOULD_BE_CAMEL_CASE".to_string(),
+                    },
+                    Diagnostic {
+                        level: "WARNING".to_string(),
+                        message: "Schema element is missing a description".to_string(),
+                        coordinate: String::new(),
+                        start_line: 1,
+                        start_byte_offset: 0,
+                        end_byte_offset: 10,
+                        rule: "ALL_ELEMENTS_REQUIRE_DESCRIPTION".to_string(),
+                    },
+                ],
+                errors_count: 0,
+                warnings_count: 2,
+            }),
+            maybe_proposals_response: None,
+            maybe_custom_response: Some(CustomCheckResponse {
+                task_status: CheckTaskStatus::PASSED,
+                target_url: None,
+                violations: vec![],
+            }),
+            maybe_downstream_response: Some(DownstreamCheckResponse {
+                task_status: CheckTaskStatus::BLOCKED,
+                target_url: None,
+                blocking_variants: vec!["my-graph@prod".to_string()],
+            }),
+            maybe_proposed_schema: None,
+        };
+
+        assert_eq!(
+            check_response.get_markdown(),
+            r#"### ❌ Schema check failed
+
+There were no changes detected in the composed API schema, but the core schema was modified.
+
+<details open>
+<summary><b>Operation Check</b> ❌ FAILED</summary>
+
+Compared 1 schema change against 12 operations.
+
+| Change | Code | Coordinate | Description |
+|---|---|---|---|
+| 🔴 FAIL | `FIELD_REMOVED` | `Query.products` | type `Query`: field `products` removed |
+
+[View operation check details](https://studio.apollographql.com/operations)
+
+</details>
+
+<details>
+<summary><b>Linter Check</b> ✅ PASSED</summary>
+
+Found 0 errors and 2 warnings.
+
+| Level | Rule | Coordinate | Line | Description |
+|---|---|---|---|---|
+| 🟡 WARNING | `FIELD_NAMES_SHOULD_BE_CAMEL_CASE` | `Query.all_products` | 3 | Fields must use camelCase \| snake_case is not allowed |
+| 🟡 WARNING | `ALL_ELEMENTS_REQUIRE_DESCRIPTION` |  | 1 | Schema element is missing a description |
+
+</details>
+
+<details>
+<summary><b>Custom Check</b> ✅ PASSED</summary>
+
+No custom check violations found.
+
+</details>
+
+<details open>
+<summary><b>Downstream Check</b> ⛔ BLOCKED</summary>
+
+These blocking downstream variants failed their checks:
+
+- `my-graph@prod`
+
+</details>
+
+[View the check in GraphOS Studio](https://studio.apollographql.com/graph/my-graph/checks)
+"#
+        );
+    }
+}
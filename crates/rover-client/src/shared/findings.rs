@@ -0,0 +1,539 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use serde_json::{json, Value};
+
+use crate::shared::{
+    ChangeSeverity, CheckTaskStatus, CheckWorkflowResponse, Diagnostic, LintResponse,
+    ProposalsCheckSeverityLevel, ProposalsCoverage, ProposedSchema,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const ROVER_DOCS_URL: &str = "https://www.apollographql.com/docs/rover/";
+
+/// The file name schemas read from stdin are reported under, which can't be pointed at
+const STDIN_FILE_NAME: &str = "stdin";
+
+/// How serious a [`Finding`] is
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FindingLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+impl FindingLevel {
+    fn from_diagnostic_level(level: &str) -> Self {
+        match level {
+            "ERROR" => FindingLevel::Error,
+            "WARNING" => FindingLevel::Warning,
+            _ => FindingLevel::Note,
+        }
+    }
+
+    const fn sarif(&self) -> &'static str {
+        match self {
+            FindingLevel::Error => "error",
+            FindingLevel::Warning => "warning",
+            FindingLevel::Note => "note",
+        }
+    }
+
+    const fn github(&self) -> &'static str {
+        match self {
+            FindingLevel::Error => "error",
+            FindingLevel::Warning => "warning",
+            FindingLevel::Note => "notice",
+        }
+    }
+}
+
+/// Where in a schema file a [`Finding`] is. Lines and columns start at 1.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FindingLocation {
+    pub file_name: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl FindingLocation {
+    fn new(file_name: &str, (line, column): (usize, usize)) -> Option<Self> {
+        if file_name == STDIN_FILE_NAME {
+            return None;
+        }
+        Some(FindingLocation {
+            file_name: file_name.trim_start_matches("./").replace('\\', "/"),
+            line,
+            column,
+        })
+    }
+
+    fn from_byte_offset(file_name: &str, sdl: &str, byte_offset: usize) -> Option<Self> {
+        let before = sdl.get(..byte_offset)?;
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |line| line.chars().count())
+            + 1;
+        Self::new(file_name, (line, column))
+    }
+
+    /// Points at the definition of a schema coordinate like `Query.products(limit:)`, falling back
+    /// to its type and then to the top of the file when it isn't in the schema, e.g. because the
+    /// change removed it
+    fn from_coordinate(schema: &ProposedSchema, coordinate: Option<&str>) -> Option<Self> {
+        let position = coordinate
+            .and_then(|coordinate| locate_coordinate(&schema.sdl, coordinate))
+            .unwrap_or((1, 1));
+        Self::new(&schema.file_name, position)
+    }
+}
+
+/// A single problem found by a check or by lint, in a shape the SARIF, JUnit, and GitHub
+/// annotation formats can all be rendered from
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Finding {
+    pub rule: String,
+    pub level: FindingLevel,
+    pub message: String,
+    /// The schema coordinate the finding is about, if there is one
+    pub coordinate: Option<String>,
+    pub location: Option<FindingLocation>,
+}
+
+impl Finding {
+    fn name(&self) -> String {
+        match &self.coordinate {
+            Some(coordinate) => format!("{} {}", self.rule, coordinate),
+            None => self.rule.clone(),
+        }
+    }
+
+    fn from_diagnostic(diagnostic: &Diagnostic, schema: Option<(&str, &str)>) -> Self {
+        Finding {
+            rule: diagnostic.rule.clone(),
+            level: FindingLevel::from_diagnostic_level(&diagnostic.level),
+            message: diagnostic.message.clone(),
+            coordinate: Some(diagnostic.coordinate.clone()).filter(|c| !c.is_empty()),
+            location: schema.and_then(|(file_name, sdl)| {
+                FindingLocation::from_byte_offset(file_name, sdl, diagnostic.start_byte_offset)
+            }),
+        }
+    }
+}
+
+/// The findings of one check task, or of lint
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TaskFindings {
+    pub task: String,
+    pub findings: Vec<Finding>,
+}
+
+/// Everything a check or lint found, for the report formats CI systems read
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FindingsReport {
+    pub tasks: Vec<TaskFindings>,
+}
+
+impl FindingsReport {
+    fn findings(&self) -> impl Iterator<Item = (&str, &Finding)> {
+        self.tasks.iter().flat_map(|task| {
+            task.findings
+                .iter()
+                .map(move |finding| (task.task.as_str(), finding))
+        })
+    }
+
+    /// Renders a [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log,
+    /// as read by GitHub code scanning
+    pub fn get_sarif(&self, tool_version: &str) -> Value {
+        let rules: BTreeSet<&str> = self
+            .findings()
+            .map(|(_, finding)| finding.rule.as_str())
+            .collect();
+        let results: Vec<Value> = self
+            .findings()
+            .map(|(task, finding)| {
+                let mut result = json!({
+                    "ruleId": finding.rule,
+                    "level": finding.level.sarif(),
+                    "message": { "text": finding.message },
+                    "properties": { "task": task },
+                });
+                if let Some(location) = &finding.location {
+                    result["locations"] = json!([{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": location.file_name },
+                            "region": {
+                                "startLine": location.line,
+                                "startColumn": location.column,
+                            },
+                        },
+                    }]);
+                }
+                result
+            })
+            .collect();
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "rover",
+                        "version": tool_version,
+                        "informationUri": ROVER_DOCS_URL,
+                        "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            }],
+        })
+    }
+
+    /// Renders a JUnit XML report with a test suite for each task. Errors are test failures,
+    /// and tasks without any findings are reported as a single passing test.
+    pub fn get_junit(&self) -> String {
+        let failures = |findings: &[Finding]| {
+            findings
+                .iter()
+                .filter(|finding| finding.level == FindingLevel::Error)
+                .count()
+        };
+        let tests = |findings: &[Finding]| findings.len().max(1);
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"rover\" tests=\"{}\" failures=\"{}\">",
+            self.tasks.iter().map(|t| tests(&t.findings)).sum::<usize>(),
+            self.tasks
+                .iter()
+                .map(|t| failures(&t.findings))
+                .sum::<usize>(),
+        );
+        for task in &self.tasks {
+            let name = xml_escape(&task.task);
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{name}\" tests=\"{}\" failures=\"{}\">",
+                tests(&task.findings),
+                failures(&task.findings),
+            );
+            if task.findings.is_empty() {
+                let _ = writeln!(xml, "    <testcase classname=\"{name}\" name=\"{name}\"/>");
+            }
+            for finding in &task.findings {
+                let _ = writeln!(
+                    xml,
+                    "    <testcase classname=\"{name}\" name=\"{}\">",
+                    xml_escape(&finding.name())
+                );
+                let details = match &finding.location {
+                    Some(location) => format!(
+                        "{}:{}:{}: {}",
+                        location.file_name, location.line, location.column, finding.message
+                    ),
+                    None => finding.message.clone(),
+                };
+                if finding.level == FindingLevel::Error {
+                    let _ = writeln!(
+                        xml,
+                        "      <failure type=\"{}\" message=\"{}\">{}</failure>",
+                        xml_escape(&finding.rule),
+                        xml_escape(&finding.message),
+                        xml_escape(&details)
+                    );
+                } else {
+                    let _ = writeln!(
+                        xml,
+                        "      <system-out>{}</system-out>",
+                        xml_escape(&details)
+                    );
+                }
+                let _ = writeln!(xml, "    </testcase>");
+            }
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+        xml.push_str("</testsuites>");
+        xml
+    }
+
+    /// Renders GitHub Actions workflow commands, which show up as annotations on the lines of
+    /// the pull request they point at
+    pub fn get_github_annotations(&self) -> String {
+        self.findings()
+            .map(|(_, finding)| {
+                let mut properties = Vec::new();
+                if let Some(location) = &finding.location {
+                    properties.push(format!("file={}", github_property(&location.file_name)));
+                    properties.push(format!("line={}", location.line));
+                    properties.push(format!("col={}", location.column));
+                }
+                properties.push(format!("title={}", github_property(&finding.name())));
+                format!(
+                    "::{} {}::{}",
+                    finding.level.github(),
+                    properties.join(","),
+                    github_data(&finding.message)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl LintResponse {
+    pub fn get_findings(&self) -> FindingsReport {
+        let schema = Some((self.file_name.as_str(), self.proposed_schema.as_str()));
+        FindingsReport {
+            tasks: vec![TaskFindings {
+                task: "Lint".to_string(),
+                findings: self
+                    .diagnostics
+                    .iter()
+                    .map(|diagnostic| Finding::from_diagnostic(diagnostic, schema))
+                    .collect(),
+            }],
+        }
+    }
+}
+
+impl CheckWorkflowResponse {
+    pub fn get_findings(&self) -> FindingsReport {
+        let schema = self.maybe_proposed_schema.as_ref();
+        let mut tasks = Vec::new();
+
+        if let Some(operations_response) = &self.maybe_operations_response {
+            tasks.push(TaskFindings {
+                task: "Operation Check".to_string(),
+                findings: operations_response
+                    .changes()
+                    .iter()
+                    .map(|change| Finding {
+                        rule: change.code.clone(),
+                        level: match change.severity {
+                            ChangeSeverity::FAIL => FindingLevel::Error,
+                            ChangeSeverity::PASS => FindingLevel::Note,
+                        },
+                        message: change.description.clone(),
+                        coordinate: change.coordinate.clone(),
+                        location: schema.and_then(|schema| {
+                            FindingLocation::from_coordinate(schema, change.coordinate.as_deref())
+                        }),
+                    })
+                    .collect(),
+            });
+        }
+
+        if let Some(lint_response) = &self.maybe_lint_response {
+            let schema = schema.map(|schema| (schema.file_name.as_str(), schema.sdl.as_str()));
+            tasks.push(TaskFindings {
+                task: "Linter Check".to_string(),
+                findings: lint_response
+                    .diagnostics
+                    .iter()
+                    .map(|diagnostic| Finding::from_diagnostic(diagnostic, schema))
+                    .collect(),
+            });
+        }
+
+        if let Some(proposals_response) = &self.maybe_proposals_response {
+            let level = match (
+                &proposals_response.proposal_coverage,
+                &proposals_response.severity_level,
+            ) {
+                (
+                    ProposalsCoverage::PARTIAL | ProposalsCoverage::NONE,
+                    ProposalsCheckSeverityLevel::ERROR,
+                ) => Some(FindingLevel::Error),
+                (
+                    ProposalsCoverage::PARTIAL | ProposalsCoverage::NONE,
+                    ProposalsCheckSeverityLevel::WARN,
+                ) => Some(FindingLevel::Warning),
+                _ => None,
+            };
+            tasks.push(TaskFindings {
+                task: "Proposals Check".to_string(),
+                findings: level
+                    .map(|level| Finding {
+                        rule: "PROPOSALS".to_string(),
+                        level,
+                        message: proposals_response.get_msg(),
+                        coordinate: None,
+                        location: None,
+                    })
+                    .into_iter()
+                    .collect(),
+            });
+        }
+
+        if let Some(custom_response) = &self.maybe_custom_response {
+            tasks.push(TaskFindings {
+                task: "Custom Check".to_string(),
+                findings: custom_response
+                    .violations
+                    .iter()
+                    .map(|violation| Finding {
+                        rule: violation.rule.clone(),
+                        level: FindingLevel::from_diagnostic_level(&violation.level),
+                        message: violation.message.clone(),
+                        coordinate: None,
+                        location: schema.zip(violation.start_line).and_then(|(schema, line)| {
+                            FindingLocation::new(&schema.file_name, (line.max(1) as usize, 1))
+                        }),
+                    })
+                    .collect(),
+            });
+        }
+
+        if let Some(downstream_response) = &self.maybe_downstream_response {
+            let findings = match downstream_response.task_status {
+                CheckTaskStatus::FAILED => downstream_response
+                    .blocking_variants
+                    .iter()
+                    .map(|variant| Finding {
+                        rule: "DOWNSTREAM".to_string(),
+                        level: FindingLevel::Error,
+                        message: format!(
+                            "The check failed for the blocking downstream variant {variant}"
+                        ),
+                        coordinate: None,
+                        location: None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            tasks.push(TaskFindings {
+                task: "Downstream Check".to_string(),
+                findings,
+            });
+        }
+
+        FindingsReport { tasks }
+    }
+}
+
+/// Finds the line and column a type, field, argument, or enum value is defined at
+fn locate_coordinate(sdl: &str, coordinate: &str) -> Option<(usize, usize)> {
+    let (type_name, member) = match coordinate.split_once('.') {
+        Some((type_name, member)) => (type_name, member.split('(').next()),
+        None => (coordinate, None),
+    };
+    let mut lines = sdl.lines().enumerate();
+    let type_position = lines.by_ref().find_map(|(index, line)| {
+        let trimmed = line.trim_start();
+        let definition = trimmed.strip_prefix("extend ").unwrap_or(trimmed);
+        let (keyword, rest) = definition.split_once(char::is_whitespace)?;
+        let is_definition = matches!(
+            keyword,
+            "type" | "interface" | "input" | "enum" | "union" | "scalar"
+        );
+        (is_definition && starts_with_name(rest.trim_start(), type_name))
+            .then(|| (index + 1, line.len() - trimmed.len() + 1))
+    })?;
+    let Some(member) = member.filter(|member| !member.is_empty()) else {
+        return Some(type_position);
+    };
+    let member_position = lines
+        .map(|(index, line)| (index, line, line.trim_start()))
+        .take_while(|(_, _, trimmed)| !trimmed.starts_with('}'))
+        .find(|(_, _, trimmed)| starts_with_name(trimmed, member))
+        .map(|(index, line, trimmed)| (index + 1, line.len() - trimmed.len() + 1));
+    Some(member_position.unwrap_or(type_position))
+}
+
+fn starts_with_name(text: &str, name: &str) -> bool {
+    text.strip_prefix(name).is_some_and(|rest| {
+        !rest
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn github_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn github_property(text: &str) -> String {
+    github_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"type Query {
+  "All the products"
+  products(limit: Int): [Product!]!
+}
+
+extend type Product @key(fields: "id") {
+  id: ID!
+  name: String
+}
+"#;
+
+    #[test]
+    fn it_locates_coordinates() {
+        assert_eq!(locate_coordinate(SDL, "Query"), Some((1, 1)));
+        assert_eq!(locate_coordinate(SDL, "Query.products"), Some((3, 3)));
+        assert_eq!(
+            locate_coordinate(SDL, "Query.products(limit:)"),
+            Some((3, 3))
+        );
+        assert_eq!(locate_coordinate(SDL, "Product.name"), Some((8, 3)));
+        // removed fields point at their type, and removed types aren't found at all
+        assert_eq!(locate_coordinate(SDL, "Product.price"), Some((6, 1)));
+        assert_eq!(locate_coordinate(SDL, "Prod"), None);
+    }
+
+    #[test]
+    fn it_renders_lint_findings() {
+        let lint_response = LintResponse {
+            diagnostics: vec![Diagnostic {
+                level: "ERROR".to_string(),
+                message: "Field names should use camelCase style.".to_string(),
+                coordinate: "Product.name".to_string(),
+                rule: "FIELD_NAMES_SHOULD_BE_CAMEL_CASE".to_string(),
+                start_line: 8,
+                start_byte_offset: SDL.find("name:").unwrap(),
+                end_byte_offset: SDL.find("name:").unwrap() + 4,
+            }],
+            file_name: "./products.graphql".to_string(),
+            proposed_schema: SDL.to_string(),
+        };
+        let report = lint_response.get_findings();
+
+        let sarif = report.get_sarif("0.0.0");
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "FIELD_NAMES_SHOULD_BE_CAMEL_CASE");
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "products.graphql");
+        assert_eq!(location["region"]["startLine"], 8);
+        assert_eq!(location["region"]["startColumn"], 3);
+
+        assert_eq!(
+            report.get_github_annotations(),
+            "::error file=products.graphql,line=8,col=3,title=FIELD_NAMES_SHOULD_BE_CAMEL_CASE Product.name::Field names should use camelCase style."
+        );
+
+        let junit = report.get_junit();
+        assert!(junit.contains(r#"<testsuite name="Lint" tests="1" failures="1">"#));
+        assert!(junit.contains(
+            r#"<failure type="FIELD_NAMES_SHOULD_BE_CAMEL_CASE" message="Field names should use camelCase style.">products.graphql:8:3: Field names should use camelCase style.</failure>"#
+        ));
+    }
+}
@@ -1,16 +1,19 @@
 mod async_check_response;
 mod check_response;
 mod fetch_response;
+mod findings;
 mod git_context;
 mod lint_response;
 
-pub use async_check_response::CheckRequestSuccessResult;
+pub use async_check_response::{CheckRequestSuccessResult, CheckWorkflowSnapshot};
+pub(crate) use async_check_response::{wait_for_check_workflow, CHECK_WORKFLOW_POLL_INTERVAL};
 pub use check_response::{
     ChangeSeverity, CheckConfig, CheckTaskStatus, CheckWorkflowResponse, CustomCheckResponse,
     DownstreamCheckResponse, LintCheckResponse, OperationCheckResponse, ProposalsCheckResponse,
-    ProposalsCheckSeverityLevel, ProposalsCoverage, RelatedProposal, SchemaChange,
+    ProposalsCheckSeverityLevel, ProposalsCoverage, ProposedSchema, RelatedProposal, SchemaChange,
     ValidationPeriod, Violation,
 };
 pub use fetch_response::{FetchResponse, Sdl, SdlType};
+pub use findings::{Finding, FindingLevel, FindingLocation, FindingsReport, TaskFindings};
 pub use git_context::GitContext;
 pub use lint_response::{Diagnostic, LintResponse};
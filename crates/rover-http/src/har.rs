@@ -0,0 +1,505 @@
+//! Records HTTP traffic so it can be exported as a [HAR](http://www.softwareishard.com/blog/har-12-spec/) file
+
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use futures::Future;
+use http::{header, HeaderMap, HeaderName, Method, StatusCode};
+use http_body_util::BodyExt;
+use reqwest::ResponseBuilderExt;
+use serde_json::{json, Value};
+use tower::{Layer, Service};
+
+use crate::{retry::RetryAttempt, HttpRequest, HttpResponse, HttpServiceError};
+
+/// Replaces the value of headers that carry credentials
+const REDACTED: &str = "<redacted>";
+
+static GLOBAL: OnceLock<HarRecorder> = OnceLock::new();
+
+/// Installs the recorder that every [`crate::ReqwestService`] and reqwest-based client records
+/// to. Returns `false` if one was already installed.
+pub fn install(recorder: HarRecorder) -> bool {
+    GLOBAL.set(recorder).is_ok()
+}
+
+/// The recorder installed with [`install`], if any
+pub fn global() -> Option<&'static HarRecorder> {
+    GLOBAL.get()
+}
+
+/// A request that was sent, and the response or error it got back
+#[derive(Clone, Debug)]
+pub struct Exchange {
+    /// When the request was sent
+    pub started: SystemTime,
+    /// How long it took to get the full response
+    pub duration: Duration,
+    /// The request's method
+    pub method: Method,
+    /// The request's URL
+    pub url: String,
+    /// The request's headers, before redaction
+    pub request_headers: HeaderMap,
+    /// The request's body
+    pub request_body: Bytes,
+    /// The response, or a description of the error that prevented one
+    pub response: Result<ExchangeResponse, String>,
+    /// `1` for the first attempt at a request, `2` for its first retry, and so on
+    pub attempt: u32,
+}
+
+/// The parts of a response that are recorded
+#[derive(Clone, Debug)]
+pub struct ExchangeResponse {
+    /// The response's status
+    pub status: StatusCode,
+    /// The response's headers
+    pub headers: HeaderMap,
+    /// The response's body
+    pub body: Bytes,
+}
+
+/// Collects [`Exchange`]s, to be written out as a HAR file
+#[derive(Clone, Debug)]
+pub struct HarRecorder {
+    creator_name: String,
+    creator_version: String,
+    entries: Arc<Mutex<Vec<Value>>>,
+}
+
+impl HarRecorder {
+    /// Constructs a new [`HarRecorder`], naming the program that made the requests
+    pub fn new(creator_name: impl Into<String>, creator_version: impl Into<String>) -> Self {
+        HarRecorder {
+            creator_name: creator_name.into(),
+            creator_version: creator_version.into(),
+            entries: Arc::default(),
+        }
+    }
+
+    /// Adds an [`Exchange`] to the log, redacting credentials
+    pub fn record(&self, exchange: Exchange) {
+        let Exchange {
+            started,
+            duration,
+            method,
+            url,
+            request_headers,
+            request_body,
+            response,
+            attempt,
+        } = exchange;
+        let time = duration.as_secs_f64() * 1000.0;
+        let query_string: Vec<Value> = url::Url::parse(&url)
+            .map(|url| {
+                url.query_pairs()
+                    .map(|(name, value)| json!({ "name": name, "value": value }))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut request = json!({
+            "method": method.as_str(),
+            "url": url,
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": har_headers(&request_headers),
+            "queryString": query_string,
+            "headersSize": -1,
+            "bodySize": request_body.len(),
+        });
+        if !request_body.is_empty() {
+            request["postData"] = json!({
+                "mimeType": mime_type(&request_headers),
+                "text": String::from_utf8_lossy(&request_body),
+            });
+        }
+        let response = match response {
+            Ok(ExchangeResponse {
+                status,
+                headers,
+                body,
+            }) => json!({
+                "status": status.as_u16(),
+                "statusText": status.canonical_reason().unwrap_or_default(),
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": har_headers(&headers),
+                "content": {
+                    "size": body.len(),
+                    "mimeType": mime_type(&headers),
+                    "text": String::from_utf8_lossy(&body),
+                },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": body.len(),
+            }),
+            // HAR has no place for failed requests, so they're recorded the way browsers do it
+            Err(error) => json!({
+                "status": 0,
+                "statusText": "",
+                "httpVersion": "",
+                "cookies": [],
+                "headers": [],
+                "content": { "size": 0, "mimeType": "" },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": -1,
+                "_error": error,
+            }),
+        };
+        let entry = json!({
+            "startedDateTime": humantime::format_rfc3339_millis(started).to_string(),
+            "time": time,
+            "request": request,
+            "response": response,
+            "cache": {},
+            "timings": { "send": 0, "wait": time, "receive": 0 },
+            "_attempt": attempt,
+        });
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.push(entry);
+        }
+    }
+
+    /// Records a request sent with a plain [`reqwest::Client`]. The response body has to be read
+    /// to be recorded, so a response with the same status, headers, and body is returned in
+    /// place of the original.
+    pub async fn record_reqwest(
+        &self,
+        request: &reqwest::Request,
+        started: SystemTime,
+        attempt: u32,
+        result: reqwest::Result<reqwest::Response>,
+    ) -> reqwest::Result<reqwest::Response> {
+        let exchange = |response| Exchange {
+            started,
+            duration: started.elapsed().unwrap_or_default(),
+            method: request.method().clone(),
+            url: request.url().to_string(),
+            request_headers: request.headers().clone(),
+            request_body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(Bytes::copy_from_slice)
+                .unwrap_or_default(),
+            response,
+            attempt,
+        };
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => {
+                self.record(exchange(Err(err.to_string())));
+                return Err(err);
+            }
+        };
+        let status = response.status();
+        let version = response.version();
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = match response.bytes().await {
+            Ok(body) => body,
+            Err(err) => {
+                self.record(exchange(Err(err.to_string())));
+                return Err(err);
+            }
+        };
+        self.record(exchange(Ok(ExchangeResponse {
+            status,
+            headers: headers.clone(),
+            body: body.clone(),
+        })));
+
+        let mut replacement = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(url);
+        if let Some(replacement_headers) = replacement.headers_mut() {
+            *replacement_headers = headers;
+        }
+        Ok(replacement
+            .body(body)
+            .expect("the status and version came from a valid response")
+            .into())
+    }
+
+    /// The HAR document for everything recorded so far
+    pub fn to_har(&self) -> Value {
+        let entries = self
+            .entries
+            .lock()
+            .map(|entries| entries.clone())
+            .unwrap_or_default();
+        json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": self.creator_name,
+                    "version": self.creator_version,
+                },
+                "entries": entries,
+            }
+        })
+    }
+}
+
+fn is_sensitive(name: &HeaderName) -> bool {
+    name == "x-api-key"
+        || name == header::AUTHORIZATION
+        || name == header::PROXY_AUTHORIZATION
+        || name == header::COOKIE
+        || name == header::SET_COOKIE
+}
+
+fn har_headers(headers: &HeaderMap) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive(name) || value.is_sensitive() {
+                REDACTED.into()
+            } else {
+                String::from_utf8_lossy(value.as_bytes())
+            };
+            json!({ "name": name.as_str(), "value": value })
+        })
+        .collect()
+}
+
+fn mime_type(headers: &HeaderMap) -> &str {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+}
+
+/// Layer that applies [`Har`], which records requests and responses to a [`HarRecorder`]
+#[derive(Clone, Debug)]
+pub struct HarLayer {
+    recorder: HarRecorder,
+}
+
+impl HarLayer {
+    /// Constructs a new [`HarLayer`]
+    pub const fn new(recorder: HarRecorder) -> HarLayer {
+        HarLayer { recorder }
+    }
+}
+
+impl<S> Layer<S> for HarLayer {
+    type Service = Har<S>;
+    fn layer(&self, inner: S) -> Self::Service {
+        Har {
+            recorder: self.recorder.clone(),
+            inner,
+        }
+    }
+}
+
+/// Middleware that records every request and response, including each retry when it sits below
+/// a [`tower::retry::RetryLayer`] using [`crate::retry::RetryPolicy`]
+#[derive(Clone, Debug)]
+pub struct Har<S> {
+    recorder: HarRecorder,
+    inner: S,
+}
+
+impl<S> Service<HttpRequest> for Har<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse, Error = HttpServiceError>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send,
+{
+    type Response = HttpResponse;
+    type Error = HttpServiceError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: HttpRequest) -> Self::Future {
+        // https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let recorder = self.recorder.clone();
+        Box::pin(async move {
+            let method = req.method().clone();
+            let url = req.uri().to_string();
+            let request_headers = req.headers().clone();
+            let attempt = req
+                .extensions()
+                .get::<RetryAttempt>()
+                .map_or(1, |attempt| attempt.0);
+            let request_body = req
+                .body()
+                .clone()
+                .collect()
+                .await
+                .map(|body| body.to_bytes())
+                .unwrap_or_default();
+
+            let started = SystemTime::now();
+            let result = inner.call(req).await;
+            let response = match &result {
+                Ok(resp) => Ok(ExchangeResponse {
+                    status: resp.status(),
+                    headers: resp.headers().clone(),
+                    body: resp
+                        .body()
+                        .clone()
+                        .collect()
+                        .await
+                        .map(|body| body.to_bytes())
+                        .unwrap_or_default(),
+                }),
+                Err(err) => Err(err.to_string()),
+            };
+            recorder.record(Exchange {
+                started,
+                duration: started.elapsed().unwrap_or_default(),
+                method,
+                url,
+                request_headers,
+                request_body,
+                response,
+                attempt,
+            });
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use bytes::Bytes;
+    use http::{HeaderValue, Method};
+    use http_body_util::Full;
+    use httpmock::MockServer;
+    use speculoos::prelude::*;
+    use tower::{Service, ServiceBuilder, ServiceExt};
+
+    use super::{HarLayer, HarRecorder};
+    use crate::{
+        retry::{RetryConfig, RetryPolicy},
+        ReqwestService,
+    };
+
+    #[tokio::test]
+    async fn it_records_requests_responses_and_retries() -> Result<()> {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/graphql");
+            then.status(503)
+                .header("content-type", "application/json")
+                .body(r#"{"errors":[]}"#);
+        });
+
+        let recorder = HarRecorder::new("rover", "0.0.0");
+        let mut service = ServiceBuilder::new()
+            .retry(RetryPolicy::new(RetryConfig {
+                max_attempts: Some(2),
+                base_delay: Duration::from_millis(10),
+                ..Default::default()
+            }))
+            .layer(HarLayer::new(recorder.clone()))
+            .service(ReqwestService::builder().build()?)
+            .boxed_clone();
+
+        let request = http::Request::builder()
+            .uri(server.url("/graphql?name=products"))
+            .method(Method::POST)
+            .header(
+                "x-api-key",
+                HeaderValue::from_static("service:my-graph:secret"),
+            )
+            .header("content-type", "application/json")
+            .body(Full::new(Bytes::from_static(b"{\"query\":\"{ me }\"}")))?;
+        service.call(request).await?;
+        mock.assert_calls(2);
+
+        let har = recorder.to_har();
+        let entries = har["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["_attempt"], 1);
+        assert_eq!(entries[1]["_attempt"], 2);
+
+        let entry = &entries[0];
+        assert_eq!(entry["request"]["method"], "POST");
+        assert_eq!(entry["request"]["queryString"][0]["value"], "products");
+        assert_eq!(
+            entry["request"]["postData"]["text"],
+            "{\"query\":\"{ me }\"}"
+        );
+        let api_key = entry["request"]["headers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|header| header["name"] == "x-api-key")
+            .unwrap();
+        assert_eq!(api_key["value"], "<redacted>");
+        assert_eq!(entry["response"]["status"], 503);
+        assert_eq!(entry["response"]["content"]["text"], r#"{"errors":[]}"#);
+        assert_eq!(entry["response"]["content"]["mimeType"], "application/json");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_records_reqwest_requests() -> Result<()> {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(404).body("not here");
+        });
+
+        let recorder = HarRecorder::new("rover", "0.0.0");
+        let client = reqwest::Client::new();
+        let request = client.get(server.url("/")).build()?;
+        let started = std::time::SystemTime::now();
+        let result = client.execute(request.try_clone().unwrap()).await;
+        let response = recorder
+            .record_reqwest(&request, started, 1, result)
+            .await?;
+
+        // the response can still be read after it's been recorded
+        assert_eq!(response.url().as_str(), server.url("/"));
+        assert!(response.error_for_status_ref().is_err());
+        assert_eq!(response.text().await?, "not here");
+
+        let har = recorder.to_har();
+        assert_eq!(har["log"]["entries"][0]["response"]["status"], 404);
+        assert_eq!(
+            har["log"]["entries"][0]["response"]["content"]["text"],
+            "not here"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_records_errors() -> Result<()> {
+        let recorder = HarRecorder::new("rover", "0.0.0");
+        let mut service = ServiceBuilder::new()
+            .layer(HarLayer::new(recorder.clone()))
+            .service(ReqwestService::builder().build()?);
+        let request = http::Request::builder()
+            .uri("http://127.0.0.1:9/")
+            .body(Full::default())?;
+        assert_that!(service.ready().await?.call(request).await).is_err();
+
+        let har = recorder.to_har();
+        assert_eq!(har["log"]["entries"][0]["response"]["status"], 0);
+        assert!(har["log"]["entries"][0]["response"]["_error"].is_string());
+        Ok(())
+    }
+}
@@ -15,14 +15,21 @@ pub mod body;
 mod error;
 pub mod error_on_status;
 pub mod extend_headers;
+pub mod har;
+pub mod proxy;
 mod reqwest;
 pub mod retry;
 #[cfg(any(test, feature = "test"))]
 pub mod test;
 pub mod timeout;
+pub mod tls;
 
 pub use error::HttpServiceError;
+pub use har::{HarLayer, HarRecorder};
+pub use proxy::{NoProxy, ProxyConfig};
 pub use reqwest::{ReqwestService, ReqwestServiceFactory};
+pub use retry::{RetryConfig, RetryOn};
+pub use tls::TlsConfig;
 
 /// Ease-of-use synonym for the request type this crate operates on
 pub type HttpRequest = http::Request<Full<Bytes>>;
@@ -49,6 +56,8 @@ pub struct HttpServiceConfig {
     accept_invalid_certificates: Option<bool>,
     accept_invalid_hostnames: Option<bool>,
     timeout: Option<Duration>,
+    tls: Option<TlsConfig>,
+    proxy: Option<ProxyConfig>,
 }
 
 impl From<Box<dyn std::error::Error + Send + Sync>> for HttpServiceError {
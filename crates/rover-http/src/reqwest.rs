@@ -4,11 +4,14 @@ use buildstructor::buildstructor;
 use futures::Future;
 use http_body_util::Full;
 use reqwest::ClientBuilder;
-use tower::{util::BoxCloneService, Service, ServiceBuilder, ServiceExt};
+use tower::{util::BoxCloneService, Layer, Service, ServiceBuilder, ServiceExt};
+use tracing::{Instrument, Span};
 
 use crate::{
-    body::body_to_bytes, HttpRequest, HttpResponse, HttpService, HttpServiceConfig,
-    HttpServiceError, HttpServiceFactory,
+    body::body_to_bytes,
+    har::{self, HarLayer, HarRecorder},
+    HttpRequest, HttpResponse, HttpService, HttpServiceConfig, HttpServiceError,
+    HttpServiceFactory, ProxyConfig,
 };
 
 /// Constructs [`HttpService`]s
@@ -18,6 +21,24 @@ pub struct ReqwestServiceFactory {
     client: reqwest::Client,
 }
 
+#[buildstructor]
+impl ReqwestServiceFactory {
+    /// Constructs a new [`ReqwestServiceFactory`], building a client from `config` if one isn't
+    /// provided
+    #[builder]
+    pub fn new(
+        config: Option<HttpServiceConfig>,
+        client: Option<reqwest::Client>,
+    ) -> Result<ReqwestServiceFactory, reqwest::Error> {
+        let config = config.unwrap_or_default();
+        let client = match client {
+            Some(client) => client,
+            None => client_from_config(&config)?,
+        };
+        Ok(ReqwestServiceFactory { config, client })
+    }
+}
+
 impl HttpServiceFactory for ReqwestServiceFactory {
     /// Creates a new [`HttpService`]
     fn create(&self) -> Result<HttpService, HttpServiceError> {
@@ -33,36 +54,59 @@ impl HttpServiceFactory for ReqwestServiceFactory {
 /// A [`Service`] that wraps a [`reqwest`] client and uses [`http`] constructs for requests and responses
 #[derive(Clone, Debug)]
 pub struct ReqwestService {
-    client: BoxCloneService<reqwest::Request, reqwest::Response, HttpServiceError>,
+    inner: HttpService,
 }
 
 #[buildstructor]
 impl ReqwestService {
-    /// Constructs a new [`ReqwestService`]
+    /// Constructs a new [`ReqwestService`]. Requests are recorded to `har`, or to the recorder
+    /// installed with [`har::install`] if it's left off.
     #[builder]
     pub fn new(
         config: Option<HttpServiceConfig>,
         client: Option<reqwest::Client>,
+        har: Option<HarRecorder>,
     ) -> Result<ReqwestService, reqwest::Error> {
         let config = config.unwrap_or_default();
         let client = match client {
             Some(client) => client,
-            None => ClientBuilder::new()
-                .danger_accept_invalid_certs(config.accept_invalid_certificates.unwrap_or_default())
-                .danger_accept_invalid_hostnames(
-                    config.accept_invalid_hostnames.unwrap_or_default(),
-                )
-                .build()?,
+            None => client_from_config(&config)?,
         };
         let client = ServiceBuilder::new()
             .map_err(HttpServiceError::from)
             .timeout((*config.timeout()).unwrap_or_else(|| Duration::from_secs(90)))
             .service(client)
             .boxed_clone();
-        Ok(ReqwestService { client })
+        let inner = HttpClientService { client }.boxed_clone();
+        let inner = match har.or_else(|| har::global().cloned()) {
+            Some(recorder) => HarLayer::new(recorder).layer(inner).boxed_clone(),
+            None => inner,
+        };
+        Ok(ReqwestService { inner })
     }
 }
 
+/// Converts between [`http`] and [`reqwest`] requests and responses
+#[derive(Clone, Debug)]
+struct HttpClientService {
+    client: BoxCloneService<reqwest::Request, reqwest::Response, HttpServiceError>,
+}
+
+fn client_from_config(config: &HttpServiceConfig) -> Result<reqwest::Client, reqwest::Error> {
+    let builder = ClientBuilder::new()
+        .danger_accept_invalid_certs(config.accept_invalid_certificates.unwrap_or_default())
+        .danger_accept_invalid_hostnames(config.accept_invalid_hostnames.unwrap_or_default());
+    let builder = match &config.tls {
+        Some(tls) => tls.apply(builder)?,
+        None => builder,
+    };
+    let builder = match &config.proxy {
+        Some(proxy) => proxy.apply(builder),
+        None => ProxyConfig::from_env(None).apply(builder),
+    };
+    builder.build()
+}
+
 impl From<reqwest::Error> for HttpServiceError {
     fn from(value: reqwest::Error) -> Self {
         if value.is_body() {
@@ -84,6 +128,23 @@ impl Service<HttpRequest> for ReqwestService {
     type Error = HttpServiceError;
     type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
 
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: HttpRequest) -> Self::Future {
+        self.inner.call(req)
+    }
+}
+
+impl Service<HttpRequest> for HttpClientService {
+    type Response = HttpResponse;
+    type Error = HttpServiceError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
     fn poll_ready(
         &mut self,
         cx: &mut std::task::Context<'_>,
@@ -94,6 +155,14 @@ impl Service<HttpRequest> for ReqwestService {
     fn call(&mut self, req: HttpRequest) -> Self::Future {
         // https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
         let mut client = self.client.clone();
+        let span = tracing::info_span!(
+            "http_request",
+            otel.name = %req.method(),
+            otel.kind = "client",
+            http.request.method = %req.method(),
+            url.full = %req.uri(),
+            http.response.status_code = tracing::field::Empty,
+        );
         let fut = async move {
             let mut req = req.clone();
             let bytes = body_to_bytes(&mut req)
@@ -103,12 +172,13 @@ impl Service<HttpRequest> for ReqwestService {
             let req = req.map(move |_| body);
             let req = reqwest::Request::try_from(req)?;
             let mut resp = http::Response::from(client.call(req).await?);
+            Span::current().record("http.response.status_code", resp.status().as_u16());
             let bytes = body_to_bytes(&mut resp)
                 .await
                 .map_err(|err| HttpServiceError::Body(Box::new(err)))?;
             Ok(resp.map(|_| Full::new(bytes)))
         };
-        Box::pin(fut)
+        Box::pin(fut.instrument(span))
     }
 }
 
@@ -2,90 +2,393 @@
 
 use std::{
     cell::OnceCell,
+    fmt::{self, Display},
+    str::FromStr,
     time::{Duration, Instant},
 };
 
-use http::StatusCode;
-use tap::TapFallible;
+use http::{header::RETRY_AFTER, HeaderMap, StatusCode};
 use tower::{
-    retry::{
-        backoff::{Backoff, ExponentialBackoff, ExponentialBackoffMaker, MakeBackoff},
-        Policy,
-    },
-    util::rng::HasherRng,
+    retry::Policy,
+    util::rng::{HasherRng, Rng},
 };
 
 use super::HttpServiceError;
 use crate::{HttpRequest, HttpResponse};
 
-/// [`Policy`] implementation that describes whetheer to retry a request
+/// Describes when and how often a failed request is retried. Shared by every client that
+/// retries requests, so they all behave the same way.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The most times a request is sent, including the first attempt. `None` means there's no
+    /// limit other than `max_elapsed`
+    pub max_attempts: Option<u32>,
+    /// No retry is started if it would finish waiting after this much time has passed since the
+    /// first attempt
+    pub max_elapsed: Duration,
+    /// The delay before the first retry, doubled for every retry after that
+    pub base_delay: Duration,
+    /// The longest delay between two attempts
+    pub max_delay: Duration,
+    /// Whether to randomly shorten each delay by up to half, so clients don't retry in lockstep
+    pub jitter: bool,
+    /// Which responses and errors are worth retrying
+    pub retry_on: RetryOn,
+    /// Whether to wait for as long as a response's `Retry-After` header asks, instead of the
+    /// usual backoff
+    pub honor_retry_after: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: None,
+            max_elapsed: Duration::from_secs(30),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+            retry_on: RetryOn::default(),
+            honor_retry_after: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// The backoff delay after the given attempt, ignoring `Retry-After`
+    pub fn backoff(&self, attempt: u32, rng: &mut impl Rng) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + rng.next_f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// How long to wait before sending the request again, after `attempt` attempts that started
+    /// `elapsed` ago. Returns `None` when the request shouldn't be retried anymore.
+    pub fn next_delay(
+        &self,
+        attempt: u32,
+        elapsed: Duration,
+        retry_after: Option<Duration>,
+        rng: &mut impl Rng,
+    ) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| attempt >= max) {
+            return None;
+        }
+        let delay = match retry_after.filter(|_| self.honor_retry_after) {
+            Some(retry_after) => retry_after,
+            None => self.backoff(attempt, rng),
+        };
+        if elapsed.saturating_add(delay) > self.max_elapsed {
+            None
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds. The HTTP-date form isn't supported, so a
+/// response that sends one falls back to the usual backoff.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// The status codes and errors that are retried, parsed from a comma-separated list like
+/// `5xx,429,timeout,connect`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryOn {
+    statuses: Vec<StatusRange>,
+    errors: Vec<RetryableError>,
+}
+
+impl Default for RetryOn {
+    /// Everything but `400 Bad Request`, which won't succeed no matter how often it's sent
+    fn default() -> Self {
+        RetryOn {
+            statuses: vec![
+                StatusRange::new(300, 399),
+                StatusRange::new(401, 499),
+                StatusRange::new(500, 599),
+            ],
+            errors: RetryableError::ALL.to_vec(),
+        }
+    }
+}
+
+impl RetryOn {
+    /// Whether a response with this status should be retried
+    pub fn status(&self, status: StatusCode) -> bool {
+        self.statuses.iter().any(|range| range.contains(status))
+    }
+
+    /// Whether a request that failed with this error should be retried
+    pub fn error(&self, error: &HttpServiceError) -> bool {
+        match error {
+            HttpServiceError::BadStatusCode { status_code, .. } => self.status(*status_code),
+            error => RetryableError::of(error).is_some_and(|kind| self.errors.contains(&kind)),
+        }
+    }
+
+    /// Whether a request that failed with this [`reqwest::Error`] should be retried, including
+    /// the errors returned by [`reqwest::Response::error_for_status`]
+    pub fn reqwest_error(&self, error: &reqwest::Error) -> bool {
+        match error.status() {
+            Some(status) => self.status(status),
+            None => {
+                RetryableError::of_reqwest(error).is_some_and(|kind| self.errors.contains(&kind))
+            }
+        }
+    }
+}
+
+impl FromStr for RetryOn {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut retry_on = RetryOn {
+            statuses: Vec::new(),
+            errors: Vec::new(),
+        };
+        for entry in value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            if let Ok(error) = entry.parse() {
+                retry_on.errors.push(error);
+            } else {
+                retry_on.statuses.push(entry.parse()?);
+            }
+        }
+        Ok(retry_on)
+    }
+}
+
+impl Display for RetryOn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self
+            .statuses
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.errors.iter().map(ToString::to_string))
+            .collect();
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+/// An inclusive range of status codes: `429`, `5xx`, or `401-499`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct StatusRange {
+    start: u16,
+    end: u16,
+}
+
+impl StatusRange {
+    const fn new(start: u16, end: u16) -> Self {
+        StatusRange { start, end }
+    }
+
+    fn contains(&self, status: StatusCode) -> bool {
+        (self.start..=self.end).contains(&status.as_u16())
+    }
+}
+
+impl FromStr for StatusRange {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "'{value}' is not a status code like '503', a range like '5xx' or '500-599', or one of: {}",
+                RetryableError::ALL
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        let code = |code: &str| {
+            code.parse::<u16>()
+                .ok()
+                .filter(|code| (100..=599).contains(code))
+                .ok_or_else(invalid)
+        };
+        let range = if let Some(class) = value.strip_suffix("xx") {
+            let start = code(&format!("{class}00"))?;
+            StatusRange::new(start, start + 99)
+        } else if let Some((start, end)) = value.split_once('-') {
+            StatusRange::new(code(start)?, code(end)?)
+        } else {
+            let code = code(value)?;
+            StatusRange::new(code, code)
+        };
+        if range.start > range.end {
+            return Err(invalid());
+        }
+        Ok(range)
+    }
+}
+
+impl Display for StatusRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else if self.start.is_multiple_of(100) && self.end == self.start + 99 {
+            write!(f, "{}xx", self.start / 100)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+/// The kinds of request failures that can be retried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryableError {
+    /// The request timed out
+    Timeout,
+    /// A connection couldn't be made
+    Connect,
+    /// The request failed after connecting, e.g. the connection was reset
+    Request,
+    /// The request or response body couldn't be sent or read
+    Body,
+    /// The response couldn't be decoded
+    Decode,
+    /// The connection closed unexpectedly
+    Closed,
+}
+
+impl RetryableError {
+    const ALL: &'static [RetryableError] = &[
+        RetryableError::Timeout,
+        RetryableError::Connect,
+        RetryableError::Request,
+        RetryableError::Body,
+        RetryableError::Decode,
+        RetryableError::Closed,
+    ];
+
+    const fn of(error: &HttpServiceError) -> Option<Self> {
+        match error {
+            HttpServiceError::TimedOut => Some(RetryableError::Timeout),
+            HttpServiceError::Connect(_) => Some(RetryableError::Connect),
+            HttpServiceError::Request(_) => Some(RetryableError::Request),
+            HttpServiceError::Body(_) => Some(RetryableError::Body),
+            HttpServiceError::Decode(_) => Some(RetryableError::Decode),
+            HttpServiceError::Closed(_) => Some(RetryableError::Closed),
+            _ => None,
+        }
+    }
+
+    fn of_reqwest(error: &reqwest::Error) -> Option<Self> {
+        if error.is_timeout() {
+            Some(RetryableError::Timeout)
+        } else if error.is_connect() {
+            Some(RetryableError::Connect)
+        } else if error.is_body() {
+            Some(RetryableError::Body)
+        } else if error.is_decode() {
+            Some(RetryableError::Decode)
+        } else if error.is_request() {
+            Some(RetryableError::Request)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for RetryableError {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "timeout" => Ok(RetryableError::Timeout),
+            "connect" => Ok(RetryableError::Connect),
+            "request" => Ok(RetryableError::Request),
+            "body" => Ok(RetryableError::Body),
+            "decode" => Ok(RetryableError::Decode),
+            "closed" => Ok(RetryableError::Closed),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for RetryableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RetryableError::Timeout => "timeout",
+            RetryableError::Connect => "connect",
+            RetryableError::Request => "request",
+            RetryableError::Body => "body",
+            RetryableError::Decode => "decode",
+            RetryableError::Closed => "closed",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Request extension set on retried requests, counting the first attempt as `1`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryAttempt(pub u32);
+
+/// [`Policy`] implementation that describes whether to retry a request, following a
+/// [`RetryConfig`]
 #[derive(Clone, Debug)]
 pub struct RetryPolicy {
+    config: RetryConfig,
     start_time: OnceCell<Instant>,
-    max_elapsed_time: Duration,
-    backoff: ExponentialBackoff,
+    attempts: u32,
+    rng: HasherRng,
 }
 
 impl RetryPolicy {
     /// Constructs a new [`RetryPolicy`]
-    pub fn new(max_elapsed_time: Duration) -> RetryPolicy {
-        let backoff = ExponentialBackoffMaker::new(
-            Duration::from_millis(500),
-            Duration::from_millis(60000),
-            0.99,
-            HasherRng::default(),
-        )
-        .tap_err(|err| tracing::error!("{:?}", err))
-        .unwrap()
-        .make_backoff();
+    pub fn new(config: RetryConfig) -> RetryPolicy {
         RetryPolicy {
+            config,
             start_time: OnceCell::new(),
-            max_elapsed_time,
-            backoff,
+            attempts: 0,
+            rng: HasherRng::default(),
         }
     }
-
-    /// Dictates whether a request can be retried, based on an optional maximum elapsed time
-    pub fn can_retry(&self) -> bool {
-        self.start_time.get_or_init(Instant::now).elapsed() < self.max_elapsed_time
-    }
 }
 
 impl Policy<HttpRequest, HttpResponse, HttpServiceError> for RetryPolicy {
     type Future = tokio::time::Sleep;
     fn retry(
         &mut self,
-        _: &mut HttpRequest,
+        req: &mut HttpRequest,
         result: &mut Result<HttpResponse, HttpServiceError>,
     ) -> Option<Self::Future> {
-        if self.can_retry() {
-            match result {
-                Err(HttpServiceError::TimedOut)
-                | Err(HttpServiceError::Connect(_))
-                | Err(HttpServiceError::Body(_))
-                | Err(HttpServiceError::Decode(_))
-                | Err(HttpServiceError::Request(_))
-                | Err(HttpServiceError::Closed(_)) => Some(self.backoff.next_backoff()),
-                Err(_) => None,
-                Ok(resp) => {
-                    let status = resp.status();
-                    if status.is_client_error()
-                        || status.is_server_error()
-                        || status.is_redirection()
-                    {
-                        if matches!(status, StatusCode::BAD_REQUEST) {
-                            None
-                        } else {
-                            Some(self.backoff.next_backoff())
-                        }
-                    } else {
-                        None
-                    }
-                }
-            }
-        } else {
-            None
-        }
+        let elapsed = self.start_time.get_or_init(Instant::now).elapsed();
+        self.attempts += 1;
+        let retry_after = match result {
+            Err(err) if self.config.retry_on.error(err) => None,
+            Ok(resp) if self.config.retry_on.status(resp.status()) => retry_after(resp.headers()),
+            _ => return None,
+        };
+        let delay = self
+            .config
+            .next_delay(self.attempts, elapsed, retry_after, &mut self.rng)?;
+        tracing::debug!(
+            "retrying request in {:?} (attempt {})",
+            delay,
+            self.attempts
+        );
+        req.extensions_mut().insert(RetryAttempt(self.attempts + 1));
+        Some(tokio::time::sleep(delay))
     }
 
     fn clone_request(&mut self, req: &HttpRequest) -> Option<HttpRequest> {
@@ -104,9 +407,9 @@ mod tests {
     use httpmock::MockServer;
     use rstest::{fixture, rstest};
     use speculoos::prelude::*;
-    use tower::{Service, ServiceBuilder, ServiceExt};
+    use tower::{util::rng::HasherRng, Service, ServiceBuilder, ServiceExt};
 
-    use super::RetryPolicy;
+    use super::{RetryConfig, RetryOn, RetryPolicy};
     use crate::{HttpService, ReqwestService};
 
     #[fixture]
@@ -119,30 +422,27 @@ mod tests {
             .boxed_clone()
     }
 
-    #[fixture]
-    pub fn retry_policy() -> RetryPolicy {
-        RetryPolicy::new(Duration::from_millis(1500))
-    }
-
-    #[fixture]
-    pub fn retry_service(retry_policy: RetryPolicy, raw_service: HttpService) -> HttpService {
+    fn retry_service(config: RetryConfig, raw_service: HttpService) -> HttpService {
         ServiceBuilder::new()
-            .retry(retry_policy)
+            .retry(RetryPolicy::new(config))
             .service(raw_service)
             .boxed_clone()
     }
 
-    #[rstest]
-    #[tokio::test]
-    pub async fn test_backoff(mut retry_service: HttpService) -> Result<()> {
+    async fn call_count(
+        mut service: HttpService,
+        status: u16,
+        retry_after: Option<&str>,
+    ) -> Result<usize> {
         let server = MockServer::start();
-        let addr = server.address().to_string();
-        println!("addr: {addr}");
-        let uri = format!("http://{}/", addr);
+        let uri = format!("http://{}/", server.address());
 
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET).path("/");
-            then.status(500).body("");
+            let then = then.status(status).body("");
+            if let Some(retry_after) = retry_after {
+                then.header("retry-after", retry_after);
+            }
         });
 
         let request = http::Request::builder()
@@ -150,13 +450,138 @@ mod tests {
             .method(http::Method::GET)
             .body(Full::default())?;
 
-        let resp = retry_service.call(request).await;
-
-        mock.assert_calls(3);
+        let resp = service.call(request).await;
 
         assert_that!(resp)
             .is_ok()
-            .matches(|resp| resp.status() == StatusCode::INTERNAL_SERVER_ERROR);
+            .matches(|resp| resp.status() == StatusCode::from_u16(status).unwrap());
+        Ok(mock.calls())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    pub async fn test_backoff(raw_service: HttpService) -> Result<()> {
+        // attempts at 0ms, 500ms and 1500ms; waiting another 2s would exceed the budget
+        let config = RetryConfig {
+            max_elapsed: Duration::from_secs(2),
+            jitter: false,
+            ..Default::default()
+        };
+        let calls = call_count(retry_service(config, raw_service), 500, None).await?;
+        assert_that!(calls).is_equal_to(3);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    pub async fn test_max_attempts(raw_service: HttpService) -> Result<()> {
+        let config = RetryConfig {
+            max_attempts: Some(2),
+            base_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let calls = call_count(retry_service(config, raw_service), 503, None).await?;
+        assert_that!(calls).is_equal_to(2);
+        Ok(())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    pub async fn test_does_not_retry_bad_requests(raw_service: HttpService) -> Result<()> {
+        let calls = call_count(
+            retry_service(RetryConfig::default(), raw_service),
+            400,
+            None,
+        )
+        .await?;
+        assert_that!(calls).is_equal_to(1);
         Ok(())
     }
+
+    #[rstest]
+    #[case::honored(true, 2)]
+    #[case::ignored(false, 1)]
+    #[tokio::test]
+    pub async fn test_retry_after(
+        raw_service: HttpService,
+        #[case] honor_retry_after: bool,
+        #[case] expected_calls: usize,
+    ) -> Result<()> {
+        // the backoff alone is longer than the budget, so only an honored `Retry-After` retries
+        let config = RetryConfig {
+            max_attempts: Some(2),
+            max_elapsed: Duration::from_secs(5),
+            base_delay: Duration::from_secs(10),
+            honor_retry_after,
+            ..Default::default()
+        };
+        let calls = call_count(retry_service(config, raw_service), 429, Some("0")).await?;
+        assert_that!(calls).is_equal_to(expected_calls);
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_after_ignores_http_dates() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_that!(super::retry_after(&headers)).is_none();
+        headers.insert(http::header::RETRY_AFTER, " 120 ".parse().unwrap());
+        assert_eq!(super::retry_after(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_delays_are_capped() {
+        let config = RetryConfig {
+            max_delay: Duration::from_secs(3),
+            jitter: false,
+            ..Default::default()
+        };
+        let mut rng = HasherRng::default();
+        let delays: Vec<_> = (1..=5)
+            .map(|attempt| config.backoff(attempt, &mut rng))
+            .collect();
+        assert_eq!(
+            delays,
+            [500, 1000, 2000, 3000, 3000].map(Duration::from_millis)
+        );
+    }
+
+    #[rstest]
+    #[case("5xx,429,timeout", &[503, 429], &[400, 404])]
+    #[case("401-499", &[401, 404, 499], &[400, 500])]
+    #[case("", &[], &[500, 503])]
+    fn test_retry_on_statuses(
+        #[case] value: &str,
+        #[case] retried: &[u16],
+        #[case] not_retried: &[u16],
+    ) {
+        let retry_on: RetryOn = value.parse().unwrap();
+        for status in retried {
+            assert!(
+                retry_on.status(StatusCode::from_u16(*status).unwrap()),
+                "{status}"
+            );
+        }
+        for status in not_retried {
+            assert!(
+                !retry_on.status(StatusCode::from_u16(*status).unwrap()),
+                "{status}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_on_round_trips() {
+        let default = RetryOn::default();
+        assert_eq!(
+            default.to_string(),
+            "3xx,401-499,5xx,timeout,connect,request,body,decode,closed"
+        );
+        assert_eq!(default.to_string().parse::<RetryOn>().unwrap(), default);
+        assert!("5xx,sometimes".parse::<RetryOn>().is_err());
+        assert!("600".parse::<RetryOn>().is_err());
+    }
 }
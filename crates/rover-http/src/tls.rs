@@ -0,0 +1,47 @@
+//! TLS settings shared by the HTTP clients Rover builds
+
+use reqwest::{Certificate, ClientBuilder, Identity};
+
+/// PEM-encoded certificates to add to an HTTP client, on top of its default TLS settings
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// Extra certificate authorities to trust, as a PEM bundle
+    pub ca_certificates: Option<Vec<u8>>,
+    /// A client certificate chain and its private key, used for mutual TLS
+    pub client_identity: Option<Vec<u8>>,
+}
+
+impl TlsConfig {
+    /// Adds the certificates to a [`reqwest::ClientBuilder`]
+    pub fn apply(&self, mut builder: ClientBuilder) -> Result<ClientBuilder, reqwest::Error> {
+        if let Some(pem) = &self.ca_certificates {
+            builder = builder.tls_certs_merge(Certificate::from_pem_bundle(pem)?);
+        }
+        if let Some(pem) = &self.client_identity {
+            builder = builder.identity(Identity::from_pem(pem)?);
+        }
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_invalid_identity() {
+        let tls = TlsConfig {
+            ca_certificates: None,
+            client_identity: Some(b"not a certificate".to_vec()),
+        };
+        assert!(tls.apply(reqwest::Client::builder()).is_err());
+    }
+
+    #[test]
+    fn test_default_leaves_builder_alone() {
+        let builder = TlsConfig::default()
+            .apply(reqwest::Client::builder())
+            .unwrap();
+        assert!(builder.build().is_ok());
+    }
+}
@@ -0,0 +1,687 @@
+use std::collections::{HashSet, VecDeque};
+
+use apollo_compiler::{
+    Name, Node, Schema,
+    ast::{Directive, InputValueDefinition, OperationType},
+    schema::ExtendedType,
+};
+use thiserror::Error;
+
+/// Errors that can occur while building a contract schema.
+#[derive(Error, Debug)]
+pub enum ContractError {
+    /// The supergraph SDL couldn't be parsed.
+    #[error("Could not parse the supergraph schema:\n{0}")]
+    Parse(String),
+
+    /// Filtering left the contract without any fields on its query type.
+    #[error(
+        "The contract schema has no Query fields. Check that the include tags match at least one Query field."
+    )]
+    EmptyQuery,
+}
+
+/// The `@tag` based filters of a contract variant, applied locally to a supergraph schema.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContractFilter {
+    /// Tags to include. When non-empty, object and interface fields are only kept if they or
+    /// their parent type carry one of these tags. Enum values, input fields and arguments are
+    /// narrowed the same way when at least one of their siblings carries one of these tags, and
+    /// otherwise follow the type or field they belong to. Required input fields and arguments are
+    /// always kept, since leaving them out would change what the subgraphs accept.
+    pub include_tags: Vec<String>,
+    /// Tags to exclude. Any element carrying one of these tags is removed.
+    pub exclude_tags: Vec<String>,
+    /// Whether to remove types that can't be reached from a root operation type.
+    pub hide_unreachable_types: bool,
+}
+
+/// The local names of the specs linked into a supergraph.
+struct LinkedSpecs {
+    tag: String,
+    inaccessible: String,
+    /// Every linked spec's local name. Directives and types belonging to these specs are
+    /// removed from the API schema.
+    names: Vec<String>,
+}
+
+impl LinkedSpecs {
+    fn from_schema(schema: &Schema) -> Self {
+        let mut specs = LinkedSpecs {
+            tag: "tag".to_string(),
+            inaccessible: "inaccessible".to_string(),
+            names: vec!["tag".to_string(), "inaccessible".to_string()],
+        };
+        // Federation 2 supergraphs use @link, Federation 1 supergraphs use @core
+        for directive in schema
+            .schema_definition
+            .directives
+            .iter()
+            .filter(|directive| directive.name == "link" || directive.name == "core")
+        {
+            let Some(url) =
+                string_argument(directive, "url").or_else(|| string_argument(directive, "feature"))
+            else {
+                continue;
+            };
+            // Spec URLs look like https://specs.apollo.dev/join/v0.3
+            let Some(identity) = url.trim_end_matches('/').rsplit('/').nth(1) else {
+                continue;
+            };
+            let name = string_argument(directive, "as").unwrap_or(identity);
+            match identity {
+                "tag" => specs.tag = name.to_string(),
+                "inaccessible" => specs.inaccessible = name.to_string(),
+                _ => {}
+            }
+            specs.names.push(name.to_string());
+        }
+        specs
+    }
+
+    fn owns_directive(&self, directive_name: &str) -> bool {
+        self.names.iter().any(|name| {
+            directive_name == name
+                || directive_name
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.starts_with("__"))
+        })
+    }
+
+    fn owns_type(&self, type_name: &str) -> bool {
+        self.names.iter().any(|name| {
+            type_name
+                .strip_prefix(name.as_str())
+                .is_some_and(|rest| rest.starts_with("__"))
+        })
+    }
+}
+
+fn string_argument<'a>(directive: &'a Directive, name: &str) -> Option<&'a str> {
+    directive
+        .specified_argument_by_name(name)
+        .and_then(|value| value.as_str())
+}
+
+impl ContractFilter {
+    /// Builds the contract's API schema from a supergraph schema, without contacting GraphOS.
+    pub fn build(&self, supergraph_sdl: &str) -> Result<String, ContractError> {
+        let mut schema = Schema::parse(supergraph_sdl, "supergraph.graphql")
+            .map_err(|with_errors| ContractError::Parse(with_errors.errors.to_string()))?;
+        let specs = LinkedSpecs::from_schema(&schema);
+
+        self.apply_tags(&mut schema, &specs);
+        remove_specs(&mut schema, &specs);
+        prune(&mut schema);
+        if self.hide_unreachable_types {
+            remove_unreachable_types(&mut schema);
+            prune(&mut schema);
+        }
+
+        if schema.schema_definition.query.is_none() {
+            return Err(ContractError::EmptyQuery);
+        }
+        Ok(schema.serialize().to_string())
+    }
+
+    fn tags<'a>(
+        directives: impl IntoIterator<Item = &'a Directive>,
+        specs: &LinkedSpecs,
+    ) -> Vec<&'a str> {
+        directives
+            .into_iter()
+            .filter(|directive| directive.name == specs.tag.as_str())
+            .filter_map(|directive| string_argument(directive, "name"))
+            .collect()
+    }
+
+    fn is_hidden<'a>(
+        &self,
+        directives: impl IntoIterator<Item = &'a Directive>,
+        specs: &LinkedSpecs,
+    ) -> bool {
+        let directives: Vec<&Directive> = directives.into_iter().collect();
+        directives
+            .iter()
+            .any(|directive| directive.name == specs.inaccessible.as_str())
+            || Self::tags(directives.iter().copied(), specs)
+                .into_iter()
+                .any(|tag| self.exclude_tags.iter().any(|excluded| excluded == tag))
+    }
+
+    fn is_included<'a>(
+        &self,
+        directives: impl IntoIterator<Item = &'a Directive>,
+        specs: &LinkedSpecs,
+    ) -> bool {
+        Self::tags(directives, specs)
+            .into_iter()
+            .any(|tag| self.include_tags.iter().any(|included| included == tag))
+    }
+
+    /// Whether an element stays, given whether the type or field it belongs to was included as
+    /// a whole.
+    fn keeps<'a>(
+        &self,
+        directives: impl IntoIterator<Item = &'a Directive> + Clone,
+        parent_included: bool,
+        specs: &LinkedSpecs,
+    ) -> bool {
+        !self.is_hidden(directives.clone(), specs)
+            && (parent_included || self.is_included(directives, specs))
+    }
+
+    /// Whether the members of a type or field that wasn't included as a whole are narrowed
+    /// down to the tagged ones, which only happens when one of them carries an included tag.
+    fn narrows<'a, D>(&self, members: impl IntoIterator<Item = D>, specs: &LinkedSpecs) -> bool
+    where
+        D: IntoIterator<Item = &'a Directive>,
+    {
+        members
+            .into_iter()
+            .any(|directives| self.is_included(directives, specs))
+    }
+
+    fn filter_arguments(
+        &self,
+        arguments: &mut Vec<Node<InputValueDefinition>>,
+        specs: &LinkedSpecs,
+    ) {
+        let include_all = self.include_tags.is_empty()
+            || !self.narrows(
+                arguments
+                    .iter()
+                    .map(|argument| argument.directives.iter().map(|d| &**d)),
+                specs,
+            );
+        arguments.retain(|argument| {
+            self.keeps(
+                argument.directives.iter().map(|d| &**d),
+                include_all || is_required(argument),
+                specs,
+            )
+        })
+    }
+
+    /// Removes the elements that are `@inaccessible`, excluded, or not included.
+    fn apply_tags(&self, schema: &mut Schema, specs: &LinkedSpecs) {
+        schema.types.retain(|_, ty| {
+            ty.is_built_in() || !self.is_hidden(ty.directives().iter().map(|d| &***d), specs)
+        });
+        for ty in schema.types.values_mut() {
+            if ty.is_built_in() {
+                continue;
+            }
+            let include_all = self.include_tags.is_empty()
+                || self.is_included(ty.directives().iter().map(|d| &***d), specs);
+            match ty {
+                ExtendedType::Object(object) => {
+                    let object = object.make_mut();
+                    object.fields.retain(|_, field| {
+                        self.keeps(field.directives.iter().map(|d| &**d), include_all, specs)
+                    });
+                    for field in object.fields.values_mut() {
+                        self.filter_arguments(&mut field.make_mut().arguments, specs);
+                    }
+                }
+                ExtendedType::Interface(interface) => {
+                    let interface = interface.make_mut();
+                    interface.fields.retain(|_, field| {
+                        self.keeps(field.directives.iter().map(|d| &**d), include_all, specs)
+                    });
+                    for field in interface.fields.values_mut() {
+                        self.filter_arguments(&mut field.make_mut().arguments, specs);
+                    }
+                }
+                ExtendedType::Enum(enum_type) => {
+                    let include_all = include_all
+                        || !self.narrows(
+                            enum_type
+                                .values
+                                .values()
+                                .map(|value| value.directives.iter().map(|d| &**d)),
+                            specs,
+                        );
+                    enum_type.make_mut().values.retain(|_, value| {
+                        self.keeps(value.directives.iter().map(|d| &**d), include_all, specs)
+                    });
+                }
+                ExtendedType::InputObject(input) => {
+                    let include_all = include_all
+                        || !self.narrows(
+                            input
+                                .fields
+                                .values()
+                                .map(|field| field.directives.iter().map(|d| &**d)),
+                            specs,
+                        );
+                    input.make_mut().fields.retain(|_, field| {
+                        self.keeps(
+                            field.directives.iter().map(|d| &**d),
+                            include_all || is_required(field),
+                            specs,
+                        )
+                    });
+                }
+                ExtendedType::Union(_) | ExtendedType::Scalar(_) => {}
+            }
+        }
+    }
+}
+
+/// Removes the linked specs' directives and types, leaving only what clients can see.
+fn remove_specs(schema: &mut Schema, specs: &LinkedSpecs) {
+    schema
+        .directive_definitions
+        .retain(|name, _| !specs.owns_directive(name));
+    schema.types.retain(|name, _| !specs.owns_type(name));
+    schema
+        .schema_definition
+        .make_mut()
+        .directives
+        .retain(|directive| !specs.owns_directive(&directive.name));
+
+    let keep = |directive: &Directive| !specs.owns_directive(&directive.name);
+    for ty in schema.types.values_mut() {
+        if ty.is_built_in() {
+            continue;
+        }
+        match ty {
+            ExtendedType::Object(object) => {
+                let object = object.make_mut();
+                object.directives.retain(|directive| keep(directive));
+                for field in object.fields.values_mut() {
+                    let field = field.make_mut();
+                    field.directives.retain(|directive| keep(directive));
+                    for argument in &mut field.arguments {
+                        argument
+                            .make_mut()
+                            .directives
+                            .retain(|directive| keep(directive));
+                    }
+                }
+            }
+            ExtendedType::Interface(interface) => {
+                let interface = interface.make_mut();
+                interface.directives.retain(|directive| keep(directive));
+                for field in interface.fields.values_mut() {
+                    let field = field.make_mut();
+                    field.directives.retain(|directive| keep(directive));
+                    for argument in &mut field.arguments {
+                        argument
+                            .make_mut()
+                            .directives
+                            .retain(|directive| keep(directive));
+                    }
+                }
+            }
+            ExtendedType::Enum(enum_type) => {
+                let enum_type = enum_type.make_mut();
+                enum_type.directives.retain(|directive| keep(directive));
+                for value in enum_type.values.values_mut() {
+                    value
+                        .make_mut()
+                        .directives
+                        .retain(|directive| keep(directive));
+                }
+            }
+            ExtendedType::InputObject(input) => {
+                let input = input.make_mut();
+                input.directives.retain(|directive| keep(directive));
+                for field in input.fields.values_mut() {
+                    field
+                        .make_mut()
+                        .directives
+                        .retain(|directive| keep(directive));
+                }
+            }
+            ExtendedType::Union(union_type) => {
+                union_type
+                    .make_mut()
+                    .directives
+                    .retain(|directive| keep(directive));
+            }
+            ExtendedType::Scalar(scalar) => {
+                scalar
+                    .make_mut()
+                    .directives
+                    .retain(|directive| keep(directive));
+            }
+        }
+    }
+}
+
+fn is_required(argument: &InputValueDefinition) -> bool {
+    argument.ty.is_non_null() && argument.default_value.is_none()
+}
+
+/// Removes whatever filtering left dangling: fields returning removed types, types left without
+/// fields or members, and so on, until nothing changes.
+fn prune(schema: &mut Schema) {
+    loop {
+        let names: HashSet<Name> = schema.types.keys().cloned().collect();
+        let exists = |name: &Name| names.contains(name);
+        let mut removed: HashSet<Name> = HashSet::new();
+        let mut changed = false;
+
+        for (name, ty) in schema.types.iter_mut() {
+            if ty.is_built_in() {
+                continue;
+            }
+            let empty = match ty {
+                ExtendedType::Object(object) => {
+                    let object = object.make_mut();
+                    let before = object.fields.len() + object.implements_interfaces.len();
+                    object
+                        .implements_interfaces
+                        .retain(|interface| exists(&interface.name));
+                    object.fields.retain(|_, field| {
+                        exists(field.ty.inner_named_type())
+                            && field.arguments.iter().all(|argument| {
+                                exists(argument.ty.inner_named_type()) || !is_required(argument)
+                            })
+                    });
+                    for field in object.fields.values_mut() {
+                        field
+                            .make_mut()
+                            .arguments
+                            .retain(|argument| exists(argument.ty.inner_named_type()));
+                    }
+                    changed |= before != object.fields.len() + object.implements_interfaces.len();
+                    object.fields.is_empty()
+                }
+                ExtendedType::Interface(interface) => {
+                    let interface = interface.make_mut();
+                    let before = interface.fields.len() + interface.implements_interfaces.len();
+                    interface
+                        .implements_interfaces
+                        .retain(|interface| exists(&interface.name));
+                    interface.fields.retain(|_, field| {
+                        exists(field.ty.inner_named_type())
+                            && field.arguments.iter().all(|argument| {
+                                exists(argument.ty.inner_named_type()) || !is_required(argument)
+                            })
+                    });
+                    for field in interface.fields.values_mut() {
+                        field
+                            .make_mut()
+                            .arguments
+                            .retain(|argument| exists(argument.ty.inner_named_type()));
+                    }
+                    changed |=
+                        before != interface.fields.len() + interface.implements_interfaces.len();
+                    interface.fields.is_empty()
+                }
+                ExtendedType::Union(union_type) => {
+                    let union_type = union_type.make_mut();
+                    let before = union_type.members.len();
+                    union_type.members.retain(|member| exists(&member.name));
+                    changed |= before != union_type.members.len();
+                    union_type.members.is_empty()
+                }
+                ExtendedType::InputObject(input) => {
+                    // An input type can't be used without its required fields, so it goes too
+                    if input
+                        .fields
+                        .values()
+                        .any(|field| !exists(field.ty.inner_named_type()) && is_required(field))
+                    {
+                        true
+                    } else {
+                        let input = input.make_mut();
+                        let before = input.fields.len();
+                        input
+                            .fields
+                            .retain(|_, field| exists(field.ty.inner_named_type()));
+                        changed |= before != input.fields.len();
+                        input.fields.is_empty()
+                    }
+                }
+                ExtendedType::Enum(enum_type) => enum_type.values.is_empty(),
+                ExtendedType::Scalar(_) => false,
+            };
+            if empty {
+                removed.insert(name.clone());
+            }
+        }
+
+        if !removed.is_empty() {
+            schema.types.retain(|name, _| !removed.contains(name));
+            changed = true;
+        }
+        let schema_definition = schema.schema_definition.make_mut();
+        for root in [
+            &mut schema_definition.query,
+            &mut schema_definition.mutation,
+            &mut schema_definition.subscription,
+        ] {
+            if root
+                .as_ref()
+                .is_some_and(|root_name| !schema.types.contains_key(&root_name.name))
+            {
+                *root = None;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Removes the types that no operation can reach, starting from the root operation types and
+/// the arguments of the remaining directives.
+fn remove_unreachable_types(schema: &mut Schema) {
+    let mut queue: VecDeque<Name> = [
+        OperationType::Query,
+        OperationType::Mutation,
+        OperationType::Subscription,
+    ]
+    .into_iter()
+    .filter_map(|operation| schema.root_operation(operation).cloned())
+    .chain(
+        schema
+            .directive_definitions
+            .values()
+            .flat_map(|directive| directive.arguments.iter())
+            .map(|argument| argument.ty.inner_named_type().clone()),
+    )
+    .collect();
+
+    let mut reachable: HashSet<Name> = HashSet::new();
+    while let Some(name) = queue.pop_front() {
+        if !reachable.insert(name.clone()) {
+            continue;
+        }
+        let Some(ty) = schema.types.get(&name) else {
+            continue;
+        };
+        let fields = match ty {
+            ExtendedType::Object(object) => Some(&object.fields),
+            ExtendedType::Interface(interface) => Some(&interface.fields),
+            _ => None,
+        };
+        if let Some(fields) = fields {
+            for field in fields.values() {
+                queue.push_back(field.ty.inner_named_type().clone());
+                queue.extend(
+                    field
+                        .arguments
+                        .iter()
+                        .map(|argument| argument.ty.inner_named_type().clone()),
+                );
+            }
+        }
+        match ty {
+            ExtendedType::Interface(_) => {
+                // Anything implementing a reachable interface can be returned through it
+                queue.extend(schema.types.iter().filter_map(|(implementor, ty)| {
+                    let implements = match ty {
+                        ExtendedType::Object(object) => &object.implements_interfaces,
+                        ExtendedType::Interface(interface) => &interface.implements_interfaces,
+                        _ => return None,
+                    };
+                    implements
+                        .iter()
+                        .any(|interface| interface.name == name)
+                        .then(|| implementor.clone())
+                }));
+            }
+            ExtendedType::Union(union_type) => {
+                queue.extend(union_type.members.iter().map(|member| member.name.clone()));
+            }
+            ExtendedType::InputObject(input) => {
+                queue.extend(
+                    input
+                        .fields
+                        .values()
+                        .map(|field| field.ty.inner_named_type().clone()),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    schema
+        .types
+        .retain(|name, ty| ty.is_built_in() || reachable.contains(name));
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use rstest::{fixture, rstest};
+    use speculoos::prelude::*;
+
+    use super::{ContractError, ContractFilter};
+
+    #[fixture]
+    fn supergraph() -> &'static str {
+        indoc! {r#"
+            schema
+              @link(url: "https://specs.apollo.dev/link/v1.0")
+              @link(url: "https://specs.apollo.dev/join/v0.3", for: EXECUTION)
+              @link(url: "https://specs.apollo.dev/tag/v0.3")
+              @link(url: "https://specs.apollo.dev/inaccessible/v0.2", for: SECURITY)
+            {
+              query: Query
+            }
+
+            directive @join__field(graph: join__Graph, requires: join__FieldSet, provides: join__FieldSet, type: String, external: Boolean, override: String, usedOverridden: Boolean) repeatable on FIELD_DEFINITION | INPUT_FIELD_DEFINITION
+            directive @join__graph(name: String!, url: String!) on ENUM_VALUE
+            directive @join__type(graph: join__Graph!, key: join__FieldSet, extension: Boolean! = false, resolvable: Boolean! = true, isInterfaceObject: Boolean! = false) repeatable on OBJECT | INTERFACE | UNION | ENUM | INPUT_OBJECT | SCALAR
+            directive @link(url: String, as: String, for: link__Purpose, import: [link__Import]) repeatable on SCHEMA
+            directive @tag(name: String!) repeatable on FIELD_DEFINITION | OBJECT | INTERFACE | UNION | ARGUMENT_DEFINITION | SCALAR | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION | SCHEMA
+            directive @inaccessible on FIELD_DEFINITION | OBJECT | INTERFACE | UNION | ARGUMENT_DEFINITION | SCALAR | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION
+
+            scalar join__FieldSet
+
+            enum join__Graph {
+              PRODUCTS @join__graph(name: "products", url: "http://localhost:4001")
+            }
+
+            scalar link__Import
+
+            enum link__Purpose {
+              SECURITY
+              EXECUTION
+            }
+
+            type Query @join__type(graph: PRODUCTS) {
+              products(filter: ProductFilter @tag(name: "public"), debug: Boolean): [Product!]! @tag(name: "public")
+              stats: Stats @tag(name: "internal")
+              secret: String @inaccessible
+            }
+
+            type Product @join__type(graph: PRODUCTS, key: "id") @tag(name: "public") {
+              id: ID!
+              name: String
+              cost: Float @tag(name: "internal")
+              category: Category
+            }
+
+            enum Category @join__type(graph: PRODUCTS) {
+              BOOKS @tag(name: "public")
+              TOYS
+            }
+
+            input ProductFilter @join__type(graph: PRODUCTS) {
+              id: ID!
+              name: String @tag(name: "public")
+              onSale: Boolean
+            }
+
+            type Stats @join__type(graph: PRODUCTS) {
+              count: Int
+            }
+
+            type Orphan @join__type(graph: PRODUCTS) @tag(name: "public") {
+              id: ID!
+            }
+        "#}
+    }
+
+    fn filter(include: &[&str], exclude: &[&str], hide_unreachable_types: bool) -> ContractFilter {
+        ContractFilter {
+            include_tags: include.iter().map(|tag| tag.to_string()).collect(),
+            exclude_tags: exclude.iter().map(|tag| tag.to_string()).collect(),
+            hide_unreachable_types,
+        }
+    }
+
+    #[rstest]
+    fn no_filters_produces_api_schema(supergraph: &str) {
+        let sdl = filter(&[], &[], false).build(supergraph).unwrap();
+        assert_that!(sdl).contains("stats: Stats");
+        assert_that!(sdl).contains("type Orphan");
+        assert_that!(sdl).does_not_contain("secret");
+        assert_that!(sdl).does_not_contain("join__");
+        assert_that!(sdl).does_not_contain("link__");
+        assert_that!(sdl).does_not_contain("@tag");
+        assert_that!(sdl).does_not_contain("@link");
+    }
+
+    #[rstest]
+    fn exclude_tags_remove_tagged_elements(supergraph: &str) {
+        let sdl = filter(&[], &["internal"], false).build(supergraph).unwrap();
+        assert_that!(sdl).contains("products(filter: ProductFilter, debug: Boolean)");
+        assert_that!(sdl).does_not_contain("stats: Stats");
+        assert_that!(sdl).does_not_contain("cost");
+        // Still there, because unreachable types aren't hidden
+        assert_that!(sdl).contains("type Stats");
+    }
+
+    #[rstest]
+    fn include_tags_keep_tagged_fields_and_types(supergraph: &str) {
+        let sdl = filter(&["public"], &[], false).build(supergraph).unwrap();
+        assert_that!(sdl).contains("products(filter: ProductFilter): [Product!]!");
+        assert_that!(sdl).contains("cost: Float");
+        assert_that!(sdl).contains("type Orphan");
+        assert_that!(sdl).does_not_contain("stats");
+        assert_that!(sdl).does_not_contain("type Stats");
+    }
+
+    #[rstest]
+    fn include_tags_narrow_enum_values_input_fields_and_arguments(supergraph: &str) {
+        let sdl = filter(&["public"], &[], false).build(supergraph).unwrap();
+        assert_that!(sdl).contains("BOOKS");
+        assert_that!(sdl).does_not_contain("TOYS");
+        assert_that!(sdl).does_not_contain("debug");
+        // Required input fields stay, untagged optional ones go
+        assert_that!(sdl).contains("input ProductFilter {\n  id: ID!\n  name: String\n}");
+    }
+
+    #[rstest]
+    fn hide_unreachable_types_removes_orphans(supergraph: &str) {
+        let sdl = filter(&[], &["internal"], true).build(supergraph).unwrap();
+        assert_that!(sdl).contains("type Product");
+        assert_that!(sdl).does_not_contain("type Stats");
+        assert_that!(sdl).does_not_contain("type Orphan");
+    }
+
+    #[rstest]
+    fn empty_query_is_an_error(supergraph: &str) {
+        let result = filter(&["nothing"], &[], false).build(supergraph);
+        assert!(matches!(result, Err(ContractError::EmptyQuery)));
+    }
+}
@@ -2,6 +2,8 @@
 
 #![warn(missing_docs)]
 
+/// Local contract schema generation from `@tag` filters.
+pub mod contract;
 /// Schema description and introspection utilities.
 pub mod describe;
 /// Error types for schema operations.
@@ -12,6 +14,7 @@ pub mod parsed_schema;
 pub mod root_paths;
 // Re-export main public types
 pub use apollo_compiler::coordinate::SchemaCoordinate;
+pub use contract::{ContractError, ContractFilter};
 pub use describe::{
     DescribeOutput, DirectiveArgDetail, DirectiveDetail, EnumDetail, ExtendedFieldsDetail,
     FieldArgDetail, FieldDetail, FieldsDetail, InputDetail, InputFieldInfo, InterfaceDetail,
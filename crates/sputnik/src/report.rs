@@ -44,17 +44,33 @@ pub trait Report {
         get_or_write_machine_id(&config_path)
     }
 
+    /// returns the globally persistent machine identifier if one has
+    /// already been written, without writing one
+    fn existing_machine_id(&self) -> Result<Option<Uuid>, SputnikError> {
+        let config_path = self.machine_id_config()?;
+        Ok(read_machine_id(&config_path))
+    }
+
     /// returns the Client to use when sending telemetry data
     fn client(&self) -> Result<Client, SputnikError>;
+
+    /// returns a file every reported payload should be appended to, if any.
+    /// by default, payloads are only sent, not kept.
+    fn telemetry_log(&self) -> Option<Utf8PathBuf> {
+        None
+    }
 }
 
 fn get_or_write_machine_id(path: &Utf8PathBuf) -> Result<Uuid, SputnikError> {
-    if let Ok(contents) = Fs::read_file(path) {
-        if let Ok(machine_uuid) = Uuid::parse_str(contents.trim()) {
-            return Ok(machine_uuid);
-        }
+    match read_machine_id(path) {
+        Some(machine_uuid) => Ok(machine_uuid),
+        None => write_machine_id(path),
     }
-    write_machine_id(path)
+}
+
+fn read_machine_id(path: &Utf8PathBuf) -> Option<Uuid> {
+    let contents = Fs::read_file(path).ok()?;
+    Uuid::parse_str(contents.trim()).ok()
 }
 
 fn write_machine_id(path: &Utf8PathBuf) -> Result<Uuid, SputnikError> {
@@ -71,7 +87,7 @@ mod tests {
     use assert_fs::prelude::*;
     use camino::Utf8PathBuf;
 
-    use super::{get_or_write_machine_id, write_machine_id};
+    use super::{get_or_write_machine_id, read_machine_id, write_machine_id};
 
     /// if a machine ID hasn't been written already, one will be created
     /// and saved.
@@ -105,4 +121,14 @@ mod tests {
         let test_path = Utf8PathBuf::try_from(test_file.path().to_path_buf()).unwrap();
         assert!(get_or_write_machine_id(&test_path).is_ok());
     }
+
+    /// reading a machine ID that does not exist yet doesn't write one
+    #[test]
+    fn it_does_not_write_a_machine_id_when_reading() {
+        let fixture = assert_fs::TempDir::new().unwrap();
+        let test_file = fixture.child("test_read_missing_machine_id.txt");
+        let test_path = Utf8PathBuf::try_from(test_file.path().to_path_buf()).unwrap();
+        assert_eq!(read_machine_id(&test_path), None);
+        assert!(!test_path.exists());
+    }
 }
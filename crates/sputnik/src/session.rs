@@ -1,9 +1,13 @@
-use std::{collections::HashMap, convert::TryFrom, env, fmt::Debug, time::Duration};
+use std::{
+    collections::HashMap, convert::TryFrom, env, fmt::Debug, fs::OpenOptions, io::Write,
+    time::Duration,
+};
 
 use camino::Utf8PathBuf;
 use ci_info::types::Vendor as CiVendor;
 use reqwest::{Client, Url};
 use rover_client::shared::GitContext;
+use rover_std::Fs;
 use semver::Version;
 use serde::Serialize;
 use sha2::{Digest, Sha256};
@@ -82,19 +86,32 @@ struct ReportingInfo {
     is_telemetry_enabled: bool,
     endpoint: Url,
     user_agent: String,
+    log: Option<Utf8PathBuf>,
 }
 
 impl Session {
     /// creates a new Session containing info about the current command
     /// being executed.
     pub fn new<T: Report>(app: &T) -> Result<Session, SputnikError> {
-        let machine_id = app.machine_id()?;
+        Self::with_machine_id(app, app.machine_id()?)
+    }
+
+    /// creates a Session to preview what would be reported, without writing
+    /// a machine identifier. if none has been written yet, the session uses
+    /// a new one that isn't saved.
+    pub fn preview<T: Report>(app: &T) -> Result<Session, SputnikError> {
+        let machine_id = app.existing_machine_id()?.unwrap_or_else(Uuid::new_v4);
+        Self::with_machine_id(app, machine_id)
+    }
+
+    fn with_machine_id<T: Report>(app: &T, machine_id: Uuid) -> Result<Session, SputnikError> {
         let command = app.serialize_command()?;
         let client = app.client()?;
         let reporting_info = ReportingInfo {
             is_telemetry_enabled: app.is_telemetry_enabled()?,
             endpoint: app.endpoint()?,
             user_agent: app.user_agent(),
+            log: app.telemetry_log(),
         };
         let current_dir = Utf8PathBuf::try_from(env::current_dir()?)?;
         let session_id = Uuid::new_v4();
@@ -134,32 +151,57 @@ impl Session {
         })
     }
 
-    /// sends anonymous usage data to the endpoint defined in ReportingInfo.
+    /// returns the exact JSON payload that `report` sends.
+    pub fn payload(&self) -> Result<serde_json::Value, SputnikError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    /// whether `report` will send anything.
+    pub const fn is_telemetry_enabled(&self) -> bool {
+        self.reporting_info.is_telemetry_enabled
+    }
+
+    /// sends anonymous usage data to the endpoint defined in ReportingInfo,
+    /// appending it to the telemetry log first if there is one.
     pub async fn report(&self) -> Result<(), SputnikError> {
+        if !self.reporting_info.is_telemetry_enabled {
+            return Ok(());
+        }
+        let body = serde_json::to_string(&self)?;
+        if let Some(log) = &self.reporting_info.log {
+            append_to_log(log, &body)?;
+        }
         // TODO: consider whether we want to disable non-production telemetry or at least document
         //  the reasoning for not using it
         if cfg!(debug_assertions) {
             tracing::debug!("Skipping telemetry reporting");
             return Ok(());
         }
-        if self.reporting_info.is_telemetry_enabled {
-            let body = serde_json::to_string(&self)?;
-            tracing::debug!("POSTing to {}", &self.reporting_info.endpoint);
-            tracing::debug!("{}", body);
-            self.client
-                .post(self.reporting_info.endpoint.clone())
-                .body(body)
-                .header("User-Agent", &self.reporting_info.user_agent)
-                .header("Content-Type", "application/json")
-                .timeout(REPORT_TIMEOUT)
-                .send()
-                .await?;
-        }
+        tracing::debug!("POSTing to {}", &self.reporting_info.endpoint);
+        tracing::debug!("{}", body);
+        self.client
+            .post(self.reporting_info.endpoint.clone())
+            .body(body)
+            .header("User-Agent", &self.reporting_info.user_agent)
+            .header("Content-Type", "application/json")
+            .timeout(REPORT_TIMEOUT)
+            .send()
+            .await?;
 
         Ok(())
     }
 }
 
+/// appends a payload to the telemetry log as a line of JSON.
+fn append_to_log(log: &Utf8PathBuf, body: &str) -> Result<(), SputnikError> {
+    if let Some(parent) = log.parent().filter(|parent| !parent.as_str().is_empty()) {
+        Fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(log)?;
+    writeln!(file, "{body}")?;
+    Ok(())
+}
+
 /// returns sha256 digest of the directory the tool was executed from.
 fn get_cwd_hash(current_dir: &Utf8PathBuf) -> String {
     Sha256::digest(current_dir.as_str().as_bytes())
@@ -223,6 +265,7 @@ mod tests {
                 is_telemetry_enabled: true,
                 endpoint: Url::parse(format!("http://0.0.0.0/{}", report_path()).as_str()).unwrap(),
                 user_agent: user_agent().into(),
+                log: None,
             },
             client: Client::new(),
         }
@@ -301,4 +344,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_report_appends_to_log(mut session: Session) -> Result<(), anyhow::Error> {
+        let fixture = assert_fs::TempDir::new()?;
+        let log = Utf8PathBuf::try_from(fixture.path().join("telemetry").join("payloads.log"))?;
+        session.reporting_info.log = Some(log.clone());
+        // nothing listens here, so only the log should see the payload
+        session.reporting_info.endpoint = Url::parse("http://127.0.0.1:9/report")?;
+
+        let _ = session.report().await;
+        let _ = session.report().await;
+
+        let lines = Fs::read_file(&log)?;
+        let lines: Vec<serde_json::Value> = lines
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(lines, vec![session.payload()?, session.payload()?]);
+
+        session.reporting_info.is_telemetry_enabled = false;
+        session.report().await?;
+        assert_eq!(Fs::read_file(&log)?.lines().count(), 2);
+
+        Ok(())
+    }
 }
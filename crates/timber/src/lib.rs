@@ -1,13 +1,29 @@
 #![deny(missing_docs)]
 
 //! Defines the output format of traces, events, and spans produced
-//! by `env_logger`, `log`, and/or `tracing`.
+//! by `env_logger`, `log`, and/or `tracing`, and optionally exports spans to an
+//! OpenTelemetry collector.
 
-use std::io;
+pub mod otlp;
+
+use std::{io, sync::OnceLock};
 
 use clap::ValueEnum;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+pub use otlp::{OtlpConfig, OtlpError};
 pub use tracing_core::Level;
-use tracing_subscriber::fmt;
+use tracing_core::Metadata;
+use tracing_subscriber::{
+    filter::{filter_fn, FilterFn, LevelFilter},
+    fmt,
+    layer::SubscriberExt,
+    util::SubscriberInitExt,
+    Layer, Registry,
+};
+
+/// The provider exporting spans over OTLP, kept so [`shutdown`] can flush it
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
 
 #[derive(Clone, ValueEnum)]
 /// Enum to describe the log levels that can be utilised by Rover, and by extension the Router
@@ -35,17 +51,114 @@ impl std::fmt::Display for RoverLogLevel {
     }
 }
 
+/// How logs are written to stderr
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Multi-line, human-readable logs without timestamps
+    #[default]
+    Pretty,
+    /// One line per log, with a timestamp and the fields of the spans it was emitted in
+    Compact,
+    /// One JSON object per line, with a timestamp and the fields of the spans it was emitted in
+    Json,
+}
+
+impl std::fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self.to_possible_value() {
+            Some(possible_value) => possible_value.get_name().to_string(),
+            None => "unknown".to_string(),
+        };
+        write!(f, "{msg}")
+    }
+}
+
+impl LogFormat {
+    fn layer(self) -> Box<dyn Layer<Registry> + Send + Sync> {
+        match self {
+            LogFormat::Pretty => fmt::layer()
+                .event_format(fmt::format().without_time().pretty())
+                .with_writer(io::stderr)
+                .boxed(),
+            LogFormat::Compact => fmt::layer().compact().with_writer(io::stderr).boxed(),
+            LogFormat::Json => fmt::layer()
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .with_writer(io::stderr)
+                .boxed(),
+        }
+    }
+}
+
+/// The target of events forwarded from child processes, like the router `rover dev` runs. These
+/// are written whatever the log level, since the child's own log filter has already applied.
+pub const FORWARDED_TARGET: &str = "forwarded";
+
+static LOG_FORMAT: OnceLock<LogFormat> = OnceLock::new();
+
+/// The format logs are written in, as set by [`init`]
+pub fn log_format() -> LogFormat {
+    LOG_FORMAT.get().copied().unwrap_or_default()
+}
+
 /// Initializes a global tracing subscriber that formats
 /// all logs produced by an application that calls init,
 /// and all logs produced by libraries consumed by that application.
-pub fn init(level: Option<Level>) {
-    // by default, no logs are printed.
-    if let Some(level) = level {
-        let format = fmt::format().without_time().pretty();
-        fmt()
-            .with_max_level(level)
-            .event_format(format)
-            .with_writer(io::stderr)
+///
+/// Spans are also exported over OTLP when the `OTEL_*` environment variables ask for it. If that
+/// configuration is invalid, logging is still set up and the error is returned.
+pub fn init(level: Option<Level>, format: LogFormat) -> Result<(), OtlpError> {
+    let _ = LOG_FORMAT.set(format);
+    let (provider, otlp_error) = match OtlpConfig::from_env()
+        .and_then(|config| config.map(|config| config.tracer_provider()).transpose())
+    {
+        Ok(provider) => (provider, None),
+        Err(err) => (None, Some(err)),
+    };
+
+    // by default, no logs are printed. Forwarded logs are only ever emitted as events in the
+    // structured formats, so those still need a formatter.
+    let fmt_layer = match (level, format) {
+        (None, LogFormat::Pretty) => None,
+        (level, format) => Some(format.layer().with_filter(fmt_filter(level))),
+    };
+    // only Rover's own spans are exported, not the debug spans of every library it uses
+    let otlp_layer = provider.map(|provider| {
+        let layer = tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer("rover"))
+            .with_filter(LevelFilter::INFO);
+        let _ = TRACER_PROVIDER.set(provider);
+        layer
+    });
+    if fmt_layer.is_some() || otlp_layer.is_some() {
+        tracing_subscriber::registry()
+            .with(fmt_layer)
+            .with(otlp_layer)
             .init();
     }
+
+    otlp_error.map_or(Ok(()), Err)
+}
+
+/// Shows events at `level` and above, and forwarded events at any level. Spans are kept at `info`
+/// even when logging less, so every event carries the command it was emitted by.
+fn fmt_filter(level: Option<Level>) -> FilterFn<impl Fn(&Metadata<'_>) -> bool> {
+    let events = level.map_or(LevelFilter::OFF, LevelFilter::from_level);
+    let spans = events.max(LevelFilter::INFO);
+    filter_fn(move |metadata| {
+        if metadata.is_span() {
+            *metadata.level() <= spans
+        } else {
+            metadata.target() == FORWARDED_TARGET || *metadata.level() <= events
+        }
+    })
+}
+
+/// Exports any spans that haven't been sent to the OTLP collector yet. This has to be called
+/// before the process exits, or the last batch of spans is lost.
+pub fn shutdown() {
+    if let Some(provider) = TRACER_PROVIDER.get() {
+        let _ = provider.shutdown();
+    }
 }
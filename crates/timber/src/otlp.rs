@@ -0,0 +1,213 @@
+//! Exports spans to an OpenTelemetry collector over OTLP, configured with the standard `OTEL_*`
+//! environment variables.
+
+use std::env;
+
+use opentelemetry_otlp::{ExporterBuildError, Protocol, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+
+const OTEL_SDK_DISABLED: &str = "OTEL_SDK_DISABLED";
+const OTEL_SERVICE_NAME: &str = "OTEL_SERVICE_NAME";
+const OTEL_TRACES_EXPORTER: &str = "OTEL_TRACES_EXPORTER";
+const OTEL_EXPORTER_OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+const OTEL_EXPORTER_OTLP_TRACES_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+const OTEL_EXPORTER_OTLP_PROTOCOL: &str = "OTEL_EXPORTER_OTLP_PROTOCOL";
+const OTEL_EXPORTER_OTLP_TRACES_PROTOCOL: &str = "OTEL_EXPORTER_OTLP_TRACES_PROTOCOL";
+
+/// The service name spans are reported under, unless `OTEL_SERVICE_NAME` says otherwise
+const DEFAULT_SERVICE_NAME: &str = "rover";
+
+/// Errors setting up the OTLP exporter
+#[derive(Debug, thiserror::Error)]
+pub enum OtlpError {
+    /// `OTEL_EXPORTER_OTLP_PROTOCOL` isn't one of the protocols in the OTLP spec
+    #[error("unsupported OTLP protocol '{0}', expected 'grpc', 'http/protobuf', or 'http/json'")]
+    UnsupportedProtocol(String),
+    /// The exporter couldn't be built, usually because of an invalid endpoint
+    #[error("could not build the OTLP exporter: {0}")]
+    Build(#[from] ExporterBuildError),
+}
+
+/// How to export spans over OTLP
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OtlpConfig {
+    protocol: Protocol,
+    /// Overrides the endpoint the exporter would otherwise read from the environment
+    endpoint: Option<String>,
+    service_name: Option<String>,
+}
+
+impl OtlpConfig {
+    /// Reads the configuration from the environment. Returns `None` unless an OTLP endpoint is
+    /// set or `OTEL_TRACES_EXPORTER` includes `otlp`, or if `OTEL_SDK_DISABLED` is `true`.
+    ///
+    /// The endpoint, headers, timeout, and compression are read by the exporter itself, from the
+    /// same variables it documents.
+    pub fn from_env() -> Result<Option<OtlpConfig>, OtlpError> {
+        Self::from_vars(|key| env::var(key).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Option<OtlpConfig>, OtlpError> {
+        let var = |key: &str| var(key).filter(|value| !value.trim().is_empty());
+        if var(OTEL_SDK_DISABLED).is_some_and(|disabled| disabled.trim() == "true") {
+            return Ok(None);
+        }
+        let enabled = match var(OTEL_TRACES_EXPORTER) {
+            Some(exporters) => exporters
+                .split(',')
+                .any(|exporter| exporter.trim() == "otlp"),
+            None => {
+                var(OTEL_EXPORTER_OTLP_TRACES_ENDPOINT).is_some()
+                    || var(OTEL_EXPORTER_OTLP_ENDPOINT).is_some()
+            }
+        };
+        if !enabled {
+            return Ok(None);
+        }
+        let protocol = match var(OTEL_EXPORTER_OTLP_TRACES_PROTOCOL)
+            .or_else(|| var(OTEL_EXPORTER_OTLP_PROTOCOL))
+            .as_deref()
+            .map(str::trim)
+        {
+            None | Some("http/protobuf") => Protocol::HttpBinary,
+            Some("http/json") => Protocol::HttpJson,
+            Some("grpc") => Protocol::Grpc,
+            Some(protocol) => return Err(OtlpError::UnsupportedProtocol(protocol.to_string())),
+        };
+        let service_name = match var(OTEL_SERVICE_NAME) {
+            // the resource reads `OTEL_SERVICE_NAME` itself
+            Some(_) => None,
+            None => Some(DEFAULT_SERVICE_NAME.to_string()),
+        };
+        Ok(Some(OtlpConfig {
+            protocol,
+            endpoint: None,
+            service_name,
+        }))
+    }
+
+    /// Builds a provider that exports spans in batches on a background thread. gRPC exporters
+    /// have to be built from within a Tokio runtime.
+    pub fn tracer_provider(&self) -> Result<SdkTracerProvider, OtlpError> {
+        let exporter = match self.protocol {
+            Protocol::Grpc => self
+                .with_endpoint(SpanExporter::builder().with_tonic())
+                .build()?,
+            protocol => self
+                .with_endpoint(SpanExporter::builder().with_http().with_protocol(protocol))
+                .build()?,
+        };
+        let resource = match &self.service_name {
+            Some(service_name) => Resource::builder().with_service_name(service_name.clone()),
+            None => Resource::builder(),
+        };
+        Ok(SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource.build())
+            .build())
+    }
+
+    fn with_endpoint<B: WithExportConfig>(&self, builder: B) -> B {
+        match &self.endpoint {
+            Some(endpoint) => builder.with_endpoint(endpoint.clone()),
+            None => builder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use httpmock::prelude::*;
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_otlp::Protocol;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{OtlpConfig, OtlpError};
+
+    fn from_vars(vars: &[(&str, &str)]) -> Result<Option<OtlpConfig>, OtlpError> {
+        let vars: HashMap<_, _> = vars.iter().copied().collect();
+        OtlpConfig::from_vars(|key| vars.get(key).map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn it_is_disabled_without_an_endpoint() {
+        assert_eq!(from_vars(&[]).unwrap(), None);
+        assert_eq!(
+            from_vars(&[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+                ("OTEL_SDK_DISABLED", "true"),
+            ])
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            from_vars(&[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+                ("OTEL_TRACES_EXPORTER", "none"),
+            ])
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn it_reads_the_protocol() {
+        let config = from_vars(&[(
+            "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+            "http://localhost:4318",
+        )])
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.protocol, Protocol::HttpBinary);
+        assert_eq!(config.service_name.as_deref(), Some("rover"));
+
+        let config = from_vars(&[
+            ("OTEL_TRACES_EXPORTER", "otlp"),
+            ("OTEL_EXPORTER_OTLP_PROTOCOL", "http/json"),
+            ("OTEL_EXPORTER_OTLP_TRACES_PROTOCOL", "grpc"),
+            ("OTEL_SERVICE_NAME", "ci"),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.protocol, Protocol::Grpc);
+        assert_eq!(config.service_name, None);
+
+        assert!(matches!(
+            from_vars(&[
+                ("OTEL_TRACES_EXPORTER", "otlp"),
+                ("OTEL_EXPORTER_OTLP_PROTOCOL", "carrier-pigeon"),
+            ]),
+            Err(OtlpError::UnsupportedProtocol(_))
+        ));
+    }
+
+    #[test]
+    fn it_exports_spans_to_a_collector() {
+        let collector = MockServer::start();
+        let traces = collector.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/traces")
+                .header("content-type", "application/json")
+                .body_includes("subgraph check")
+                .body_includes("rover");
+            then.status(200).body("{}");
+        });
+        let config = OtlpConfig {
+            protocol: Protocol::HttpJson,
+            endpoint: Some(collector.url("/v1/traces")),
+            service_name: Some("rover".to_string()),
+        };
+        let provider = config.tracer_provider().unwrap();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("rover")));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("command", command = "subgraph check").in_scope(|| {});
+        });
+        provider.shutdown().unwrap();
+
+        traces.assert();
+    }
+}
@@ -5,7 +5,7 @@ use bytes::Bytes;
 use reqwest::header::{self, HeaderMap, HeaderValue};
 use rover_http::{
     extend_headers::ExtendHeadersLayer, retry::RetryPolicy, timeout::TimeoutLayer, Full,
-    HttpRequest, HttpResponse, HttpServiceError,
+    HttpRequest, HttpResponse, HttpServiceError, RetryConfig,
 };
 use tower::{retry::RetryLayer, util::BoxService, Service, ServiceBuilder};
 use tower_http::decompression::{DecompressionBody, DecompressionLayer};
@@ -38,10 +38,11 @@ impl FileDownloadService {
             .boxed()
             .layer(DecompressionLayer::default()) // explicit stand-in for reqwest's brotli/gzip decompression options
             .layer(file_download_layer())
-            .layer(RetryLayer::new(RetryPolicy::new(
-                max_elapsed_duration
+            .layer(RetryLayer::new(RetryPolicy::new(RetryConfig {
+                max_elapsed: max_elapsed_duration
                     .unwrap_or_else(|| Duration::from_secs(DEFAULT_ELAPSED_DURATION_SECONDS)),
-            )))
+                ..RetryConfig::default()
+            })))
             .layer(TimeoutLayer::new(timeout_duration.unwrap_or_else(|| {
                 Duration::from_secs(DEFAULT_TIMEOUT_DURATION_SECONDS)
             })))
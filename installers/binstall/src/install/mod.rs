@@ -7,7 +7,7 @@ use bytes::Bytes;
 use camino::Utf8PathBuf;
 use download::{gz_decode::GzDecodeLayer, FileDownloadService};
 use http::Request;
-use rover_http::Full;
+use rover_http::{Full, ProxyConfig, TlsConfig};
 use rover_std::Fs;
 use tower::{Service, ServiceBuilder, ServiceExt};
 use url::Url;
@@ -25,6 +25,10 @@ pub struct Installer {
     pub executable_location: Utf8PathBuf,
     /// Install the binary into a non-default location
     pub override_install_path: Option<Utf8PathBuf>,
+    /// Extra certificates for the requests made outside of a `FileDownloadService`
+    pub tls: TlsConfig,
+    /// The proxy for the requests made outside of a `FileDownloadService`
+    pub proxy: ProxyConfig,
 }
 
 impl Installer {
@@ -97,9 +101,12 @@ impl Installer {
         is_latest: bool,
     ) -> Result<String, InstallerError> {
         if is_latest {
-            let no_redirect_client = reqwest::Client::builder()
-                .redirect(reqwest::redirect::Policy::none())
-                .build()?;
+            let no_redirect_client =
+                self.tls
+                    .apply(self.proxy.apply(
+                        reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()),
+                    ))?
+                    .build()?;
             let response = no_redirect_client
                 .head(plugin_tarball_url)
                 .send()
@@ -314,7 +321,7 @@ mod test {
     use http::{HeaderValue, Response, Uri};
     use httpmock::prelude::*;
     use reqwest::header::{ACCEPT, USER_AGENT};
-    use rover_http::{test::MockHttpService, Full, HttpServiceError};
+    use rover_http::{test::MockHttpService, Full, HttpServiceError, ProxyConfig, TlsConfig};
     use rover_tower::{expect_poll_ready, test::MockCloneService};
     use rstest::{fixture, rstest};
     use sealed_test::prelude::*;
@@ -358,6 +365,8 @@ mod test {
             force_install: true,
             executable_location,
             override_install_path: None,
+            tls: TlsConfig::default(),
+            proxy: ProxyConfig::default(),
         }
     }
 
@@ -71,6 +71,8 @@ mod tests {
             force_install: false,
             override_install_path: Some(base_dir.clone()),
             executable_location: Utf8PathBuf::try_from(std::env::current_exe().unwrap()).unwrap(),
+            tls: rover_http::TlsConfig::default(),
+            proxy: rover_http::ProxyConfig::default(),
         }
         .install()
         .unwrap()
@@ -4,7 +4,7 @@ use binstall::{download::FileDownloadService, Installer};
 use camino::Utf8PathBuf;
 use httpmock::prelude::*;
 use reqwest::header::{ACCEPT, USER_AGENT};
-use rover_http::ReqwestService;
+use rover_http::{ProxyConfig, ReqwestService, TlsConfig};
 use speculoos::prelude::*;
 
 #[test]
@@ -26,6 +26,8 @@ pub fn test_install() {
         force_install: true, // necessary to bypass TTY prompt
         executable_location: executable_location_utf,
         override_install_path: Some(install_dir.clone()),
+        tls: TlsConfig::default(),
+        proxy: ProxyConfig::default(),
     };
     installer.install().unwrap();
     let expected_install_path = install_dir.join(".test").join("bin").join("test");
@@ -88,6 +90,8 @@ pub async fn test_install_plugin() {
         force_install: true,
         executable_location,
         override_install_path: Some(override_path),
+        tls: TlsConfig::default(),
+        proxy: ProxyConfig::default(),
     };
 
     let http_service = ReqwestService::builder()
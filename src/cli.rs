@@ -2,7 +2,7 @@ use std::{fmt::Display, io, process};
 
 use camino::Utf8PathBuf;
 use clap::{
-    Parser, ValueEnum,
+    CommandFactory, FromArgMatches, Parser, ValueEnum,
     builder::{
         Styles,
         styling::{AnsiColor, Effects},
@@ -12,19 +12,26 @@ use config::Config;
 use houston as config;
 use lazycell::{AtomicLazyCell, LazyCell};
 use reqwest::Client;
-use rover_client::shared::GitContext;
-use rover_std::Style;
+use rover_client::shared::{CheckWorkflowResponse, FindingsReport, GitContext, LintResponse};
+use rover_http::{
+    ProxyConfig,
+    har::{self, HarRecorder},
+    proxy::parse_proxy_url,
+};
+use rover_std::{Fs, Style};
 use serde::Serialize;
-use sputnik::Session;
-use timber::Level;
+use sputnik::{Report, Session};
+use timber::{Level, LogFormat};
+use url::Url;
 
 use crate::{
-    RoverResult,
+    PKG_NAME, PKG_VERSION, RoverResult,
     command::{self, RoverOutput},
-    options::OutputOpts,
+    options::{OutputOpts, RetryOpts},
     utils::{
-        client::{ClientBuilder, ClientTimeout, StudioClientConfig},
+        client::{ClientBuilder, ClientTimeout, StudioClientConfig, load_tls_config},
         env::{RoverEnv, RoverEnvKey},
+        project_config::ProjectConfig,
         stringify::option_from_display,
         version,
     },
@@ -74,9 +81,19 @@ pub struct Rover {
     #[serde(serialize_with = "option_from_display")]
     log_level: Option<Level>,
 
+    /// Specify the format of Rover's logs, and of the router and MCP server logs `rover dev`
+    /// forwards
+    #[arg(long = "log-format", value_enum, default_value_t = LogFormat::Pretty, global = true)]
+    #[serde(skip_serializing)]
+    log_format: LogFormat,
+
     #[clap(flatten)]
     output_opts: OutputOpts,
 
+    #[clap(flatten)]
+    #[serde(skip_serializing)]
+    retry_opts: RetryOpts,
+
     /// Accept invalid certificates when performing HTTPS requests.
     ///
     /// You should think very carefully before using this flag.
@@ -96,13 +113,64 @@ pub struct Rover {
     #[arg(long = "insecure-accept-invalid-hostnames", global = true)]
     accept_invalid_hostnames: bool,
 
-    /// Configure the timeout length (in seconds) when performing HTTP(S) requests.
+    /// Path to a PEM file of certificate authorities to trust when performing HTTPS requests,
+    /// on top of the system's.
+    ///
+    /// Use this when requests go through a TLS-intercepting proxy, or to a registry with a
+    /// certificate signed by an internal CA.
+    #[arg(
+        long = "ca-cert",
+        value_name = "PEM",
+        env = "APOLLO_CA_CERT",
+        global = true
+    )]
+    #[serde(skip_serializing)]
+    ca_cert: Option<Utf8PathBuf>,
+
+    /// Path to a PEM client certificate to present when performing HTTPS requests, for mutual TLS.
+    ///
+    /// The private key can be in the same file, or passed with `--client-key`.
     #[arg(
-        long = "client-timeout",
-        global = true,
-        default_value_t = ClientTimeout::default()
+        long = "client-cert",
+        value_name = "PEM",
+        env = "APOLLO_CLIENT_CERT",
+        global = true
     )]
-    client_timeout: ClientTimeout,
+    #[serde(skip_serializing)]
+    client_cert: Option<Utf8PathBuf>,
+
+    /// Path to the PEM private key for `--client-cert`.
+    #[arg(
+        long = "client-key",
+        value_name = "PEM",
+        env = "APOLLO_CLIENT_KEY",
+        requires = "client_cert",
+        global = true
+    )]
+    #[serde(skip_serializing)]
+    client_key: Option<Utf8PathBuf>,
+
+    /// Proxy to send HTTP(S) requests through, e.g. `http://proxy.example.com:8080`.
+    ///
+    /// Takes precedence over `HTTPS_PROXY`, `HTTP_PROXY`, and `ALL_PROXY`. Hosts matched by
+    /// `NO_PROXY` are still contacted directly.
+    #[arg(long = "proxy", value_name = "URL", value_parser = parse_proxy_url, global = true)]
+    #[serde(skip_serializing)]
+    proxy: Option<Url>,
+
+    /// Record every HTTP(S) request and response to a HAR file when the command finishes.
+    ///
+    /// Credentials like `x-api-key` are redacted, but request and response bodies are recorded
+    /// as is.
+    #[arg(long = "http-trace", value_name = "FILE", global = true)]
+    #[serde(skip_serializing)]
+    http_trace: Option<Utf8PathBuf>,
+
+    /// Configure the timeout length (in seconds) when performing HTTP(S) requests.
+    ///
+    /// Defaults to the profile's `client_timeout` setting, or 30 seconds.
+    #[arg(long = "client-timeout", global = true)]
+    client_timeout: Option<ClientTimeout>,
 
     /// Skip checking for newer versions of rover.
     #[arg(long = "skip-update-check", global = true)]
@@ -123,13 +191,34 @@ pub struct Rover {
 
 impl Rover {
     pub async fn run_from_args() -> RoverResult<()> {
-        Rover::parse().run().await
+        // a `.rover.toml` provides defaults for common options, so it has to be found before the
+        // command line is parsed
+        ProjectConfig::init();
+        let mut matches =
+            match ProjectConfig::get_matches(Rover::command(), std::env::args_os().collect()) {
+                Ok(matches) => matches,
+                Err(error) => {
+                    error.print()?;
+                    process::exit(1);
+                }
+            };
+        let rover = Rover::from_arg_matches_mut(&mut matches)
+            .unwrap_or_else(|e| e.format(&mut Rover::command()).exit());
+        rover.run().await
     }
 
     pub async fn run(&self) -> RoverResult<()> {
-        timber::init(self.log_level);
+        if let Err(err) = timber::init(self.log_level, self.log_format) {
+            eprintln!(
+                "{} Spans won't be exported over OTLP: {err}",
+                Style::WarningPrefix.paint("WARN:")
+            );
+        }
         tracing::trace!(command_structure = ?self);
         self.output_opts.set_no_color();
+        if self.http_trace.is_some() {
+            har::install(HarRecorder::new(PKG_NAME, PKG_VERSION));
+        }
 
         // attempt to create a new `Session` to capture anonymous usage data
         let rover_output = match Session::new(self) {
@@ -163,6 +252,9 @@ impl Rover {
             Err(_) => self.execute_command().await,
         };
 
+        self.write_http_trace();
+        timber::shutdown();
+
         match rover_output {
             Ok(output) => {
                 self.output_opts.handle_output(output)?;
@@ -177,6 +269,40 @@ impl Rover {
         }
     }
 
+    /// The command being run, like `subgraph check`, to name its span after
+    fn command_name(&self) -> String {
+        self.serialize_command()
+            .map(|command| command.name)
+            .unwrap_or_default()
+    }
+
+    /// Writes the requests recorded for `--http-trace`. Failing to write them only warns, so the
+    /// command's own result is still reported.
+    fn write_http_trace(&self) {
+        if let (Some(path), Some(recorder)) = (&self.http_trace, har::global()) {
+            let written = serde_json::to_string_pretty(&recorder.to_har())
+                .map_err(anyhow::Error::from)
+                .and_then(|har| Fs::write_file(path, har).map_err(anyhow::Error::from));
+            match written {
+                Ok(()) => eprintln!(
+                    "{} {}",
+                    Style::Heading.paint("HTTP trace was written to"),
+                    Style::Path.paint(path)
+                ),
+                Err(err) => eprintln!(
+                    "{} Could not write the HTTP trace to {}: {err}",
+                    Style::WarningPrefix.paint("WARN:"),
+                    Style::Path.paint(path)
+                ),
+            }
+        }
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        name = "command",
+        fields(command = %self.command_name())
+    )]
     pub async fn execute_command(&self) -> RoverResult<RoverOutput> {
         // before running any commands, we check if rover is up to date
         // this only happens once a day automatically
@@ -233,7 +359,7 @@ impl Rover {
                     )
                     .await
             }
-            Command::Template(command) => command.run().await,
+            Command::Template(command) => command.run(self.get_reqwest_client()?).await,
             Command::Readme(command) => command.run(self.get_client_config()?).await,
             Command::Subgraph(command) => {
                 command
@@ -255,9 +381,10 @@ impl Rover {
                     .do_install(self.get_install_override_path()?, self.get_client_config()?)
                     .await
             }
-            Command::Info(command) => command.run(),
+            Command::Info(command) => command.run(&self.get_reqwest_client_builder()?),
             Command::Explain(command) => command.run(),
             Command::PersistedQueries(command) => command.run(self.get_client_config()?).await,
+            Command::Proposal(command) => command.run(self.get_client_config()?).await,
             Command::License(command) => command.run(self.get_client_config()?).await,
             #[cfg(feature = "composition-js")]
             Command::Lsp(command) => command.run(self.get_client_config()?).await,
@@ -270,7 +397,9 @@ impl Rover {
             .get_env_var(RoverEnvKey::ConfigHome)?
             .map(|p| Utf8PathBuf::from(&p));
         let override_api_key = self.get_env_var(RoverEnvKey::Key)?;
-        Ok(Config::new(override_home.as_ref(), override_api_key)?)
+        let credentials_passphrase = self.get_env_var(RoverEnvKey::CredentialsPassphrase)?;
+        Ok(Config::new(override_home.as_ref(), override_api_key)?
+            .with_credential_passphrase(credentials_passphrase))
     }
 
     pub(crate) fn get_client_config(&self) -> RoverResult<StudioClientConfig> {
@@ -286,8 +415,9 @@ impl Rover {
             override_endpoint,
             config,
             is_sudo,
-            self.get_reqwest_client_builder(),
+            self.get_reqwest_client_builder()?,
             self.client_timeout,
+            self.retry_opts.clone(),
         ))
     }
 
@@ -317,24 +447,31 @@ impl Rover {
         if let Some(client) = self.client.borrow() {
             Ok(client.clone())
         } else {
-            let client = self.get_reqwest_client_builder().build()?;
+            let client = self.get_reqwest_client_builder()?.build()?;
             let _ = self.client.fill(client);
             self.get_reqwest_client()
         }
     }
 
-    pub(crate) fn get_reqwest_client_builder(&self) -> ClientBuilder {
+    pub(crate) fn get_reqwest_client_builder(&self) -> anyhow::Result<ClientBuilder> {
         // return a copy of the underlying client builder if it's already been populated
         if let Some(client_builder) = self.client_builder.borrow() {
-            *client_builder
+            Ok(client_builder.clone())
         } else {
             // if a request hasn't been made yet, this cell won't be populated yet
+            let tls = load_tls_config(
+                self.ca_cert.as_deref(),
+                self.client_cert.as_deref(),
+                self.client_key.as_deref(),
+            )?;
             self.client_builder
                 .fill(
                     ClientBuilder::new()
                         .accept_invalid_certs(self.accept_invalid_certs)
                         .accept_invalid_hostnames(self.accept_invalid_hostnames)
-                        .with_timeout(self.client_timeout.get_duration()),
+                        .with_timeout(self.client_timeout.unwrap_or_default().get_duration())
+                        .tls(tls)
+                        .proxy(ProxyConfig::from_env(self.proxy.clone())),
                 )
                 .ok();
             self.get_reqwest_client_builder()
@@ -439,6 +576,9 @@ pub enum Command {
     #[command(visible_alias = "pq")]
     PersistedQueries(command::PersistedQueries),
 
+    /// Schema proposal commands
+    Proposal(command::Proposal),
+
     /// Installs Rover
     Install(command::Install),
 
@@ -463,6 +603,44 @@ pub enum RoverOutputFormatKind {
     #[default]
     Plain,
     Json,
+    /// SARIF, for GitHub code scanning. Only for check and lint results.
+    Sarif,
+    /// JUnit XML test reports. Only for check and lint results.
+    Junit,
+    /// GitHub Actions annotations. Only for check and lint results.
+    Github,
+    /// Markdown, for pull request comments. Only for check results.
+    Markdown,
+}
+
+impl RoverOutputFormatKind {
+    /// Renders a check result in one of the report formats, or `None` for the formats every
+    /// command supports
+    pub(crate) fn render_check(&self, check_response: &CheckWorkflowResponse) -> Option<String> {
+        match self {
+            RoverOutputFormatKind::Markdown => Some(check_response.get_markdown()),
+            _ => self.render_findings(&check_response.get_findings()),
+        }
+    }
+
+    /// Renders a lint result in one of the report formats, or `None` for the formats that don't
+    /// apply to lint
+    pub(crate) fn render_lint(&self, lint_response: &LintResponse) -> Option<String> {
+        self.render_findings(&lint_response.get_findings())
+    }
+
+    fn render_findings(&self, findings: &FindingsReport) -> Option<String> {
+        match self {
+            RoverOutputFormatKind::Plain
+            | RoverOutputFormatKind::Json
+            | RoverOutputFormatKind::Markdown => None,
+            RoverOutputFormatKind::Sarif => {
+                serde_json::to_string_pretty(&findings.get_sarif(PKG_VERSION)).ok()
+            }
+            RoverOutputFormatKind::Junit => Some(findings.get_junit()),
+            RoverOutputFormatKind::Github => Some(findings.get_github_annotations()),
+        }
+    }
 }
 
 impl Display for RoverOutputFormatKind {
@@ -470,6 +648,10 @@ impl Display for RoverOutputFormatKind {
         match self {
             RoverOutputFormatKind::Plain => write!(f, "plain"),
             RoverOutputFormatKind::Json => write!(f, "json"),
+            RoverOutputFormatKind::Sarif => write!(f, "sarif"),
+            RoverOutputFormatKind::Junit => write!(f, "junit"),
+            RoverOutputFormatKind::Github => write!(f, "github"),
+            RoverOutputFormatKind::Markdown => write!(f, "markdown"),
         }
     }
 }
@@ -7,6 +7,7 @@ use rover_client::{
         update, validate,
     },
 };
+use rover_studio::types::GraphRef;
 use serde::Serialize;
 
 use crate::{
@@ -60,15 +61,18 @@ impl Config {
         match &self.command {
             Command::Fetch(args) => {
                 let client = client_config.get_authenticated_client(&args.profile)?;
-                self.fetch(client, &args.graph).await
+                let graph_ref = args.graph.resolve(&args.profile, &client_config.config)?;
+                self.fetch(client, graph_ref).await
             }
             Command::Update(args) => {
                 let client = client_config.get_authenticated_client(&args.profile)?;
-                self.update(client, &args.graph, &args.file).await
+                let graph_ref = args.graph.resolve(&args.profile, &client_config.config)?;
+                self.update(client, graph_ref, &args.file).await
             }
             Command::Validate(args) => {
                 let client = client_config.get_authenticated_client(&args.profile)?;
-                self.validate(client, &args.graph, &args.file).await
+                let graph_ref = args.graph.resolve(&args.profile, &client_config.config)?;
+                self.validate(client, graph_ref, &args.file).await
             }
         }
     }
@@ -76,17 +80,11 @@ impl Config {
     pub async fn fetch(
         &self,
         client: StudioClient,
-        graph: &GraphRefOpt,
+        graph_ref: GraphRef,
     ) -> RoverResult<RoverOutput> {
-        eprintln!("Fetching cloud router config for: {}", graph.graph_ref);
+        eprintln!("Fetching cloud router config for: {graph_ref}");
 
-        let cloud_config = fetch::run(
-            CloudConfigFetchInput {
-                graph_ref: graph.graph_ref.clone(),
-            },
-            &client,
-        )
-        .await?;
+        let cloud_config = fetch::run(CloudConfigFetchInput { graph_ref }, &client).await?;
 
         Ok(RoverOutput::CloudConfigFetchResponse {
             config: cloud_config.config,
@@ -96,21 +94,14 @@ impl Config {
     pub async fn update(
         &self,
         client: StudioClient,
-        graph: &GraphRefOpt,
+        graph_ref: GraphRef,
         file: &FileOpt,
     ) -> RoverResult<RoverOutput> {
-        eprintln!("Updating cloud router config for: {}", graph.graph_ref);
+        eprintln!("Updating cloud router config for: {graph_ref}");
 
         let config = file.read_file_descriptor("Cloud Router config", &mut std::io::stdin())?;
 
-        let res = update::run(
-            CloudConfigInput {
-                graph_ref: graph.graph_ref.clone(),
-                config,
-            },
-            &client,
-        )
-        .await?;
+        let res = update::run(CloudConfigInput { graph_ref, config }, &client).await?;
 
         Ok(RoverOutput::MessageResponse { msg: res.msg })
     }
@@ -118,21 +109,14 @@ impl Config {
     pub async fn validate(
         &self,
         client: StudioClient,
-        graph: &GraphRefOpt,
+        graph_ref: GraphRef,
         file: &FileOpt,
     ) -> RoverResult<RoverOutput> {
-        eprintln!("Validating cloud router config for: {}", graph.graph_ref);
+        eprintln!("Validating cloud router config for: {graph_ref}");
 
         let config = file.read_file_descriptor("Cloud Router config", &mut std::io::stdin())?;
 
-        let res = validate::run(
-            CloudConfigInput {
-                graph_ref: graph.graph_ref.clone(),
-                config,
-            },
-            &client,
-        )
-        .await?;
+        let res = validate::run(CloudConfigInput { graph_ref, config }, &client).await?;
 
         Ok(RoverOutput::MessageResponse { msg: res.msg })
     }
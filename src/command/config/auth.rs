@@ -1,11 +1,14 @@
 use anyhow::anyhow;
-use clap::Parser;
-use config::Profile;
+use clap::{Parser, ValueEnum};
+use config::{CredentialStore, Profile};
 use houston as config;
 use rover_std::Style;
 use serde::Serialize;
 
-use crate::{RoverError, RoverErrorSuggestion, RoverOutput, RoverResult, options::ProfileOpt};
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverOutput, RoverResult, options::ProfileOpt,
+    utils::env::RoverEnvKey,
+};
 
 #[derive(Debug, Serialize, Parser)]
 /// Authenticate a configuration profile with an API key
@@ -21,10 +24,35 @@ use crate::{RoverError, RoverErrorSuggestion, RoverOutput, RoverResult, options:
 pub struct Auth {
     #[clap(flatten)]
     profile: ProfileOpt,
+
+    /// Where to keep API keys from now on. Defaults to the store chosen the last time
+    /// this command was run, or `file` if it never has been.
+    #[arg(long, value_enum)]
+    #[serde(skip_serializing)]
+    store: Option<StoreKind>,
+
+    /// The credential helper to use with `--store helper`. A bare name like `foo` runs
+    /// `rover-credential-foo`.
+    #[arg(long, required_if_eq("store", "helper"))]
+    #[serde(skip_serializing)]
+    helper: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum StoreKind {
+    /// Plaintext in the profile directory
+    File,
+    /// The desktop keyring, through libsecret's `secret-tool`, which has to be installed
+    SecretTool,
+    /// The profile directory, encrypted with a passphrase
+    EncryptedFile,
+    /// An external credential helper program
+    Helper,
 }
 
 impl Auth {
     pub fn run(&self, config: config::Config) -> RoverResult<RoverOutput> {
+        let config = self.configure_store(config)?;
         let api_key = api_key_prompt()?;
         Profile::set_api_key(&self.profile.profile_name, &config, &api_key)?;
         Profile::get_credential(&self.profile.profile_name, &config).map(|_| {
@@ -32,6 +60,50 @@ impl Auth {
         })?;
         Ok(RoverOutput::EmptySuccess)
     }
+
+    /// Checks and saves the store chosen with `--store`, and asks for a passphrase if the store
+    /// needs one and `$APOLLO_CREDENTIALS_PASSPHRASE` isn't set.
+    fn configure_store(&self, config: config::Config) -> RoverResult<config::Config> {
+        let store = match self.store {
+            Some(kind) => {
+                let store = match kind {
+                    StoreKind::File => CredentialStore::File,
+                    StoreKind::SecretTool => CredentialStore::SecretTool { program: None },
+                    StoreKind::EncryptedFile => CredentialStore::EncryptedFile,
+                    StoreKind::Helper => CredentialStore::Helper {
+                        helper: self.helper.clone().unwrap_or_default(),
+                    },
+                };
+                store.check(&self.profile.profile_name)?;
+                store.save(&config)?;
+                eprintln!("Storing API keys in: {store}");
+                store
+            }
+            None => CredentialStore::load(&config)?,
+        };
+
+        if store == CredentialStore::EncryptedFile && config.credential_passphrase.is_none() {
+            let passphrase = passphrase_prompt()?;
+            Ok(config.with_credential_passphrase(Some(passphrase)))
+        } else {
+            Ok(config)
+        }
+    }
+}
+
+fn passphrase_prompt() -> RoverResult<String> {
+    let term = console::Term::stderr();
+    eprintln!(
+        "Enter the passphrase used to encrypt your API keys. Set ${} to use it with other commands.",
+        RoverEnvKey::CredentialsPassphrase
+    );
+    term.write_str("> ")?;
+    let passphrase = term.read_secure_line()?;
+    if passphrase.is_empty() {
+        Err(anyhow!("Received an empty passphrase. Please try again.").into())
+    } else {
+        Ok(passphrase)
+    }
 }
 
 fn api_key_prompt() -> RoverResult<String> {
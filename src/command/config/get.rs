@@ -0,0 +1,33 @@
+use clap::Parser;
+use houston::{Profile, ProfileSettings};
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult, options::ProfileOpt};
+
+#[derive(Debug, Serialize, Parser)]
+/// Show the defaults set for a configuration profile
+///
+/// Pass a key to show a single setting, or leave it off to show every
+/// setting that has a value.
+pub struct Get {
+    /// The setting to show
+    #[arg(value_parser = ProfileSettings::KEYS.to_vec())]
+    #[serde(skip_serializing)]
+    key: Option<String>,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Get {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let settings = Profile::get_settings(&self.profile.profile_name, &config)?;
+        match &self.key {
+            Some(key) => Ok(RoverOutput::ProfileSetting {
+                key: key.clone(),
+                value: settings.get(key)?,
+            }),
+            None => Ok(RoverOutput::ProfileSettings(settings.entries())),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 mod auth;
 mod clear;
 mod delete;
+mod get;
 mod list;
+mod set;
+mod show_effective;
+mod telemetry;
 mod whoami;
 
 use clap::Parser;
@@ -26,9 +30,21 @@ pub enum Command {
     /// Delete a configuration profile
     Delete(delete::Delete),
 
+    /// Show the defaults set for a configuration profile
+    Get(get::Get),
+
     /// List all configuration profiles
     List(list::List),
 
+    /// Set a default for a configuration profile
+    Set(set::Set),
+
+    /// Show the resolved value of common options and where each one came from
+    ShowEffective(show_effective::ShowEffective),
+
+    /// Inspect and control the anonymous usage data Rover reports
+    Telemetry(telemetry::Telemetry),
+
     /// View the identity of a user/api key
     Whoami(whoami::WhoAmI),
 }
@@ -40,6 +56,10 @@ impl Config {
             Command::List(command) => command.run(client_config.config),
             Command::Delete(command) => command.run(client_config.config),
             Command::Clear(command) => command.run(client_config.config),
+            Command::Get(command) => command.run(client_config.config),
+            Command::Set(command) => command.run(client_config.config),
+            Command::ShowEffective(command) => command.run(client_config.config),
+            Command::Telemetry(command) => command.run(client_config.config),
             Command::Whoami(command) => command.run(client_config).await,
         }
     }
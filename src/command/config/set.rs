@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use anyhow::{Context, anyhow};
+use apollo_federation_types::config::FederationVersion;
+use clap::Parser;
+use houston::{HoustonProblem, Profile, ProfileData, ProfileSettings};
+use rover_http::{RetryOn, proxy::parse_proxy_url};
+use rover_std::Style;
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+use url::Url;
+
+use crate::{RoverOutput, RoverResult, options::ProfileOpt};
+
+#[derive(Debug, Serialize, Parser)]
+/// Set a default for a configuration profile
+///
+/// Profile settings are used whenever the matching flag, argument or
+/// environment variable is left off. The available settings are
+/// `registry_url`, `graph_ref`, `federation_version`, `client_timeout`,
+/// `accept_invalid_certs`, `accept_invalid_hostnames`, `proxy`, and the `retry_*`
+/// settings.
+pub struct Set {
+    /// The setting to change
+    #[arg(value_parser = ProfileSettings::KEYS.to_vec())]
+    #[serde(skip_serializing)]
+    key: String,
+
+    /// The new value for the setting
+    #[arg(required_unless_present = "unset")]
+    #[serde(skip_serializing)]
+    value: Option<String>,
+
+    /// Remove the setting, so the built-in default is used again
+    #[arg(long, conflicts_with = "value")]
+    #[serde(skip_serializing)]
+    unset: bool,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Set {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let profile_name = &self.profile.profile_name;
+        // a profile is loaded through its API key, so settings can't create one on their own
+        if !Profile::list(&config)?.contains(profile_name) {
+            return Err(HoustonProblem::ProfileNotFound(profile_name.to_string()).into());
+        }
+        let mut settings = Profile::get_settings(profile_name, &config)?;
+        match &self.value {
+            Some(value) if !self.unset => {
+                validate(&self.key, value)?;
+                settings.set(&self.key, value)?;
+            }
+            _ => settings.unset(&self.key)?,
+        }
+        Profile::save(
+            profile_name,
+            &config,
+            ProfileData {
+                api_key: None,
+                settings: Some(settings),
+            },
+        )?;
+        eprintln!(
+            "Successfully updated {} for the {} profile.",
+            Style::Command.paint(&self.key),
+            Style::Command.paint(profile_name)
+        );
+        Ok(RoverOutput::EmptySuccess)
+    }
+}
+
+/// Checks the settings that houston stores as plain strings, so mistakes are caught now rather
+/// than the next time the setting is used.
+fn validate(key: &str, value: &str) -> RoverResult<()> {
+    match key {
+        "registry_url" => {
+            Url::parse(value).with_context(|| format!("'{value}' is not a valid URL"))?;
+        }
+        "proxy" => {
+            parse_proxy_url(value)
+                .with_context(|| format!("'{value}' is not a valid proxy URL"))?;
+        }
+        "retry_max_elapsed" | "retry_base_delay" | "retry_max_delay" => {
+            humantime::parse_duration(value).with_context(|| {
+                format!("'{value}' is not a valid duration, like '500ms' or '2m'")
+            })?;
+        }
+        "retry_on" => {
+            value.parse::<RetryOn>().map_err(|err| anyhow!(err))?;
+        }
+        "graph_ref" => {
+            GraphRef::from_str(value)?;
+        }
+        "federation_version" => {
+            FederationVersion::from_str(value).with_context(|| {
+                format!(
+                    "Invalid federation version '{value}'. Must be 'latest-0', 'latest-2', or an exact version preceded with an '='."
+                )
+            })?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use camino::Utf8Path;
+    use clap::Parser;
+    use houston::{Config, Profile};
+
+    use super::Set;
+
+    fn get_config() -> (TempDir, Config) {
+        let tmp_home = TempDir::new().unwrap();
+        let tmp_home_path = Utf8Path::from_path(tmp_home.path()).unwrap().to_owned();
+        let config = Config::new(Some(&tmp_home_path), None).unwrap();
+        (tmp_home, config)
+    }
+
+    #[test]
+    fn it_can_set_and_unset_a_setting() {
+        let (_tmp_home, config) = get_config();
+        Profile::set_api_key("staging", &config, "user:gh.foo:key").unwrap();
+
+        Set::parse_from([
+            "set",
+            "graph_ref",
+            "my-graph@staging",
+            "--profile",
+            "staging",
+        ])
+        .run(config.clone())
+        .unwrap();
+        let settings = Profile::get_settings("staging", &config).unwrap();
+        assert_eq!(settings.graph_ref, Some("my-graph@staging".to_string()));
+
+        Set::parse_from(["set", "graph_ref", "--unset", "--profile", "staging"])
+            .run(config.clone())
+            .unwrap();
+        let settings = Profile::get_settings("staging", &config).unwrap();
+        assert_eq!(settings.graph_ref, None);
+    }
+
+    #[test]
+    fn it_refuses_to_create_a_profile() {
+        let (_tmp_home, config) = get_config();
+
+        assert!(
+            Set::parse_from(["set", "client_timeout", "90", "--profile", "staging"])
+                .run(config.clone())
+                .is_err()
+        );
+        assert!(Profile::list(&config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_rejects_invalid_values() {
+        let (_tmp_home, config) = get_config();
+        Profile::set_api_key("default", &config, "user:gh.foo:key").unwrap();
+
+        assert!(
+            Set::parse_from(["set", "federation_version", "three"])
+                .run(config.clone())
+                .is_err()
+        );
+        assert!(
+            Set::parse_from(["set", "client_timeout", "soon"])
+                .run(config.clone())
+                .is_err()
+        );
+        assert!(
+            Set::parse_from(["set", "proxy", "http://proxy:port"])
+                .run(config.clone())
+                .is_err()
+        );
+        assert!(
+            Set::parse_from(["set", "retry_max_elapsed", "a while"])
+                .run(config.clone())
+                .is_err()
+        );
+        assert!(
+            Set::parse_from(["set", "retry_on", "5xx,teapot"])
+                .run(config)
+                .is_err()
+        );
+        assert!(Set::try_parse_from(["set", "api_key", "user:gh.foo:key"]).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::project_config::ProjectConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+/// Show the options resolved from flags, environment variables, `.rover.toml` and profile
+/// settings, along with where each value came from.
+///
+/// Pass the same flags as another command to see what that command would resolve.
+pub struct ShowEffective {
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// The supergraph config to resolve, as with `--supergraph-config` on other commands
+    #[arg(long = "supergraph-config", id = "supergraph_config_path")]
+    #[serde(skip_serializing)]
+    supergraph_config: Option<String>,
+
+    /// The federation version to resolve, as with `--federation-version` on other commands
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    federation_version: Option<String>,
+
+    /// The router config to resolve, as with `rover dev --router-config`
+    #[arg(long = "router-config", id = "router_config_path")]
+    #[serde(skip_serializing)]
+    router_config: Option<String>,
+
+    /// The query count threshold to resolve, as with `--query-count-threshold` on checks
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    query_count_threshold: Option<String>,
+
+    /// The query percentage threshold to resolve, as with `--query-percentage-threshold` on
+    /// checks
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    query_percentage_threshold: Option<String>,
+
+    /// The validation period to resolve, as with `--validation-period` on checks
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    validation_period: Option<String>,
+
+    /// Resolve as if `--background` were passed to a check
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    background: bool,
+}
+
+impl ShowEffective {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let graph_ref = self
+            .graph
+            .resolve_with_source(&self.profile, &config)?
+            .map(|(graph_ref, source)| (graph_ref.to_string(), source));
+        let values = ProjectConfig::effective_values(
+            ProjectConfig::current()?,
+            vec![
+                ("profile_name", Some(self.profile.profile_name.clone())),
+                ("supergraph_config_path", self.supergraph_config.clone()),
+                ("federation_version", self.federation_version.clone()),
+                ("router_config_path", self.router_config.clone()),
+                ("query_count_threshold", self.query_count_threshold.clone()),
+                (
+                    "query_percentage_threshold",
+                    self.query_percentage_threshold.clone(),
+                ),
+                ("validation_period", self.validation_period.clone()),
+                ("background", Some(self.background.to_string())),
+            ],
+            graph_ref,
+            &self.profile.profile_name,
+            &config,
+        )?;
+        Ok(RoverOutput::EffectiveConfig(values))
+    }
+}
@@ -0,0 +1,21 @@
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult};
+
+#[derive(Debug, Serialize, Parser)]
+/// Stop reporting anonymous usage data from this machine
+///
+/// This has the same effect as always setting `APOLLO_TELEMETRY_DISABLED=true`, for every
+/// profile.
+pub struct Disable {}
+
+impl Disable {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let mut settings = config.telemetry_settings()?;
+        settings.disabled = true;
+        config.save_telemetry_settings(&settings)?;
+        eprintln!("Anonymous usage data will no longer be reported from this machine.");
+        Ok(RoverOutput::EmptySuccess)
+    }
+}
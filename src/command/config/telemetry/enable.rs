@@ -0,0 +1,23 @@
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult, utils::env::RoverEnvKey};
+
+#[derive(Debug, Serialize, Parser)]
+/// Resume reporting anonymous usage data from this machine
+///
+/// `APOLLO_TELEMETRY_DISABLED` still turns reporting off when it's set.
+pub struct Enable {}
+
+impl Enable {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let mut settings = config.telemetry_settings()?;
+        settings.disabled = false;
+        config.save_telemetry_settings(&settings)?;
+        eprintln!(
+            "Anonymous usage data will be reported from this machine, unless {} is set.",
+            RoverEnvKey::TelemetryDisabled
+        );
+        Ok(RoverOutput::EmptySuccess)
+    }
+}
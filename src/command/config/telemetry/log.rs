@@ -0,0 +1,42 @@
+use camino::Utf8PathBuf;
+use clap::Parser;
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult};
+
+#[derive(Debug, Serialize, Parser)]
+/// Keep a local copy of every anonymous usage data payload that is reported
+///
+/// Each payload is appended to the file as a line of JSON, just before it's sent.
+pub struct Log {
+    /// The file to append payloads to
+    #[arg(required_unless_present = "off")]
+    #[serde(skip_serializing)]
+    file: Option<Utf8PathBuf>,
+
+    /// Stop keeping a copy of reported payloads
+    #[arg(long, conflicts_with = "file")]
+    #[serde(skip_serializing)]
+    off: bool,
+}
+
+impl Log {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        let mut settings = config.telemetry_settings()?;
+        settings.log = match &self.file {
+            // payloads are reported from wherever Rover runs, so a relative path would scatter them
+            Some(file) if !self.off => Some(camino::absolute_utf8(file)?),
+            _ => None,
+        };
+        config.save_telemetry_settings(&settings)?;
+        match &settings.log {
+            Some(file) => eprintln!(
+                "Reported usage data will be appended to {}.",
+                Style::Path.paint(file)
+            ),
+            None => eprintln!("Reported usage data will no longer be kept."),
+        }
+        Ok(RoverOutput::EmptySuccess)
+    }
+}
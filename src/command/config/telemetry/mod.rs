@@ -0,0 +1,41 @@
+mod disable;
+mod enable;
+mod log;
+mod show;
+
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Telemetry {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Serialize, Parser)]
+pub enum Command {
+    /// Stop reporting anonymous usage data from this machine
+    Disable(disable::Disable),
+
+    /// Resume reporting anonymous usage data from this machine
+    Enable(enable::Enable),
+
+    /// Keep a local copy of every anonymous usage data payload that is reported
+    Log(log::Log),
+
+    /// Print the exact anonymous usage data that would be reported for a command
+    Show(show::Show),
+}
+
+impl Telemetry {
+    pub fn run(&self, config: houston::Config) -> RoverResult<RoverOutput> {
+        match &self.command {
+            Command::Disable(command) => command.run(config),
+            Command::Enable(command) => command.run(config),
+            Command::Log(command) => command.run(config),
+            Command::Show(command) => command.run(),
+        }
+    }
+}
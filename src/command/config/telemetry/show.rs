@@ -0,0 +1,43 @@
+use std::iter;
+
+use anyhow::anyhow;
+use clap::Parser;
+use serde::Serialize;
+use sputnik::Session;
+
+use crate::{PKG_NAME, RoverError, RoverOutput, RoverResult, cli::Rover};
+
+#[derive(Debug, Serialize, Parser)]
+/// Print the exact anonymous usage data that would be reported for a command
+///
+/// The command itself isn't run, e.g. `rover config telemetry show subgraph check
+/// my-graph@current --name products --schema ./products.graphql`.
+pub struct Show {
+    /// The command to show the payload for, without the leading `rover`
+    #[arg(
+        value_name = "COMMAND",
+        required = true,
+        trailing_var_arg = true,
+        allow_hyphen_values = true
+    )]
+    #[serde(skip_serializing)]
+    command: Vec<String>,
+}
+
+impl Show {
+    pub fn run(&self) -> RoverResult<RoverOutput> {
+        let args = iter::once(PKG_NAME.to_string()).chain(self.command.iter().cloned());
+        let rover = Rover::try_parse_from(args).map_err(|err| {
+            RoverError::new(anyhow!(
+                "Could not parse `{PKG_NAME} {}`:\n{err}",
+                self.command.join(" ")
+            ))
+        })?;
+        // previewing shouldn't write anything, including the machine ID
+        let session = Session::preview(&rover)?;
+        Ok(RoverOutput::TelemetryPayload {
+            payload: session.payload()?,
+            enabled: session.is_telemetry_enabled(),
+        })
+    }
+}
@@ -70,6 +70,15 @@ impl WhoAmI {
     fn get_origin(&self, client: &StudioClient) -> String {
         match client.get_credential_origin() {
             CredentialOrigin::ConfigFile(path) => format!("--profile {}", &path),
+            CredentialOrigin::SecretTool(profile) => {
+                format!("--profile {} (secret-tool)", &profile)
+            }
+            CredentialOrigin::EncryptedFile(profile) => {
+                format!("--profile {} (encrypted file)", &profile)
+            }
+            CredentialOrigin::CredentialHelper(profile) => {
+                format!("--profile {} (credential helper)", &profile)
+            }
             CredentialOrigin::EnvVar => format!("${}", &RoverEnvKey::Key),
         }
     }
@@ -0,0 +1,49 @@
+use clap::Parser;
+use rover_schema::ContractFilter;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult, utils::parsers::FileDescriptorType};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Build {
+    /// The supergraph schema to filter, such as the output of `rover supergraph compose`. You can
+    /// pass `-` to use stdin instead of a file.
+    #[arg(long, short = 's')]
+    #[serde(skip_serializing)]
+    supergraph: FileDescriptorType,
+
+    /// List of tag names to include in the contract schema (e.g. '--include-tag foo --include-tag bar').
+    /// When omitted, every element that isn't excluded is included.
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    include_tag: Vec<String>,
+
+    /// List of tag names to exclude from the contract schema (e.g. '--exclude-tag foo --exclude-tag bar').
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    exclude_tag: Vec<String>,
+
+    /// Automatically hide types that can never be reached in operations on the contract schema.
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    hide_unreachable_types: bool,
+}
+
+impl Build {
+    pub fn run(&self) -> RoverResult<RoverOutput> {
+        let supergraph_sdl = self
+            .supergraph
+            .read_file_descriptor("supergraph schema", &mut std::io::stdin())?;
+
+        eprintln!("Building contract schema from the supergraph schema.\n");
+
+        let api_schema = ContractFilter {
+            include_tags: self.include_tag.clone(),
+            exclude_tags: self.exclude_tag.clone(),
+            hide_unreachable_types: self.hide_unreachable_types,
+        }
+        .build(&supergraph_sdl)?;
+
+        Ok(RoverOutput::ContractSchema(api_schema))
+    }
+}
@@ -1,3 +1,4 @@
+mod build;
 mod describe;
 mod publish;
 
@@ -14,6 +15,9 @@ pub struct Contract {
 
 #[derive(Debug, Serialize, Parser)]
 pub enum Command {
+    /// Build a contract schema locally by applying tag filters to a supergraph schema
+    Build(build::Build),
+
     /// Describe the configuration of a contract variant from the Apollo graph registry
     Describe(describe::Describe),
 
@@ -24,6 +28,7 @@ pub enum Command {
 impl Contract {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         match &self.command {
+            Command::Build(command) => command.run(),
             Command::Describe(command) => command.run(client_config).await,
             Command::Publish(command) => command.run(client_config).await,
         }
@@ -72,9 +72,10 @@ pub struct Publish {
 impl Publish {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
         eprintln!(
             "Publishing configuration to {} using credentials from the {} profile.\n",
-            Style::Link.paint(self.graph.graph_ref.to_string()),
+            Style::Link.paint(graph_ref.to_string()),
             Style::Command.paint(&self.profile.profile_name)
         );
 
@@ -94,7 +95,7 @@ impl Publish {
 
         let publish_response = publish::run(
             ContractPublishInput {
-                graph_ref: self.graph.graph_ref.clone(),
+                graph_ref,
                 source_variant: self.source_variant.clone(),
                 include_tags,
                 exclude_tags,
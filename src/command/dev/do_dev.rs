@@ -4,11 +4,12 @@ use anyhow::anyhow;
 use apollo_federation_types::config::{FederationVersion, RouterVersion};
 use camino::Utf8PathBuf;
 use dotenvy::dotenv;
-use futures::StreamExt;
+use futures::{StreamExt, stream};
 use rover_client::RoverClientError;
 use rover_std::{errln, infoln};
 use semver::Version;
 use timber::Level;
+use tokio::task::JoinHandle;
 use tower::ServiceExt;
 
 use crate::{
@@ -17,10 +18,12 @@ use crate::{
         Dev,
         dev::{
             OVERRIDE_DEV_COMPOSITION_VERSION, OVERRIDE_DEV_ROUTER_VERSION,
+            logs::LogPrinter,
             mcp::{binary::RunMcpServerBinaryError, run::RunMcpServer},
             router::{
                 binary::RunRouterBinaryError,
-                config::{RouterAddress, RouterHost, RouterPort},
+                replay::{Replay, ReplayError, ReplayResult, format_replay_results},
+                routers::{self, RouterSpec},
                 run::RunRouter,
             },
         },
@@ -30,6 +33,7 @@ use crate::{
         CompositionError, FederationUpdaterConfig,
         events::CompositionEvent,
         pipeline::CompositionPipeline,
+        runner::CompositionSlice,
         supergraph::config::{
             full::introspect::MakeResolveIntrospectSubgraph,
             resolver::{
@@ -68,7 +72,29 @@ impl Dev {
         let tmp_dir = tempfile::Builder::new().prefix("supergraph").tempdir()?;
         let tmp_config_dir_path = Utf8PathBuf::try_from(tmp_dir.keep())?;
 
-        let router_config_path = self.opts.supergraph_opts.router_config_path.clone();
+        let router_specs = match &self.opts.supergraph_opts.routers_config_path {
+            Some(routers_config_path) => RouterSpec::load(routers_config_path)?,
+            None => vec![RouterSpec::from_cli(
+                self.opts.supergraph_opts.supergraph_address,
+                self.opts.supergraph_opts.supergraph_port,
+                self.opts.supergraph_opts.router_config_path.clone(),
+            )],
+        };
+
+        // A replay file that can't be read is reported before anything is started, but it
+        // doesn't stop the dev session
+        let replay = match self.opts.replay.operations_path.clone() {
+            Some(operations_path) => {
+                match Replay::load(operations_path, self.opts.replay.baseline_path.clone()) {
+                    Ok(replay) => Some(replay),
+                    Err(err) => {
+                        errln!("{err}. No operations will be replayed.");
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
 
         let profile = &self.opts.plugin_opts.profile;
         let graph_ref = &self.opts.supergraph_opts.graph_ref;
@@ -93,7 +119,8 @@ impl Dev {
         //
         // 1) cli option
         // 2) env var override
-        // 3) what's in the supergraph config (represented here as None)
+        // 3) the profile's `federation_version` setting
+        // 4) what's in the supergraph config (represented here as None)
         let federation_version = self
             .opts
             .supergraph_opts
@@ -115,6 +142,10 @@ impl Dev {
 
                 version.clone()
             });
+        let federation_version = self
+            .opts
+            .plugin_opts
+            .federation_version(federation_version, &client_config.config)?;
 
         let subgraph_definition = self
             .opts
@@ -158,6 +189,12 @@ impl Dev {
             )
             .await?;
 
+        // A typo in the routers file would otherwise leave a router composing nothing
+        routers::check_subgraphs(
+            &router_specs,
+            &composition_pipeline.state.resolver.subgraph_names(),
+        )?;
+
         let router_version = match &*OVERRIDE_DEV_ROUTER_VERSION {
             Some(version) => RouterVersion::Exact(Version::parse(version)?),
             None => RouterVersion::LatestTwo,
@@ -190,57 +227,67 @@ impl Dev {
             )
             .await?;
 
-        let mut composition_messages = composition_runner.run();
+        // Each router composes its own slice of the supergraph, but they all share the same
+        // subgraph watchers. A single router composes everything, as it always has
+        let multiple_routers = router_specs.len() > 1;
+        let composition_streams = if multiple_routers {
+            let slices: Vec<CompositionSlice> =
+                router_specs.iter().map(|spec| spec.slice.clone()).collect();
+            composition_runner.run_slices(&slices)
+        } else {
+            vec![composition_runner.run()]
+        };
 
         // Sit in a loop and wait for the composition to actually succeed, once it does then
         // we can progress
-        let supergraph_schema;
-        loop {
-            match composition_messages.next().await {
-                Some(CompositionEvent::Started) => {
-                    if let Ok(ref binary) = composition_pipeline.state.supergraph_binary {
-                        eprintln!("composing supergraph with Federation {}", binary.version());
+        let mut composed_routers = Vec::with_capacity(router_specs.len());
+        for (spec, mut composition_messages) in router_specs.into_iter().zip(composition_streams) {
+            let supergraph_schema;
+            loop {
+                match composition_messages.next().await {
+                    Some(CompositionEvent::Started) => {
+                        if let Ok(ref binary) = composition_pipeline.state.supergraph_binary {
+                            if multiple_routers {
+                                eprintln!(
+                                    "composing supergraph for {} with Federation {}",
+                                    spec.name,
+                                    binary.version()
+                                );
+                            } else {
+                                eprintln!(
+                                    "composing supergraph with Federation {}",
+                                    binary.version()
+                                );
+                            }
+                        }
+                    }
+                    Some(CompositionEvent::Success(success)) => {
+                        supergraph_schema = success.supergraph_sdl;
+                        break;
+                    }
+                    Some(CompositionEvent::Error(CompositionError::Build { source, .. })) => {
+                        let number_of_subgraphs = source.len();
+                        let error_to_output = RoverError::from(RoverClientError::BuildErrors {
+                            source,
+                            num_subgraphs: number_of_subgraphs,
+                        });
+                        eprintln!("{error_to_output}")
+                    }
+                    Some(CompositionEvent::Error(err)) => {
+                        errln!("Error occurred when composing supergraph\n{}", err)
+                    }
+                    Some(_) => {}
+                    None => {
+                        return Err(RoverError::new(anyhow!(
+                            "Composition Events Stream closed before supergraph schema could successfully compose"
+                        )));
                     }
-                }
-                Some(CompositionEvent::Success(success)) => {
-                    supergraph_schema = success.supergraph_sdl;
-                    break;
-                }
-                Some(CompositionEvent::Error(CompositionError::Build { source, .. })) => {
-                    let number_of_subgraphs = source.len();
-                    let error_to_output = RoverError::from(RoverClientError::BuildErrors {
-                        source,
-                        num_subgraphs: number_of_subgraphs,
-                    });
-                    eprintln!("{error_to_output}")
-                }
-                Some(CompositionEvent::Error(err)) => {
-                    errln!("Error occurred when composing supergraph\n{}", err)
-                }
-                Some(_) => {}
-                None => {
-                    return Err(RoverError::new(anyhow!(
-                        "Composition Events Stream closed before supergraph schema could successfully compose"
-                    )));
                 }
             }
+            composed_routers.push((spec, composition_messages, supergraph_schema));
         }
 
-        // This RouterAddress hasn't been fully processed. It only represents the CLI option or
-        // default, but we still have to reckon with the config-set address (if one exists). See
-        // the reassignment of the variable below for details
-        let router_address = RouterAddress::new(
-            self.opts
-                .supergraph_opts
-                .supergraph_address
-                .map(RouterHost::CliOption),
-            self.opts
-                .supergraph_opts
-                .supergraph_port
-                .map(RouterPort::CliOption),
-        );
-
-        let run_router = RunRouter::default()
+        let install_router = RunRouter::default()
             .install(
                 router_version,
                 client_config.clone(),
@@ -248,44 +295,127 @@ impl Dev {
                 elv2_license_accepter,
                 skip_update,
             )
-            .await?
-            .load_config(&read_file_impl, router_address, router_config_path)
-            .await?
-            .load_remote_config(
-                client_config.clone(),
-                self.opts.plugin_opts.profile.clone(),
-                graph_ref.clone(),
-                home_override.clone(),
-                api_key_override.clone(),
-            )
-            .await;
-        // This RouterAddress has some logic figuring out _which_ of the potentially multiple
-        // address options we should use (eg, CLI, config, env var, or default). It will be used in
-        // the cli arguments for the router, but also as a message to the user for
-        // where to find their router
-        let router_address = *run_router.state.config.address();
-        // Extract the router's listen path from the config to construct the full endpoint URL for MCP
-        let router_url_path = run_router.state.config.listen_path();
-        infoln!(
-            "Attempting to start router at {}.",
-            router_address.pretty_string()
-        );
-
-        let mut run_router = run_router
-            .run(
-                FsWriteFile::default(),
-                TokioSpawn::default(),
-                &tmp_config_dir_path,
-                client_config.clone(),
-                &supergraph_schema,
-                self.opts.plugin_opts.profile.clone(),
-                home_override,
-                api_key_override,
-                log_level,
-            )
-            .await?
-            .watch_for_changes(write_file_impl, composition_messages)
-            .await;
+            .await?;
+
+        let mut router_log_printer = LogPrinter::new(
+            self.opts.router_logs.filter.clone(),
+            self.opts.router_logs.file.clone(),
+        )?;
+        // The router only emits logs as verbose as it's told to, so make sure it emits everything
+        // the log filter might want to show
+        let router_log_level = self
+            .opts
+            .router_logs
+            .filter
+            .as_ref()
+            .map(|filter| log_level.unwrap_or(Level::INFO).max(filter.level()))
+            .or(log_level);
+
+        let mut configured_routers = Vec::with_capacity(composed_routers.len());
+        for (spec, composition_messages, supergraph_schema) in composed_routers {
+            // The spec's RouterAddress hasn't been fully processed. It only represents the CLI
+            // option, routers file entry or default, but we still have to reckon with the
+            // config-set address (if one exists). See the reassignment of the variable below for
+            // details
+            let run_router = install_router
+                .clone()
+                .load_config(&read_file_impl, spec.address, spec.config_path)
+                .await?
+                .load_remote_config(
+                    client_config.clone(),
+                    self.opts.plugin_opts.profile.clone(),
+                    graph_ref.clone(),
+                    home_override.clone(),
+                    api_key_override.clone(),
+                )
+                .await;
+            configured_routers.push((
+                spec.name,
+                composition_messages,
+                supergraph_schema,
+                run_router,
+            ));
+        }
+        // Router configs can set their own ports, so check for clashes before starting any
+        if multiple_routers {
+            let listen_ports: Vec<(String, Vec<u16>)> = configured_routers
+                .iter()
+                .map(|(name, _, _, run_router)| {
+                    (name.clone(), run_router.state.config.listen_ports())
+                })
+                .collect();
+            routers::check_ports(&listen_ports)?;
+        }
+
+        let mut run_routers = Vec::with_capacity(configured_routers.len());
+        for (name, composition_messages, supergraph_schema, run_router) in configured_routers {
+            // This RouterAddress has some logic figuring out _which_ of the potentially multiple
+            // address options we should use (eg, CLI, config, env var, or default). It will be
+            // used in the cli arguments for the router, but also as a message to the user for
+            // where to find their router
+            let router_address = *run_router.state.config.address();
+            // Extract the router's listen path from the config to construct the full endpoint URL
+            // for MCP
+            let router_url_path = run_router.state.config.listen_path();
+            if multiple_routers {
+                infoln!(
+                    "Attempting to start router {} at {}.",
+                    name,
+                    router_address.pretty_string()
+                );
+            } else {
+                infoln!(
+                    "Attempting to start router at {}.",
+                    router_address.pretty_string()
+                );
+            }
+
+            // Routers running side-by-side each need their own copy of the config and schema
+            let temp_router_dir = if multiple_routers {
+                tmp_config_dir_path.join(&name)
+            } else {
+                tmp_config_dir_path.clone()
+            };
+            let run_router = run_router
+                .run(
+                    FsWriteFile::default(),
+                    TokioSpawn::default(),
+                    &temp_router_dir,
+                    client_config.clone(),
+                    &supergraph_schema,
+                    self.opts.plugin_opts.profile.clone(),
+                    home_override.clone(),
+                    api_key_override.clone(),
+                    router_log_level,
+                )
+                .await?
+                .watch_for_changes(write_file_impl.clone(), composition_messages)
+                .await;
+            run_routers.push((name, router_address, router_url_path, run_router));
+        }
+
+        // Recorded operations and the MCP server both go to the first router
+        let (_, router_address, router_url_path, _) = &run_routers[0];
+        let router_address = *router_address;
+        let router_url_path = router_url_path.clone();
+
+        // The router has reported healthy by now, so replay any recorded operations against it
+        // in the background while we keep forwarding its logs
+        let mut replay_task = match replay {
+            Some(replay) => {
+                let client = client_config.get_reqwest_client()?;
+                let endpoint = format!(
+                    "{}{}",
+                    router_address.pretty_string(),
+                    router_url_path.clone().unwrap_or_default()
+                );
+                infoln!("Replaying operations against {}", endpoint);
+                Some(tokio::spawn(
+                    async move { replay.run(&client, &endpoint).await },
+                ))
+            }
+            None => None,
+        };
 
         if let Some(ref config) = self.opts.mcp.config {
             let mcp_version = self
@@ -305,55 +435,67 @@ impl Dev {
                 )
                 .await?;
 
+            let mut mcp_log_printer = LogPrinter::new(
+                self.opts.mcp.log_filter.clone(),
+                self.opts.mcp.log_file.clone(),
+            )?;
+
+            let (_, _, _, first_router) = &run_routers[0];
             let mut run_mcp_server = run_mcp_server
                 .run(
                     TokioSpawn::default(),
-                    run_router.state.hot_reload_schema_path.clone(),
+                    first_router.state.hot_reload_schema_path.clone(),
                     router_address,
                     router_url_path,
                     config.clone(),
-                    run_router.state.env.clone(),
+                    self.opts
+                        .mcp
+                        .log_filter
+                        .as_ref()
+                        .map(|filter| filter.level()),
+                    first_router.state.env.clone(),
                 )
                 .await?;
 
+            let mut router_logs =
+                stream::select_all(run_routers.iter_mut().map(|(name, _, _, run_router)| {
+                    let name = name.clone();
+                    run_router
+                        .router_logs()
+                        .map(move |router_log| (name.clone(), router_log))
+                }));
             loop {
                 tokio::select! {
                     _ = tokio::signal::ctrl_c() => {
                         eprintln!("\nreceived shutdown signal, stopping `rover dev` processes...");
-
-                        // Note that these calls aren't strictly necessary. The OS will send the
-                        // SIGINT signal to forked child processes, so they would exit anyway.
-                        run_router.shutdown();
-                        run_mcp_server.shutdown();
                         break
                     },
 
-                    Some(router_log) = run_router.router_logs().next() => {
+                    Some((name, router_log)) = router_logs.next() => {
                         match router_log {
-                            Ok(router_log) => {
-                                if !router_log.to_string().is_empty() {
-                                    eprintln!("{router_log}");
-                                }
+                            Ok(router_log) if multiple_routers => {
+                                router_log_printer.print_from(&name, &router_log)
                             }
+                            Ok(router_log) => router_log_printer.print(&router_log),
                             Err(RunRouterBinaryError::BinaryExited(res)) => {
+                                let router = router_label(&name, multiple_routers);
                                 match res {
                                     Ok(status) => {
                                         match status.code() {
                                             None => {
-                                                eprintln!("Router process terminal by signal");
+                                                eprintln!("{router} process terminal by signal");
                                             }
                                             Some(code) => {
-                                                eprintln!("Router process exited with status code: {code}");
+                                                eprintln!("{router} process exited with status code: {code}");
                                             }
                                         }
 
                                     }
                                     Err(err) => {
-                                        tracing::error!("Router process exited without status code. Error: {err}")
+                                        tracing::error!("{router} process exited without status code. Error: {err}")
                                     }
                                 }
-                                eprintln!("\nRouter binary exited, stopping `rover dev` processes...");
-                                run_mcp_server.shutdown();
+                                eprintln!("\n{router} binary exited, stopping `rover dev` processes...");
                                 break;
                             }
                             Err(err) => {
@@ -364,11 +506,7 @@ impl Dev {
 
                     Some(mcp_server_logs) = run_mcp_server.mcp_server_logs().next() => {
                         match mcp_server_logs {
-                            Ok(mcp_server_logs) => {
-                                if !mcp_server_logs.to_string().is_empty() {
-                                    eprintln!("{mcp_server_logs}");
-                                }
-                            }
+                            Ok(mcp_server_logs) => mcp_log_printer.print(&mcp_server_logs),
                             Err(RunMcpServerBinaryError::BinaryExited(res)) => {
                                 match res {
                                     Ok(status) => {
@@ -387,7 +525,6 @@ impl Dev {
                                     }
                                 }
                                 eprintln!("\nMCP Server binary exited, stopping `rover dev` processes...");
-                                run_router.shutdown();
                                 break;
                             }
                             Err(err) => {
@@ -396,42 +533,60 @@ impl Dev {
                         }
                     },
 
+                    replay = replay_finished(&mut replay_task) => {
+                        match replay {
+                            Ok(results) => eprintln!("{}", format_replay_results(&results)),
+                            // the routers are still worth keeping around when a replay fails
+                            Err(err) => errln!("Failed to replay operations: {err}"),
+                        }
+                    },
+
                     else => break,
                 }
             }
+
+            // Note that these calls aren't strictly necessary. The OS will send the SIGINT signal
+            // to forked child processes, so they would exit anyway.
+            run_mcp_server.shutdown();
         } else {
+            let mut router_logs =
+                stream::select_all(run_routers.iter_mut().map(|(name, _, _, run_router)| {
+                    let name = name.clone();
+                    run_router
+                        .router_logs()
+                        .map(move |router_log| (name.clone(), router_log))
+                }));
             loop {
                 tokio::select! {
                     _ = tokio::signal::ctrl_c() => {
                         eprintln!("\nreceived shutdown signal, stopping `rover dev` processes...");
-                        run_router.shutdown();
                         break
                     },
-                    Some(router_log) = run_router.router_logs().next() => {
+                    Some((name, router_log)) = router_logs.next() => {
                         match router_log {
-                            Ok(router_log) => {
-                                if !router_log.to_string().is_empty() {
-                                    eprintln!("{router_log}");
-                                }
+                            Ok(router_log) if multiple_routers => {
+                                router_log_printer.print_from(&name, &router_log)
                             }
+                            Ok(router_log) => router_log_printer.print(&router_log),
                             Err(RunRouterBinaryError::BinaryExited(res)) => {
+                                let router = router_label(&name, multiple_routers);
                                 match res {
                                     Ok(status) => {
                                         match status.code() {
                                             None => {
-                                                eprintln!("Router process terminal by signal");
+                                                eprintln!("{router} process terminal by signal");
                                             }
                                             Some(code) => {
-                                                eprintln!("Router process exited with status code: {code}");
+                                                eprintln!("{router} process exited with status code: {code}");
                                             }
                                         }
 
                                     }
                                     Err(err) => {
-                                        tracing::error!("Router process exited without status code. Error: {err}")
+                                        tracing::error!("{router} process exited without status code. Error: {err}")
                                     }
                                 }
-                                eprintln!("\nRouter binary exited, stopping `rover dev` processes...");
+                                eprintln!("\n{router} binary exited, stopping `rover dev` processes...");
                                 break;
                             }
                             Err(err) => {
@@ -439,10 +594,43 @@ impl Dev {
                             }
                         }
                     },
+                    replay = replay_finished(&mut replay_task) => {
+                        match replay {
+                            Ok(results) => eprintln!("{}", format_replay_results(&results)),
+                            // the routers are still worth keeping around when a replay fails
+                            Err(err) => errln!("Failed to replay operations: {err}"),
+                        }
+                    },
+
                     else => break,
                 }
             }
         };
+        // Stop every router, including the others when only one of them has exited
+        for (_, _, _, run_router) in &mut run_routers {
+            run_router.shutdown();
+        }
         Ok(RoverOutput::EmptySuccess)
     }
 }
+
+/// Waits for replayed operations to finish, or forever if nothing is being replayed
+async fn replay_finished(
+    replay_task: &mut Option<JoinHandle<Result<Vec<ReplayResult>, ReplayError>>>,
+) -> RoverResult<Vec<ReplayResult>> {
+    let Some(task) = replay_task.as_mut() else {
+        return std::future::pending().await;
+    };
+    let results = task.await;
+    *replay_task = None;
+    Ok(results??)
+}
+
+/// How to refer to a router in messages about it exiting
+fn router_label(name: &str, multiple_routers: bool) -> String {
+    if multiple_routers {
+        format!("Router {name}")
+    } else {
+        "Router".to_string()
+    }
+}
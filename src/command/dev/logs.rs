@@ -0,0 +1,500 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    str::FromStr,
+};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Parser;
+use console::strip_ansi_codes;
+use rover_std::Style;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use timber::{FORWARDED_TARGET, Level, LogFormat};
+
+/// The most verbose level shown in the terminal when no filter is given. More verbose logs are
+/// still sent to Rover's own tracing output.
+const DEFAULT_FILTER_LEVEL: Level = Level::WARN;
+
+/// Rotate log files once they grow past 10MB
+const DEFAULT_LOG_FILE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Keep this many rotated log files around in addition to the active one
+const DEFAULT_LOG_FILE_MAX_FILES: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Parser)]
+pub struct RouterLogOpts {
+    /// Filter the router logs shown in the terminal by level and subgraph
+    ///
+    /// Accepts comma-separated directives, e.g. `debug` or `level=info,subgraph=products`.
+    /// Defaults to showing warnings and errors from every subgraph.
+    #[arg(long = "router-log-filter")]
+    #[serde(skip_serializing)]
+    pub filter: Option<LogFilter>,
+
+    /// Write the router's raw logs to this file, rotating it every 10MB
+    #[arg(long = "router-log-file")]
+    pub file: Option<Utf8PathBuf>,
+}
+
+/// A log line from one of the child processes `rover dev` runs.
+pub trait ChildProcessLog {
+    /// The line exactly as the process emitted it
+    fn raw(&self) -> &str;
+
+    /// The line as it should be shown in the terminal, or `None` if `filter` hides it
+    fn render(&self, filter: &LogFilter) -> Option<String>;
+
+    /// The line parsed as a structured log, if the process emitted it as one
+    fn parse(&self) -> Option<StructuredLog>;
+}
+
+/// Shows child process logs in the terminal according to a [`LogFilter`], optionally copying
+/// every raw line to a [`RotatingLogFile`].
+///
+/// Unless Rover's own logs are pretty-printed, the lines that would be shown are forwarded as
+/// tracing events instead, so they come out in the same format as Rover's logs.
+pub struct LogPrinter {
+    filter: LogFilter,
+    file: Option<RotatingLogFile>,
+    format: LogFormat,
+}
+
+impl LogPrinter {
+    pub fn new(filter: Option<LogFilter>, file: Option<Utf8PathBuf>) -> io::Result<LogPrinter> {
+        let file = file
+            .map(|path| {
+                RotatingLogFile::new(path, DEFAULT_LOG_FILE_MAX_BYTES, DEFAULT_LOG_FILE_MAX_FILES)
+            })
+            .transpose()?;
+        Ok(LogPrinter {
+            filter: filter.unwrap_or_default(),
+            file,
+            format: timber::log_format(),
+        })
+    }
+
+    pub fn print<L: ChildProcessLog>(&mut self, log: &L) {
+        self.print_labelled(None, log)
+    }
+
+    /// Like [`LogPrinter::print`], but labels the line with the name of the process that emitted
+    /// it, for when several processes share the terminal
+    pub fn print_from<L: ChildProcessLog>(&mut self, source: &str, log: &L) {
+        self.print_labelled(Some(source), log)
+    }
+
+    fn print_labelled<L: ChildProcessLog>(&mut self, source: Option<&str>, log: &L) {
+        if let Some(file) = &mut self.file {
+            let written = match source {
+                Some(source) => file.write_line(&format!("[{source}] {}", log.raw())),
+                None => file.write_line(log.raw()),
+            };
+            if let Err(err) = written {
+                tracing::error!("Failed to write to {}: {:?}", file.path, err);
+            }
+        }
+        if let Some(line) = log.render(&self.filter)
+            && !line.is_empty()
+        {
+            if self.format != LogFormat::Pretty {
+                forward(source, log.parse(), log.raw());
+                return;
+            }
+            match source {
+                Some(source) => {
+                    eprintln!("{} {line}", Style::Command.paint(format!("[{source}]")))
+                }
+                None => eprintln!("{line}"),
+            }
+        }
+    }
+}
+
+/// Emits a child process log as a tracing event, keeping its structure when it has one
+fn forward(source: Option<&str>, log: Option<StructuredLog>, raw: &str) {
+    let source = source.unwrap_or_default();
+    let (level, message, target, fields) = match log {
+        Some(log) => (
+            log.level.unwrap_or(Level::WARN),
+            log.message,
+            log.target.unwrap_or_default(),
+            Value::Object(log.fields),
+        ),
+        None => (
+            Level::WARN,
+            strip_ansi_codes(raw).to_string(),
+            String::new(),
+            Value::Null,
+        ),
+    };
+    match level {
+        Level::ERROR => {
+            tracing::error!(
+                target: FORWARDED_TARGET,
+                source,
+                child.target = %target,
+                child.fields = %fields,
+                "{message}"
+            )
+        }
+        Level::WARN => {
+            tracing::warn!(
+                target: FORWARDED_TARGET,
+                source,
+                child.target = %target,
+                child.fields = %fields,
+                "{message}"
+            )
+        }
+        Level::INFO => {
+            tracing::info!(
+                target: FORWARDED_TARGET,
+                source,
+                child.target = %target,
+                child.fields = %fields,
+                "{message}"
+            )
+        }
+        Level::DEBUG => {
+            tracing::debug!(
+                target: FORWARDED_TARGET,
+                source,
+                child.target = %target,
+                child.fields = %fields,
+                "{message}"
+            )
+        }
+        _ => {
+            tracing::trace!(
+                target: FORWARDED_TARGET,
+                source,
+                child.target = %target,
+                child.fields = %fields,
+                "{message}"
+            )
+        }
+    }
+}
+
+/// A log line emitted by one of the child processes `rover dev` runs, parsed from its JSON
+/// log output.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructuredLog {
+    pub level: Option<Level>,
+    pub target: Option<String>,
+    pub message: String,
+    pub timestamp: Option<String>,
+    /// Fields attached to the event itself
+    pub fields: Map<String, Value>,
+    /// Spans the event was emitted in, from the outermost to the innermost
+    pub spans: Vec<Map<String, Value>>,
+}
+
+impl StructuredLog {
+    /// Parses a line of `tracing-subscriber` JSON output.
+    ///
+    /// Depending on the version of the emitting binary, the message is either at the top level
+    /// or nested under `fields`, and fields are either nested under `fields` or flattened into
+    /// the top level object; both layouts are handled here.
+    pub fn parse(line: &str) -> Option<StructuredLog> {
+        let Value::Object(mut parsed) = serde_json::from_str::<Value>(line).ok()? else {
+            return None;
+        };
+
+        let level = parsed
+            .remove("level")
+            .and_then(|level| level.as_str().and_then(|l| Level::from_str(l).ok()));
+        let target = parsed
+            .remove("target")
+            .and_then(|target| target.as_str().map(str::to_string));
+        let timestamp = parsed
+            .remove("timestamp")
+            .and_then(|timestamp| timestamp.as_str().map(str::to_string));
+
+        let mut fields = match parsed.remove("fields") {
+            Some(Value::Object(fields)) => fields,
+            _ => Map::new(),
+        };
+
+        let mut spans: Vec<Map<String, Value>> = match parsed.remove("spans") {
+            Some(Value::Array(spans)) => spans
+                .into_iter()
+                .filter_map(|span| match span {
+                    Value::Object(span) => Some(span),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        if let Some(Value::Object(span)) = parsed.remove("span")
+            && spans.last() != Some(&span)
+        {
+            spans.push(span);
+        }
+
+        let message = fields
+            .remove("message")
+            .or_else(|| parsed.remove("message"))
+            .map(|message| match message {
+                Value::String(message) => message,
+                message => message.to_string(),
+            })?;
+
+        // Anything left at the top level is a flattened field
+        fields.extend(parsed);
+
+        Some(StructuredLog {
+            level,
+            target,
+            message,
+            timestamp,
+            fields,
+            spans,
+        })
+    }
+
+    /// Looks up a field on the event, falling back to the innermost span that has it.
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .get(name)
+            .or_else(|| self.spans.iter().rev().find_map(|span| span.get(name)))
+    }
+
+    /// Looks up the first of `names` present on the event or its spans as a string.
+    pub fn field_str(&self, names: &[&str]) -> Option<&str> {
+        names
+            .iter()
+            .find_map(|name| self.field(name).and_then(Value::as_str))
+    }
+}
+
+/// Controls which child process logs are shown in the terminal.
+///
+/// Written as comma-separated directives, e.g. `info`, `level=debug` or
+/// `level=info,subgraph=products,subgraph=reviews`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogFilter {
+    level: Level,
+    subgraphs: Vec<String>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        LogFilter {
+            level: DEFAULT_FILTER_LEVEL,
+            subgraphs: Vec::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    /// Whether a log at `level`, optionally attributed to `subgraph`, should be shown.
+    ///
+    /// When the filter names subgraphs, logs that can't be attributed to one of them are hidden.
+    pub fn allows(&self, level: Level, subgraph: Option<&str>) -> bool {
+        if level > self.level {
+            return false;
+        }
+        self.subgraphs.is_empty()
+            || subgraph.is_some_and(|subgraph| self.subgraphs.iter().any(|s| s == subgraph))
+    }
+
+    /// The most verbose level this filter shows
+    pub const fn level(&self) -> Level {
+        self.level
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = LogFilter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let (key, value) = directive.split_once('=').unwrap_or(("level", directive));
+            match (key.trim(), value.trim()) {
+                ("level", level) => {
+                    filter.level = Level::from_str(level)
+                        .map_err(|_| format!("'{level}' is not a valid log level"))?;
+                }
+                ("subgraph", subgraph) if !subgraph.is_empty() => {
+                    filter.subgraphs.push(subgraph.to_string())
+                }
+                _ => {
+                    return Err(format!(
+                        "'{directive}' is not a valid log filter. Expected `<level>`, `level=<level>` or `subgraph=<name>`"
+                    ));
+                }
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// A log file that is rotated once it reaches a maximum size, keeping a bounded number of old
+/// files around as `<path>.1`, `<path>.2`, ... with `<path>.1` being the most recent.
+pub struct RotatingLogFile {
+    path: Utf8PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    written: u64,
+}
+
+impl RotatingLogFile {
+    pub fn new(path: Utf8PathBuf, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_append(&path)?;
+        let written = file.metadata()?.len();
+        Ok(RotatingLogFile {
+            path,
+            max_bytes,
+            max_files,
+            file,
+            written,
+        })
+    }
+
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.written > 0 && self.written + len > self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.written += len;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            self.file = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .open(&self.path)?;
+        } else {
+            for index in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+            self.file = open_append(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+fn open_append(path: &Utf8Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn rotated_path(path: &Utf8Path, index: usize) -> Utf8PathBuf {
+    Utf8PathBuf::from(format!("{path}.{index}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_nested_message_format() {
+        let line = json!({
+            "timestamp": "2025-01-01T00:00:00Z",
+            "level": "WARN",
+            "target": "apollo_router::services::subgraph_service",
+            "fields": { "message": "fetch failed", "http.status": 500 },
+            "spans": [{ "name": "request" }, { "name": "subgraph", "apollo.subgraph.name": "products" }]
+        })
+        .to_string();
+        let log = StructuredLog::parse(&line).unwrap();
+        assert_eq!(log.level, Some(Level::WARN));
+        assert_eq!(log.message, "fetch failed");
+        assert_eq!(
+            log.target.as_deref(),
+            Some("apollo_router::services::subgraph_service")
+        );
+        assert_eq!(log.field("http.status"), Some(&json!(500)));
+        assert_eq!(log.field_str(&["apollo.subgraph.name"]), Some("products"));
+    }
+
+    #[test]
+    fn parses_flattened_message_format() {
+        let line = json!({
+            "level": "ERROR",
+            "message": "boom",
+            "subgraph": "reviews",
+            "span": { "name": "subgraph" }
+        })
+        .to_string();
+        let log = StructuredLog::parse(&line).unwrap();
+        assert_eq!(log.level, Some(Level::ERROR));
+        assert_eq!(log.message, "boom");
+        assert_eq!(log.field_str(&["subgraph"]), Some("reviews"));
+        assert_eq!(log.spans.len(), 1);
+    }
+
+    #[test]
+    fn ignores_unstructured_lines() {
+        assert!(StructuredLog::parse("plain text").is_none());
+        assert!(StructuredLog::parse("[1, 2]").is_none());
+    }
+
+    #[rstest]
+    #[case::bare_level("info", Level::INFO, vec![])]
+    #[case::level_directive("level=debug", Level::DEBUG, vec![])]
+    #[case::subgraphs("subgraph=a,subgraph=b", Level::WARN, vec!["a", "b"])]
+    #[case::both("level=error, subgraph=a", Level::ERROR, vec!["a"])]
+    fn parses_log_filters(#[case] input: &str, #[case] level: Level, #[case] subgraphs: Vec<&str>) {
+        let filter = LogFilter::from_str(input).unwrap();
+        assert_eq!(filter.level, level);
+        assert_eq!(filter.subgraphs, subgraphs);
+    }
+
+    #[rstest]
+    #[case("level=loud")]
+    #[case("subgraph=")]
+    #[case("service=a")]
+    fn rejects_invalid_log_filters(#[case] input: &str) {
+        assert!(LogFilter::from_str(input).is_err());
+    }
+
+    #[test]
+    fn filters_by_level_and_subgraph() {
+        let filter = LogFilter::from_str("level=info,subgraph=products").unwrap();
+        assert!(filter.allows(Level::ERROR, Some("products")));
+        assert!(filter.allows(Level::INFO, Some("products")));
+        assert!(!filter.allows(Level::DEBUG, Some("products")));
+        assert!(!filter.allows(Level::ERROR, Some("reviews")));
+        assert!(!filter.allows(Level::ERROR, None));
+        assert!(LogFilter::default().allows(Level::WARN, None));
+    }
+
+    #[test]
+    fn rotates_log_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(dir.path().join("router.log")).unwrap();
+        let mut file = RotatingLogFile::new(path.clone(), 10, 2).unwrap();
+        for line in ["first", "second", "third", "fourth"] {
+            file.write_line(line).unwrap();
+        }
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "third\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).unwrap(),
+            "second\n"
+        );
+        assert!(!rotated_path(&path, 3).exists());
+    }
+}
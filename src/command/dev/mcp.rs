@@ -4,7 +4,7 @@ use camino::Utf8PathBuf;
 use clap::Parser;
 use serde::Serialize;
 
-use crate::command::install::McpServerVersion;
+use crate::command::{dev::logs::LogFilter, install::McpServerVersion};
 
 pub mod binary;
 pub mod install;
@@ -33,4 +33,15 @@ pub struct Opts {
     /// You can also use the `APOLLO_ROVER_DEV_MCP_VERSION` environment variable
     #[arg(long = "mcp-version", env = "APOLLO_ROVER_DEV_MCP_VERSION", value_parser = parse_mcp_version)]
     pub version: Option<McpServerVersion>,
+
+    /// Filter the MCP server logs shown in the terminal by level, e.g. `debug`
+    ///
+    /// Tool registrations, operation loads and tool invocation errors are always shown.
+    #[arg(long = "mcp-log-filter", requires = "config")]
+    #[serde(skip_serializing)]
+    pub log_filter: Option<LogFilter>,
+
+    /// Write the MCP server's raw logs to this file, rotating it every 10MB
+    #[arg(long = "mcp-log-file", requires = "config")]
+    pub log_file: Option<Utf8PathBuf>,
 }
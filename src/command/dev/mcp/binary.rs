@@ -1,17 +1,21 @@
 use std::{
     collections::HashMap,
-    fmt,
-    fmt::Formatter,
     io,
     process::{ExitStatus, Stdio},
+    str::FromStr,
+    sync::LazyLock,
 };
 
 use buildstructor::Builder;
 use camino::Utf8PathBuf;
+use console::strip_ansi_codes;
 use futures::TryFutureExt;
+use regex::Regex;
 use rover_std::Style;
 use semver::Version;
+use serde_json::Value;
 use tap::TapFallible;
+use timber::Level;
 use tokio::{
     io::{AsyncBufReadExt, BufReader},
     process::Child,
@@ -20,7 +24,10 @@ use tokio_util::sync::CancellationToken;
 use tower::{Service, ServiceExt};
 
 use crate::{
-    command::dev::router::config::RouterAddress,
+    command::dev::{
+        logs::{ChildProcessLog, LogFilter, StructuredLog},
+        router::{binary::subgraph_name, config::RouterAddress},
+    },
     subtask::SubtaskHandleUnit,
     utils::effect::exec::{ExecCommandConfig, ExecCommandOutput},
 };
@@ -30,16 +37,154 @@ pub enum McpServerLog {
     Stderr(String),
 }
 
-impl fmt::Display for McpServerLog {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// Fields the MCP server uses to name the tool an event is about
+const TOOL_NAME_FIELDS: [&str; 2] = ["tool", "tool_name"];
+
+/// Fields the MCP server uses to count the operations it loaded
+const OPERATION_COUNT_FIELDS: [&str; 2] = ["operation_count", "operations"];
+
+/// Events from the MCP server that are surfaced in the terminal on their own, rather than as
+/// plain log lines. Like every other log, they're only shown when the log filter allows them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum McpServerEvent {
+    ToolRegistered { tool: String },
+    OperationsLoaded,
+    ToolInvocationError { tool: String },
+}
+
+impl McpServerEvent {
+    /// Classifies a JSON log by its structured fields rather than its message, which is free to
+    /// change between MCP server versions. Text logs have no fields, so they're never classified.
+    fn classify(log: &StructuredLog) -> Option<McpServerEvent> {
+        let level = log.level?;
+        // a tool call runs in a span named after the tool, so anything going wrong in it names
+        // the tool even when the event itself doesn't
+        if let Some(tool) = log.field_str(&TOOL_NAME_FIELDS)
+            && level <= Level::WARN
+        {
+            return Some(McpServerEvent::ToolInvocationError {
+                tool: tool.to_string(),
+            });
+        }
+        // while registering, the tool is named on the event itself rather than on a call span
+        if let Some(tool) = TOOL_NAME_FIELDS
+            .iter()
+            .find_map(|name| log.fields.get(*name).and_then(Value::as_str))
+        {
+            return Some(McpServerEvent::ToolRegistered {
+                tool: tool.to_string(),
+            });
+        }
+        if OPERATION_COUNT_FIELDS
+            .iter()
+            .any(|name| log.fields.get(*name).is_some_and(Value::is_number))
+        {
+            return Some(McpServerEvent::OperationsLoaded);
+        }
+        None
+    }
+}
+
+/// Matches a line of `tracing-subscriber`'s default text output, e.g.
+/// `2025-01-01T00:00:00.000000Z  INFO span{a=1}: apollo_mcp_server::server: Starting MCP server`
+static TEXT_LOG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:(\S+)\s+)?(TRACE|DEBUG|INFO|WARN|ERROR)\s+(?:\S+\{[^}]*\}:\s*)*([\w:]+):\s+(.*)$",
+    )
+    .unwrap()
+});
+
+/// Parses a line of text output, for MCP server versions that don't support JSON logs
+fn parse_text_log(line: &str) -> Option<StructuredLog> {
+    let line = strip_ansi_codes(line);
+    let captures = TEXT_LOG_REGEX.captures(line.trim())?;
+    Some(StructuredLog {
+        timestamp: captures.get(1).map(|m| m.as_str().to_string()),
+        level: captures
+            .get(2)
+            .and_then(|m| Level::from_str(m.as_str()).ok()),
+        target: captures.get(3).map(|m| m.as_str().to_string()),
+        message: captures.get(4)?.as_str().to_string(),
+        fields: Default::default(),
+        spans: Vec::new(),
+    })
+}
+
+impl ChildProcessLog for McpServerLog {
+    fn raw(&self) -> &str {
+        match self {
+            Self::Stdout(line) | Self::Stderr(line) => line,
+        }
+    }
+
+    /// Parses the MCP server's logs, which are JSON unless the server is too old to support it,
+    /// in which case they're `tracing-subscriber`'s default text format.
+    fn parse(&self) -> Option<StructuredLog> {
+        match self {
+            Self::Stdout(stdout) => StructuredLog::parse(stdout).or_else(|| parse_text_log(stdout)),
+            Self::Stderr(_) => None,
+        }
+    }
+
+    fn render(&self, filter: &LogFilter) -> Option<String> {
         match self {
             Self::Stdout(stdout) => {
-                // TODO: add a JSON output option to the MCP Server so we can parse it
-                write!(f, "{}", &stdout)
-            }
-            Self::Stderr(stderr) => {
-                write!(f, "{} {}", Style::ErrorPrefix.paint("ERROR:"), &stderr)
+                let Some(log) = self.parse() else {
+                    return Some(stdout.to_string());
+                };
+                let message = &log.message;
+                let Some(level) = log.level else {
+                    return Some(message.to_string());
+                };
+                // the MCP server's logs are rarely about a subgraph, so the filter's subgraph
+                // names only apply to the ones that are
+                let allowed = match subgraph_name(&log) {
+                    Some(subgraph) => filter.allows(level, Some(subgraph)),
+                    None => level <= filter.level(),
+                };
+                if !allowed {
+                    match level {
+                        Level::ERROR => tracing::error!(%message),
+                        Level::WARN => tracing::warn!(%message),
+                        Level::INFO => tracing::info!(%message),
+                        Level::DEBUG => tracing::debug!(%message),
+                        _ => tracing::trace!(%message),
+                    }
+                    return None;
+                }
+                Some(match McpServerEvent::classify(&log) {
+                    Some(McpServerEvent::ToolRegistered { tool }) => format!(
+                        "{} {}",
+                        Style::InfoPrefix.paint("MCP TOOL REGISTERED:"),
+                        tool
+                    ),
+                    Some(McpServerEvent::OperationsLoaded) => format!(
+                        "{} {}",
+                        Style::InfoPrefix.paint("MCP OPERATIONS LOADED:"),
+                        message
+                    ),
+                    Some(McpServerEvent::ToolInvocationError { tool }) => format!(
+                        "{} [{}] {}",
+                        Style::ErrorPrefix.paint("MCP TOOL ERROR:"),
+                        tool,
+                        message
+                    ),
+                    None => {
+                        let prefix = match level {
+                            Level::ERROR => Style::ErrorPrefix.paint("ERROR:"),
+                            Level::WARN => Style::WarningPrefix.paint("WARN:"),
+                            Level::INFO => Style::InfoPrefix.paint("INFO:"),
+                            _ => Style::DebugPrefix.paint(format!("{level}:")),
+                        };
+                        format!("{prefix} {message}")
+                    }
+                })
             }
+            Self::Stderr(stderr) => Some(format!(
+                "{} {}",
+                Style::ErrorPrefix.paint("ERROR:"),
+                &stderr
+            )),
         }
     }
 }
@@ -80,6 +225,7 @@ pub struct RunMcpServerBinary<Spawn: Send> {
     router_address: RouterAddress,
     router_url_path: Option<String>,
     mcp_config_path: Option<Utf8PathBuf>,
+    log_level: Option<Level>,
     env: HashMap<String, String>,
 }
 
@@ -95,7 +241,7 @@ impl<Spawn: Send> RunMcpServerBinary<Spawn> {
             self.router_address.pretty_string()
         };
 
-        let overlaid = HashMap::from([
+        let mut overlaid = HashMap::from([
             // Configure the schema to be a local file
             ("APOLLO_MCP_SCHEMA__SOURCE".to_string(), "local".to_string()),
             (
@@ -104,11 +250,19 @@ impl<Spawn: Send> RunMcpServerBinary<Spawn> {
             ),
             // Configure the endpoint from the running router instance
             ("APOLLO_MCP_ENDPOINT".to_string(), endpoint),
+            // JSON logs carry fields like the tool name, which the text format leaves out
+            ("APOLLO_MCP_LOGGING__FORMAT".to_string(), "json".to_string()),
             (
                 "APOLLO_MCP_TRANSPORT__TYPE".to_string(),
                 "streamable_http".to_string(),
             ),
         ]);
+        if let Some(log_level) = self.log_level {
+            overlaid.insert(
+                "APOLLO_MCP_LOGGING__LEVEL".to_string(),
+                log_level.to_string().to_lowercase(),
+            );
+        }
 
         // We don't want the user's env possibly conflicting with what rover dev has configured,
         // so we overlay rover's configuration over the user's env.
@@ -401,4 +555,157 @@ mod tests {
 
         assert_eq!(endpoint, "http://localhost:4000");
     }
+
+    #[test]
+    fn test_mcp_log_level_is_passed_to_the_server() {
+        let binary = McpServerBinary::new(
+            Utf8PathBuf::from("/fake/path"),
+            Version::parse("1.0.0").unwrap(),
+        );
+
+        let runner = RunMcpServerBinary::<MockSpawn>::builder()
+            .mcp_server_binary(binary)
+            .supergraph_schema_path(Utf8PathBuf::from("/fake/schema.graphql"))
+            .spawn(MockSpawn)
+            .router_address(RouterAddress::default())
+            .log_level(Level::DEBUG)
+            .env(HashMap::new())
+            .build();
+
+        let env = runner.opts_into_env();
+        assert_eq!(env.get("APOLLO_MCP_LOGGING__LEVEL").unwrap(), "debug");
+    }
+
+    #[test]
+    fn test_parses_text_logs() {
+        let log = McpServerLog::Stdout(
+            "2025-01-01T00:00:00.000000Z  WARN apollo_mcp_server::server: Failed to call tool GetUser"
+                .to_string(),
+        );
+        let parsed = log.parse().unwrap();
+        assert_eq!(parsed.level, Some(Level::WARN));
+        assert_eq!(parsed.target.as_deref(), Some("apollo_mcp_server::server"));
+        assert_eq!(parsed.message, "Failed to call tool GetUser");
+        // without structured fields there's nothing to classify it by
+        assert_eq!(McpServerEvent::classify(&parsed), None);
+    }
+
+    #[test]
+    fn test_classifies_mcp_events_by_their_fields() {
+        let classify = |log: serde_json::Value| {
+            McpServerEvent::classify(&StructuredLog::parse(&log.to_string()).unwrap())
+        };
+        assert_eq!(
+            classify(serde_json::json!({
+                "level": "INFO",
+                "fields": { "message": "Tool is ready", "tool": "GetUser" }
+            })),
+            Some(McpServerEvent::ToolRegistered {
+                tool: "GetUser".to_string()
+            })
+        );
+        assert_eq!(
+            classify(serde_json::json!({
+                "level": "ERROR",
+                "fields": { "message": "Request failed" },
+                "spans": [{ "name": "call_tool", "tool": "GetUser" }]
+            })),
+            Some(McpServerEvent::ToolInvocationError {
+                tool: "GetUser".to_string()
+            })
+        );
+        assert_eq!(
+            classify(serde_json::json!({
+                "level": "INFO",
+                "fields": { "message": "Ready", "operation_count": 3 }
+            })),
+            Some(McpServerEvent::OperationsLoaded)
+        );
+        // an info log during a tool call is neither a registration nor an error
+        assert_eq!(
+            classify(serde_json::json!({
+                "level": "INFO",
+                "fields": { "message": "Tool registered and operations loaded" },
+                "spans": [{ "name": "call_tool", "tool": "GetUser" }]
+            })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_filters_mcp_events_by_level() {
+        let registered = McpServerLog::Stdout(
+            serde_json::json!({
+                "level": "INFO",
+                "message": "Tool registered",
+                "tool": "GetUser"
+            })
+            .to_string(),
+        );
+        assert!(registered.render(&LogFilter::default()).is_none());
+        let rendered = registered
+            .render(&LogFilter::from_str("info").unwrap())
+            .unwrap();
+        assert!(rendered.contains("MCP TOOL REGISTERED:"));
+        assert!(rendered.contains("GetUser"));
+
+        let failed = McpServerLog::Stdout(
+            serde_json::json!({
+                "level": "ERROR",
+                "message": "Request failed",
+                "tool": "GetUser"
+            })
+            .to_string(),
+        );
+        assert!(
+            failed
+                .render(&LogFilter::default())
+                .unwrap()
+                .contains("MCP TOOL ERROR:")
+        );
+
+        let other =
+            McpServerLog::Stdout("INFO apollo_mcp_server::server: Starting MCP server".to_string());
+        assert!(other.render(&LogFilter::default()).is_none());
+        assert!(
+            other
+                .render(&LogFilter::from_str("info").unwrap())
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_mcp_logs_as_json() {
+        let runner = RunMcpServerBinary::<MockSpawn>::builder()
+            .mcp_server_binary(McpServerBinary::new(
+                Utf8PathBuf::from("/fake/path"),
+                Version::parse("1.0.0").unwrap(),
+            ))
+            .supergraph_schema_path(Utf8PathBuf::from("/fake/schema.graphql"))
+            .spawn(MockSpawn)
+            .router_address(RouterAddress::default())
+            .env(HashMap::new())
+            .build();
+
+        let env = runner.opts_into_env();
+        assert_eq!(env.get("APOLLO_MCP_LOGGING__FORMAT").unwrap(), "json");
+    }
+
+    #[test]
+    fn test_subgraph_filter_only_applies_to_attributed_logs() {
+        let filter = LogFilter::from_str("level=info,subgraph=products").unwrap();
+        let unattributed =
+            McpServerLog::Stdout("INFO apollo_mcp_server::server: Starting MCP server".to_string());
+        assert!(unattributed.render(&filter).is_some());
+
+        let other_subgraph = McpServerLog::Stdout(
+            serde_json::json!({
+                "level": "INFO",
+                "message": "Fetched from subgraph",
+                "subgraph": "reviews"
+            })
+            .to_string(),
+        );
+        assert!(other_subgraph.render(&filter).is_none());
+    }
 }
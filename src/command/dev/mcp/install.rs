@@ -116,7 +116,7 @@ mod tests {
     use super::InstallMcpServer;
     use crate::{
         command::install::McpServerVersion,
-        options::LicenseAccepter,
+        options::{LicenseAccepter, RetryOpts},
         utils::{
             client::{ClientBuilder, ClientTimeout, StudioClientConfig},
             effect::install::InstallBinary,
@@ -136,13 +136,15 @@ mod tests {
         let config = Config {
             home: Utf8PathBuf::from_path_buf(TempDir::new().unwrap().to_path_buf()).unwrap(),
             override_api_key: Some("api-key".to_string()),
+            credential_passphrase: None,
         };
         let studio_client_config = StudioClientConfig::new(
             Some(mock_server_endpoint.to_string()),
             config,
             false,
             ClientBuilder::default(),
-            ClientTimeout::default(),
+            Some(ClientTimeout::default()),
+            RetryOpts::default(),
         );
         let override_install_path = NamedTempFile::new("override_path")?;
         let install_mcp_server =
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use camino::Utf8PathBuf;
+use timber::Level;
 use tokio::process::Child;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tokio_util::sync::CancellationToken;
@@ -52,6 +53,7 @@ impl RunMcpServer<state::Install> {
 }
 
 impl RunMcpServer<state::Run> {
+    #[allow(clippy::too_many_arguments)]
     pub async fn run<Spawn>(
         self,
         spawn: Spawn,
@@ -59,6 +61,7 @@ impl RunMcpServer<state::Run> {
         router_address: RouterAddress,
         router_url_path: Option<String>,
         mcp_config_path: Option<Utf8PathBuf>,
+        log_level: Option<Level>,
         env: HashMap<String, String>,
     ) -> Result<RunMcpServer<state::Abort>, RunMcpServerBinaryError>
     where
@@ -73,6 +76,7 @@ impl RunMcpServer<state::Run> {
             .router_address(router_address)
             .and_router_url_path(router_url_path)
             .and_mcp_config_path(mcp_config_path)
+            .and_log_level(log_level)
             .env(env)
             .build();
 
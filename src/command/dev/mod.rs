@@ -6,6 +6,8 @@ use camino::Utf8PathBuf;
 #[cfg(feature = "composition-js")]
 mod do_dev;
 #[cfg(feature = "composition-js")]
+mod logs;
+#[cfg(feature = "composition-js")]
 mod mcp;
 #[cfg(not(feature = "composition-js"))]
 mod no_dev;
@@ -46,6 +48,14 @@ pub struct DevOpts {
     #[cfg(feature = "composition-js")]
     #[clap(flatten)]
     pub mcp: mcp::Opts,
+
+    #[cfg(feature = "composition-js")]
+    #[clap(flatten)]
+    pub replay: router::replay::ReplayOpts,
+
+    #[cfg(feature = "composition-js")]
+    #[clap(flatten)]
+    pub router_logs: logs::RouterLogOpts,
 }
 
 #[derive(Debug, Parser, Serialize, Clone, Getters)]
@@ -73,6 +83,18 @@ pub struct SupergraphOpts {
     #[serde(skip_serializing)]
     router_config_path: Option<Utf8PathBuf>,
 
+    /// The path to a routers file, for running several routers side-by-side. Each router in the
+    /// file gets its own port, router configuration and, optionally, its own subset of the
+    /// supergraph's subgraphs to compose.
+    ///
+    /// Cannot be used with `--supergraph-port`, `--supergraph-address`, or `--router-config`.
+    #[arg(
+        long = "routers",
+        conflicts_with_all = ["supergraph_port", "supergraph_address", "router_config_path"]
+    )]
+    #[serde(skip_serializing)]
+    routers_config_path: Option<Utf8PathBuf>,
+
     /// The path to a supergraph configuration file. If provided, subgraphs will be loaded from this
     /// file.
     ///
@@ -1,7 +1,5 @@
 use std::{
     collections::HashMap,
-    fmt,
-    fmt::Formatter,
     io,
     net::{AddrParseError, SocketAddr},
     process::{ExitStatus, Stdio},
@@ -11,7 +9,7 @@ use buildstructor::Builder;
 use camino::Utf8PathBuf;
 use futures::TryFutureExt;
 use regex::Regex;
-use rover_std::{Style, infoln};
+use rover_std::Style;
 use semver::Version;
 use tap::TapFallible;
 use timber::Level;
@@ -23,7 +21,10 @@ use tokio_util::sync::CancellationToken;
 use tower::{Service, ServiceExt};
 
 use crate::{
-    command::dev::router::config::{RouterAddress, RouterHost, RouterPort},
+    command::dev::{
+        logs::{ChildProcessLog, LogFilter, StructuredLog},
+        router::config::{RouterAddress, RouterHost, RouterPort},
+    },
     subtask::SubtaskHandleUnit,
     utils::effect::exec::{ExecCommandConfig, ExecCommandOutput},
 };
@@ -33,6 +34,43 @@ pub enum RouterLog {
     Stderr(String),
 }
 
+/// Span and event fields the router uses to attribute a log to a subgraph, depending on its
+/// version
+const SUBGRAPH_NAME_FIELDS: [&str; 3] = ["apollo.subgraph.name", "subgraph.name", "subgraph"];
+
+/// Router logs that are worth calling out in the terminal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RouterLogKind {
+    QueryPlanningError,
+    SubgraphFetchError,
+    Other,
+}
+
+impl RouterLogKind {
+    fn classify(log: &StructuredLog) -> RouterLogKind {
+        if !matches!(log.level, Some(Level::WARN) | Some(Level::ERROR)) {
+            return RouterLogKind::Other;
+        }
+        let target = log.target.as_deref().unwrap_or_default();
+        let message = log.message.to_lowercase();
+        if target.contains("query_planner") || message.contains("query planning") {
+            RouterLogKind::QueryPlanningError
+        } else if target.contains("subgraph_service")
+            || message.contains("subgraph request")
+            || message.contains("fetch failed")
+            || message.contains("fetch error")
+        {
+            RouterLogKind::SubgraphFetchError
+        } else {
+            RouterLogKind::Other
+        }
+    }
+}
+
+pub(crate) fn subgraph_name(log: &StructuredLog) -> Option<&str> {
+    log.field_str(&SUBGRAPH_NAME_FIELDS)
+}
+
 fn should_select_log_message(log_message: &str) -> bool {
     // For most info-level messages, we want to pipe them to tracing only.
     // However, a few info-level messages (e.g. for confirming that the router started up)
@@ -44,7 +82,7 @@ fn should_select_log_message(log_message: &str) -> bool {
     log_message.matches("exposed at http").next().is_some()
 }
 
-fn produce_special_message(raw_message: &str) {
+fn produce_special_message(raw_message: &str) -> String {
     let starting_message_regex = Regex::new(r"^.*\s+.*://(.*:[0-9]+).*\s+.*").unwrap();
 
     let contents = match starting_message_regex.captures(raw_message) {
@@ -67,48 +105,74 @@ fn produce_special_message(raw_message: &str) {
             }
         }
     };
-    infoln!("{}", contents)
+    format!("{} {}", Style::InfoPrefix.paint("==>"), contents)
 }
 
-impl fmt::Display for RouterLog {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl ChildProcessLog for RouterLog {
+    fn raw(&self) -> &str {
+        match self {
+            Self::Stdout(line) | Self::Stderr(line) => line,
+        }
+    }
+
+    /// Parses the router's JSON log output. Only stdout is structured; stderr lines are always
+    /// passed through as-is.
+    fn parse(&self) -> Option<StructuredLog> {
+        match self {
+            Self::Stdout(stdout) => StructuredLog::parse(stdout),
+            Self::Stderr(_) => None,
+        }
+    }
+
+    fn render(&self, filter: &LogFilter) -> Option<String> {
         let warn_prefix = Style::WarningPrefix.paint("WARN:");
         let error_prefix = Style::ErrorPrefix.paint("ERROR:");
         let unknown_prefix = Style::ErrorPrefix.paint("UNKNOWN:");
         match self {
             Self::Stdout(stdout) => {
-                if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(stdout) {
-                    let fields = &parsed["fields"];
-                    let level = parsed["level"].as_str().unwrap_or("UNKNOWN");
-                    let message = fields["message"]
-                        .as_str()
-                        .or_else(|| {
-                            // Message is in a slightly different location depending on the
-                            // version of Router
-                            parsed["message"].as_str()
-                        })
-                        .unwrap_or(stdout);
+                let Some(log) = self.parse() else {
+                    return Some(format!("{} {}", warn_prefix, &stdout));
+                };
+                let message = &log.message;
+                let Some(level) = log.level else {
+                    return Some(format!("{} {}", unknown_prefix, message));
+                };
+                if level == Level::INFO && should_select_log_message(message) {
+                    return Some(produce_special_message(message));
+                }
 
+                let subgraph = subgraph_name(&log);
+                if !filter.allows(level, subgraph) {
                     match level {
-                        "INFO" if should_select_log_message(message) => {
-                            produce_special_message(message);
-                        }
-                        "INFO" => tracing::info!(%message),
-                        "DEBUG" => tracing::debug!(%message),
-                        "TRACE" => tracing::trace!(%message),
-                        "WARN" => write!(f, "{} {}", warn_prefix, &message)?,
-                        "ERROR" => write!(f, "{} {}", error_prefix, &message)?,
-                        "UNKNOWN" => write!(f, "{} {}", unknown_prefix, &message)?,
-                        _ => write!(f, "{} {}", unknown_prefix, &message)?,
-                    };
-                    Ok(())
-                } else {
-                    write!(f, "{} {}", warn_prefix, &stdout)
+                        Level::ERROR => tracing::error!(%message),
+                        Level::WARN => tracing::warn!(%message),
+                        Level::INFO => tracing::info!(%message),
+                        Level::DEBUG => tracing::debug!(%message),
+                        _ => tracing::trace!(%message),
+                    }
+                    return None;
                 }
+
+                let prefix = match RouterLogKind::classify(&log) {
+                    RouterLogKind::QueryPlanningError => {
+                        Style::ErrorPrefix.paint("QUERY PLANNING ERROR:")
+                    }
+                    RouterLogKind::SubgraphFetchError => {
+                        Style::ErrorPrefix.paint("SUBGRAPH FETCH ERROR:")
+                    }
+                    RouterLogKind::Other => match level {
+                        Level::ERROR => error_prefix,
+                        Level::WARN => warn_prefix,
+                        Level::INFO => Style::InfoPrefix.paint("INFO:"),
+                        _ => Style::DebugPrefix.paint(format!("{level}:")),
+                    },
+                };
+                Some(match subgraph {
+                    Some(subgraph) => format!("{prefix} [{subgraph}] {message}"),
+                    None => format!("{prefix} {message}"),
+                })
             }
-            Self::Stderr(stderr) => {
-                write!(f, "{} {}", error_prefix, &stderr)
-            }
+            Self::Stderr(stderr) => Some(format!("{} {}", error_prefix, &stderr)),
         }
     }
 }
@@ -300,3 +364,77 @@ where
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    fn stdout(log: serde_json::Value) -> RouterLog {
+        RouterLog::Stdout(log.to_string())
+    }
+
+    #[rstest]
+    #[case::query_planning(
+        json!({ "level": "ERROR", "target": "apollo_router::query_planner", "message": "boom" }),
+        RouterLogKind::QueryPlanningError
+    )]
+    #[case::subgraph_fetch(
+        json!({ "level": "WARN", "target": "apollo_router::services::subgraph_service", "message": "boom" }),
+        RouterLogKind::SubgraphFetchError
+    )]
+    #[case::fetch_message(
+        json!({ "level": "ERROR", "target": "apollo_router", "message": "HTTP fetch failed from 'products'" }),
+        RouterLogKind::SubgraphFetchError
+    )]
+    #[case::info_is_never_highlighted(
+        json!({ "level": "INFO", "target": "apollo_router::query_planner", "message": "planned" }),
+        RouterLogKind::Other
+    )]
+    fn classifies_router_logs(#[case] log: serde_json::Value, #[case] kind: RouterLogKind) {
+        let log = StructuredLog::parse(&log.to_string()).unwrap();
+        assert_eq!(RouterLogKind::classify(&log), kind);
+    }
+
+    #[test]
+    fn renders_highlighted_logs_with_their_subgraph() {
+        let log = stdout(json!({
+            "level": "ERROR",
+            "target": "apollo_router::services::subgraph_service",
+            "fields": { "message": "connection refused" },
+            "spans": [{ "name": "subgraph", "apollo.subgraph.name": "products" }]
+        }));
+        let rendered = log.render(&LogFilter::default()).unwrap();
+        assert!(rendered.contains("SUBGRAPH FETCH ERROR:"));
+        assert!(rendered.contains("[products] connection refused"));
+    }
+
+    #[test]
+    fn filters_logs_by_level_and_subgraph() {
+        let info = stdout(json!({ "level": "INFO", "message": "hello" }));
+        assert!(info.render(&LogFilter::default()).is_none());
+        assert!(info.render(&LogFilter::from_str("info").unwrap()).is_some());
+
+        let reviews = stdout(json!({ "level": "ERROR", "message": "oops", "subgraph": "reviews" }));
+        let filter = LogFilter::from_str("subgraph=products").unwrap();
+        assert!(reviews.render(&filter).is_none());
+        assert!(reviews.render(&LogFilter::default()).is_some());
+    }
+
+    #[test]
+    fn passes_through_unstructured_logs() {
+        let log = RouterLog::Stdout("not json".to_string());
+        assert!(
+            log.render(&LogFilter::default())
+                .unwrap()
+                .contains("not json")
+        );
+        assert_eq!(log.raw(), "not json");
+        let log = RouterLog::Stderr("panic".to_string());
+        assert!(log.render(&LogFilter::default()).unwrap().contains("panic"));
+    }
+}
@@ -96,7 +96,7 @@ pub enum RouterPort {
 }
 
 impl RouterPort {
-    const fn get_port(&self) -> u16 {
+    pub(crate) const fn get_port(&self) -> u16 {
         match self {
             RouterPort::CliOption(port)
             | RouterPort::ConfigFile(port)
@@ -285,6 +285,20 @@ impl RunRouterConfig<RunRouterConfigFinal> {
         self.state.health_check_path.clone()
     }
 
+    /// The ports the router will listen on: the supergraph's, and the health check's if it's
+    /// enabled
+    pub fn listen_ports(&self) -> Vec<u16> {
+        let mut ports = vec![self.state.address.port().get_port()];
+        if let Some(health_check_endpoint) = self
+            .state
+            .health_check_endpoint
+            .filter(|_| self.state.health_check_enabled)
+        {
+            ports.push(health_check_endpoint.port());
+        }
+        ports
+    }
+
     pub fn raw_config(&self) -> String {
         self.state.raw_config.clone()
     }
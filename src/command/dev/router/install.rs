@@ -111,7 +111,7 @@ mod tests {
 
     use super::InstallRouter;
     use crate::{
-        options::LicenseAccepter,
+        options::{LicenseAccepter, RetryOpts},
         utils::{
             client::{ClientBuilder, ClientTimeout, StudioClientConfig},
             effect::install::InstallBinary,
@@ -130,10 +130,12 @@ mod tests {
             Config {
                 home: Utf8PathBuf::from_path_buf(TempDir::new().unwrap().to_path_buf()).unwrap(),
                 override_api_key: Some("api-key".to_string()),
+                credential_passphrase: None,
             },
             false,
             ClientBuilder::default(),
-            ClientTimeout::default(),
+            Some(ClientTimeout::default()),
+            RetryOpts::default(),
         );
         let license_accepter = LicenseAccepter {
             elv2_license_accepted: Some(true),
@@ -2,5 +2,7 @@ pub mod binary;
 pub mod config;
 pub mod hot_reload;
 pub mod install;
+pub mod replay;
+pub mod routers;
 pub mod run;
 pub mod watchers;
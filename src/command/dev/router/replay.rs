@@ -0,0 +1,579 @@
+use std::time::{Duration, Instant};
+
+use camino::Utf8PathBuf;
+use clap::Parser;
+use comfy_table::Cell;
+use reqwest::Client;
+use rover_client::operations::persisted_queries::publish::ApolloPersistedQueryManifest;
+use rover_std::{Fs, RoverStdError, Style};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::utils::table;
+
+#[derive(Debug, Clone, Serialize, Parser)]
+pub struct ReplayOpts {
+    /// Replay the operations in this file against the router once it reports healthy
+    ///
+    /// The file can be a JSON array of GraphQL requests (`query`, `operationName` and
+    /// `variables`), one GraphQL request per line, or a persisted query manifest.
+    #[arg(long = "replay")]
+    pub operations_path: Option<Utf8PathBuf>,
+
+    /// Compare replayed responses against a saved baseline
+    ///
+    /// If the file doesn't exist yet, the replayed responses are recorded to it instead.
+    #[arg(long = "replay-baseline", requires = "operations_path")]
+    pub baseline_path: Option<Utf8PathBuf>,
+}
+
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    #[error("Failed to read replay file at {}", .path)]
+    ReadFile {
+        path: Utf8PathBuf,
+        source: RoverStdError,
+    },
+    #[error(
+        "{} is neither a list of GraphQL requests nor a persisted query manifest: {}",
+        .path,
+        .source
+    )]
+    Parse {
+        path: Utf8PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("{} is not a valid replay baseline: {}", .path, .source)]
+    Baseline {
+        path: Utf8PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("Failed to write replay baseline to {}", .path)]
+    WriteBaseline {
+        path: Utf8PathBuf,
+        source: RoverStdError,
+    },
+}
+
+/// A single GraphQL request as it would be sent over the wire to the router.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayOperation {
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Value>,
+}
+
+impl ReplayOperation {
+    fn name(&self) -> &str {
+        self.operation_name.as_deref().unwrap_or("<anonymous>")
+    }
+
+    fn label(&self, index: usize) -> String {
+        format!("#{} {}", index + 1, self.name())
+    }
+
+    /// Identifies the operation in a baseline, so that adding or removing operations from the
+    /// replay file doesn't change which response each one is compared against
+    fn baseline_key(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.query.as_bytes());
+        if let Some(variables) = &self.variables {
+            hasher.update(variables.to_string().as_bytes());
+        }
+        let hash: String = hasher
+            .finalize()
+            .iter()
+            .take(8)
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        format!("{} {hash}", self.name())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ReplayFile {
+    Manifest(ApolloPersistedQueryManifest),
+    Operations(Vec<ReplayOperation>),
+}
+
+impl From<ReplayFile> for Vec<ReplayOperation> {
+    fn from(value: ReplayFile) -> Self {
+        match value {
+            ReplayFile::Manifest(manifest) => manifest
+                .operations
+                .into_iter()
+                .map(|operation| ReplayOperation {
+                    query: operation.body,
+                    operation_name: Some(operation.name),
+                    variables: None,
+                })
+                .collect(),
+            ReplayFile::Operations(operations) => operations,
+        }
+    }
+}
+
+/// Parses the contents of a replay file.
+///
+/// Accepts a JSON array of GraphQL requests, newline-delimited GraphQL requests (as captured
+/// from router traffic), or an Apollo persisted query manifest.
+pub fn parse_replay_file(contents: &str) -> Result<Vec<ReplayOperation>, serde_json::Error> {
+    match serde_json::from_str::<ReplayFile>(contents) {
+        Ok(file) => Ok(file.into()),
+        Err(err) => {
+            let mut lines = contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .peekable();
+            if lines.peek().is_none() {
+                return Err(err);
+            }
+            lines.map(serde_json::from_str).collect()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// The operation's name and a hash of its query and variables
+    pub operation: String,
+    pub response: Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BaselineComparison {
+    Recorded,
+    Matches,
+    Differs(Vec<String>),
+    Missing,
+}
+
+#[derive(Debug)]
+pub struct ReplayResult {
+    pub operation: String,
+    pub baseline_key: String,
+    pub status: Option<u16>,
+    pub errors: Vec<String>,
+    pub latency: Duration,
+    pub response: Option<Value>,
+    pub baseline: Option<BaselineComparison>,
+}
+
+impl ReplayResult {
+    fn is_success(&self) -> bool {
+        self.errors.is_empty()
+            && self
+                .status
+                .is_some_and(|status| (200..300).contains(&status))
+            && !matches!(
+                self.baseline,
+                Some(BaselineComparison::Differs(_)) | Some(BaselineComparison::Missing)
+            )
+    }
+}
+
+/// Replays a set of recorded operations against the local router once it's healthy.
+pub struct Replay {
+    operations: Vec<ReplayOperation>,
+    baseline: Option<Vec<BaselineEntry>>,
+    baseline_path: Option<Utf8PathBuf>,
+}
+
+impl Replay {
+    /// Reads the replay file and the baseline, if there is one, so that a bad file is reported
+    /// before any routers are started.
+    pub fn load(
+        operations_path: Utf8PathBuf,
+        baseline_path: Option<Utf8PathBuf>,
+    ) -> Result<Replay, ReplayError> {
+        let contents = Fs::read_file(&operations_path).map_err(|source| ReplayError::ReadFile {
+            path: operations_path.clone(),
+            source,
+        })?;
+        let operations = parse_replay_file(&contents).map_err(|source| ReplayError::Parse {
+            path: operations_path.clone(),
+            source,
+        })?;
+        let baseline = read_baseline(baseline_path.as_ref())?;
+        Ok(Replay {
+            operations,
+            baseline,
+            baseline_path,
+        })
+    }
+
+    /// Sends every operation in the replay file to `endpoint`, comparing responses to the
+    /// baseline if one exists, or recording a new baseline if the baseline file is missing.
+    pub async fn run(
+        &self,
+        client: &Client,
+        endpoint: &str,
+    ) -> Result<Vec<ReplayResult>, ReplayError> {
+        let mut results = Vec::with_capacity(self.operations.len());
+        for (index, operation) in self.operations.iter().enumerate() {
+            let mut result = send_operation(client, endpoint, operation, index).await;
+            if let Some(baseline) = &self.baseline {
+                result.baseline = Some(compare_to_baseline(baseline, &result));
+            }
+            results.push(result);
+        }
+
+        if self.baseline.is_none() {
+            self.write_baseline(&mut results)?;
+        }
+
+        Ok(results)
+    }
+
+    fn write_baseline(&self, results: &mut [ReplayResult]) -> Result<(), ReplayError> {
+        let Some(path) = &self.baseline_path else {
+            return Ok(());
+        };
+        let entries: Vec<BaselineEntry> = results
+            .iter()
+            .filter_map(|result| {
+                result.response.clone().map(|response| BaselineEntry {
+                    operation: result.baseline_key.clone(),
+                    response,
+                })
+            })
+            .collect();
+        let contents =
+            serde_json::to_string_pretty(&entries).map_err(|source| ReplayError::Baseline {
+                path: path.clone(),
+                source,
+            })?;
+        Fs::write_file(path, contents).map_err(|source| ReplayError::WriteBaseline {
+            path: path.clone(),
+            source,
+        })?;
+        for result in results.iter_mut() {
+            result.baseline = Some(BaselineComparison::Recorded);
+        }
+        Ok(())
+    }
+}
+
+fn read_baseline(path: Option<&Utf8PathBuf>) -> Result<Option<Vec<BaselineEntry>>, ReplayError> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = Fs::read_file(path).map_err(|source| ReplayError::ReadFile {
+        path: path.clone(),
+        source,
+    })?;
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|source| ReplayError::Baseline {
+            path: path.clone(),
+            source,
+        })
+}
+
+fn compare_to_baseline(baseline: &[BaselineEntry], result: &ReplayResult) -> BaselineComparison {
+    let Some(entry) = baseline
+        .iter()
+        .find(|entry| entry.operation == result.baseline_key)
+    else {
+        return BaselineComparison::Missing;
+    };
+    match &result.response {
+        Some(response) => {
+            let differences = diff_json(&entry.response, response);
+            if differences.is_empty() {
+                BaselineComparison::Matches
+            } else {
+                BaselineComparison::Differs(differences)
+            }
+        }
+        None => BaselineComparison::Differs(vec!["<no response>".to_string()]),
+    }
+}
+
+async fn send_operation(
+    client: &Client,
+    endpoint: &str,
+    operation: &ReplayOperation,
+    index: usize,
+) -> ReplayResult {
+    let label = operation.label(index);
+    let baseline_key = operation.baseline_key();
+    let started = Instant::now();
+    let response = client.post(endpoint).json(operation).send().await;
+    match response {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            let body = response.json::<Value>().await;
+            let latency = started.elapsed();
+            match body {
+                Ok(body) => {
+                    let errors = body["errors"]
+                        .as_array()
+                        .map(|errors| {
+                            errors
+                                .iter()
+                                .map(|error| {
+                                    error["message"]
+                                        .as_str()
+                                        .map(str::to_string)
+                                        .unwrap_or_else(|| error.to_string())
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    ReplayResult {
+                        operation: label,
+                        baseline_key,
+                        status: Some(status),
+                        errors,
+                        latency,
+                        response: Some(body),
+                        baseline: None,
+                    }
+                }
+                Err(err) => ReplayResult {
+                    operation: label,
+                    baseline_key,
+                    status: Some(status),
+                    errors: vec![format!("Response was not valid JSON: {err}")],
+                    latency,
+                    response: None,
+                    baseline: None,
+                },
+            }
+        }
+        Err(err) => ReplayResult {
+            operation: label,
+            baseline_key,
+            status: None,
+            errors: vec![err.to_string()],
+            latency: started.elapsed(),
+            response: None,
+            baseline: None,
+        },
+    }
+}
+
+/// Returns the JSON paths at which `expected` and `actual` differ.
+pub fn diff_json(expected: &Value, actual: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    diff_json_at("$", expected, actual, &mut differences);
+    differences
+}
+
+fn diff_json_at(path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected_value) in expected {
+                let child = format!("{path}.{key}");
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        diff_json_at(&child, expected_value, actual_value, differences)
+                    }
+                    None => differences.push(child),
+                }
+            }
+            for key in actual.keys().filter(|key| !expected.contains_key(*key)) {
+                differences.push(format!("{path}.{key}"));
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                diff_json_at(&format!("{path}[{index}]"), expected, actual, differences);
+            }
+        }
+        (expected, actual) if expected != actual => differences.push(path.to_string()),
+        _ => {}
+    }
+}
+
+/// Renders the results of a replay as a table, followed by a one-line summary.
+pub fn format_replay_results(results: &[ReplayResult]) -> String {
+    let mut table = table::get_table();
+    table.set_header(vec!["Operation", "Status", "Latency", "Errors", "Baseline"]);
+    for result in results {
+        let status = result
+            .status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let baseline = match &result.baseline {
+            None => String::new(),
+            Some(BaselineComparison::Recorded) => "recorded".to_string(),
+            Some(BaselineComparison::Matches) => "matches".to_string(),
+            Some(BaselineComparison::Missing) => "not in baseline".to_string(),
+            Some(BaselineComparison::Differs(paths)) => format!("differs at {}", paths.join(", ")),
+        };
+        table.add_row(vec![
+            Cell::new(&result.operation),
+            Cell::new(status),
+            Cell::new(format!("{}ms", result.latency.as_millis())),
+            Cell::new(result.errors.join("\n")),
+            Cell::new(baseline),
+        ]);
+    }
+
+    let failed = results.iter().filter(|result| !result.is_success()).count();
+    let summary = if failed == 0 {
+        Style::Success.paint(format!(
+            "Replayed {} operations successfully",
+            results.len()
+        ))
+    } else {
+        Style::Failure.paint(format!(
+            "Replayed {} operations, {} failed",
+            results.len(),
+            failed
+        ))
+    };
+    format!("{table}\n{summary}")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_a_list_of_requests() {
+        let contents = json!([
+            { "query": "{ me { id } }" },
+            { "query": "query Q($id: ID!) { user(id: $id) { id } }", "operationName": "Q", "variables": { "id": "1" } }
+        ])
+        .to_string();
+        let operations = parse_replay_file(&contents).unwrap();
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[1].operation_name, Some("Q".to_string()));
+        assert_eq!(operations[1].variables, Some(json!({ "id": "1" })));
+    }
+
+    #[test]
+    fn parses_newline_delimited_requests() {
+        let contents = "{\"query\": \"{ a }\"}\n\n{\"query\": \"{ b }\"}\n";
+        let operations = parse_replay_file(contents).unwrap();
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[1].query, "{ b }");
+    }
+
+    #[test]
+    fn parses_a_single_newline_delimited_request() {
+        let contents = "{\"query\": \"{ a }\", \"operationName\": \"A\"}\n";
+        let operations = parse_replay_file(contents).unwrap();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].operation_name, Some("A".to_string()));
+    }
+
+    #[test]
+    fn parses_a_persisted_query_manifest() {
+        let contents = json!({
+            "format": "apollo-persisted-query-manifest",
+            "version": 1,
+            "operations": [
+                { "id": "abc", "name": "Me", "type": "query", "body": "query Me { me { id } }" }
+            ]
+        })
+        .to_string();
+        let operations = parse_replay_file(&contents).unwrap();
+        assert_eq!(
+            operations,
+            vec![ReplayOperation {
+                query: "query Me { me { id } }".to_string(),
+                operation_name: Some("Me".to_string()),
+                variables: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_files() {
+        assert!(parse_replay_file("not json").is_err());
+    }
+
+    #[test]
+    fn diffs_json_responses() {
+        let expected = json!({ "data": { "a": 1, "b": [1, 2], "c": "x" } });
+        let actual = json!({ "data": { "a": 2, "b": [1, 2], "d": true } });
+        assert_eq!(
+            diff_json(&expected, &actual),
+            vec!["$.data.a", "$.data.c", "$.data.d"]
+        );
+        assert!(diff_json(&expected, &expected).is_empty());
+    }
+
+    fn operation(name: &str, query: &str) -> ReplayOperation {
+        ReplayOperation {
+            query: query.to_string(),
+            operation_name: Some(name.to_string()),
+            variables: None,
+        }
+    }
+
+    fn result(operation: &ReplayOperation, index: usize, response: Value) -> ReplayResult {
+        ReplayResult {
+            operation: operation.label(index),
+            baseline_key: operation.baseline_key(),
+            status: Some(200),
+            errors: Vec::new(),
+            latency: Duration::ZERO,
+            response: Some(response),
+            baseline: None,
+        }
+    }
+
+    #[test]
+    fn baseline_keys_ignore_the_operation_position() {
+        let me = operation("Me", "query Me { me { id } }");
+        let other_me = operation("Me", "query Me { me { id name } }");
+        assert_eq!(
+            me.baseline_key(),
+            operation("Me", "query Me { me { id } }").baseline_key()
+        );
+        assert!(me.baseline_key().starts_with("Me "));
+        assert_ne!(me.baseline_key(), other_me.baseline_key());
+
+        let with_variables = ReplayOperation {
+            variables: Some(json!({ "id": "1" })),
+            ..me.clone()
+        };
+        assert_ne!(me.baseline_key(), with_variables.baseline_key());
+    }
+
+    #[test]
+    fn compares_operations_to_their_own_baseline_entry() {
+        let me = operation("Me", "query Me { me { id } }");
+        let products = operation("Products", "query Products { products { id } }");
+        let baseline = vec![BaselineEntry {
+            operation: me.baseline_key(),
+            response: json!({ "data": { "me": { "id": "1" } } }),
+        }];
+
+        // a new operation ahead of `Me` doesn't shift what it's compared against
+        assert_eq!(
+            compare_to_baseline(&baseline, &result(&products, 0, json!({ "data": {} }))),
+            BaselineComparison::Missing
+        );
+        assert_eq!(
+            compare_to_baseline(
+                &baseline,
+                &result(&me, 1, json!({ "data": { "me": { "id": "1" } } }))
+            ),
+            BaselineComparison::Matches
+        );
+        assert_eq!(
+            compare_to_baseline(
+                &baseline,
+                &result(&me, 1, json!({ "data": { "me": { "id": "2" } } }))
+            ),
+            BaselineComparison::Differs(vec!["$.data.me.id".to_string()])
+        );
+    }
+}
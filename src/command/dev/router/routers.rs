@@ -0,0 +1,337 @@
+//! Running several routers side-by-side from one `rover dev` session, each serving its own
+//! composition of a subset of the supergraph's subgraphs.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    net::IpAddr,
+};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use rover_std::{Fs, RoverStdError};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::config::{RouterAddress, RouterHost, RouterPort};
+use crate::composition::runner::CompositionSlice;
+
+/// The port the first router listens on when the routers file doesn't say; each router after
+/// it gets the next port up
+const DEFAULT_FIRST_ROUTER_PORT: u16 = 4000;
+
+/// The name given to the router when `rover dev` only runs one
+const DEFAULT_ROUTER_NAME: &str = "router";
+
+#[derive(Error, Debug)]
+pub enum RoutersConfigError {
+    #[error("Failed to read routers file at {}", .path)]
+    ReadFile {
+        path: Utf8PathBuf,
+        source: RoverStdError,
+    },
+    #[error("{} is not a valid routers file: {}", .path, .source)]
+    Parse {
+        path: Utf8PathBuf,
+        source: serde_yaml::Error,
+    },
+    #[error("{} doesn't list any routers", .path)]
+    Empty { path: Utf8PathBuf },
+    #[error("More than one router is named `{}`", .name)]
+    DuplicateName { name: String },
+    #[error("Routers `{}` and `{}` both listen on port {}", .first, .second, .port)]
+    DuplicatePort {
+        first: String,
+        second: String,
+        port: u16,
+    },
+    #[error(
+        "Router `{}` lists subgraph `{}`, which isn't in the supergraph config. Available subgraphs: {}",
+        .router,
+        .subgraph,
+        .available.join(", ")
+    )]
+    UnknownSubgraph {
+        router: String,
+        subgraph: String,
+        available: Vec<String>,
+    },
+}
+
+/// The contents of a routers file, passed to `rover dev` with `--routers`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RoutersFile {
+    routers: Vec<RouterEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RouterEntry {
+    name: String,
+    port: Option<u16>,
+    address: Option<IpAddr>,
+    router_config: Option<Utf8PathBuf>,
+    subgraphs: Option<BTreeSet<String>>,
+}
+
+/// Everything needed to start one of the routers `rover dev` runs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouterSpec {
+    pub name: String,
+    pub address: RouterAddress,
+    pub config_path: Option<Utf8PathBuf>,
+    pub slice: CompositionSlice,
+}
+
+impl RouterSpec {
+    /// The single router `rover dev` runs when no routers file is given, configured from the
+    /// CLI options
+    pub fn from_cli(
+        address: Option<IpAddr>,
+        port: Option<u16>,
+        config_path: Option<Utf8PathBuf>,
+    ) -> RouterSpec {
+        RouterSpec {
+            name: DEFAULT_ROUTER_NAME.to_string(),
+            address: RouterAddress::new(
+                address.map(RouterHost::CliOption),
+                port.map(RouterPort::CliOption),
+            ),
+            config_path,
+            slice: CompositionSlice {
+                name: DEFAULT_ROUTER_NAME.to_string(),
+                subgraphs: None,
+            },
+        }
+    }
+
+    /// Reads the routers in the routers file at `path`. Router config paths in the file are
+    /// relative to the file itself.
+    pub fn load(path: &Utf8Path) -> Result<Vec<RouterSpec>, RoutersConfigError> {
+        let contents = Fs::read_file(path).map_err(|source| RoutersConfigError::ReadFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let base_dir = path.parent().unwrap_or_else(|| Utf8Path::new("."));
+        Self::parse(&contents, base_dir).map_err(|err| match err {
+            RoutersConfigError::Empty { .. } => RoutersConfigError::Empty {
+                path: path.to_path_buf(),
+            },
+            RoutersConfigError::Parse { source, .. } => RoutersConfigError::Parse {
+                path: path.to_path_buf(),
+                source,
+            },
+            err => err,
+        })
+    }
+
+    fn parse(contents: &str, base_dir: &Utf8Path) -> Result<Vec<RouterSpec>, RoutersConfigError> {
+        let file: RoutersFile =
+            serde_yaml::from_str(contents).map_err(|source| RoutersConfigError::Parse {
+                path: Utf8PathBuf::new(),
+                source,
+            })?;
+        if file.routers.is_empty() {
+            return Err(RoutersConfigError::Empty {
+                path: Utf8PathBuf::new(),
+            });
+        }
+
+        let mut names = HashSet::new();
+        let mut ports: Vec<(u16, String)> = Vec::new();
+        let mut specs = Vec::with_capacity(file.routers.len());
+        for (index, entry) in file.routers.into_iter().enumerate() {
+            if !names.insert(entry.name.clone()) {
+                return Err(RoutersConfigError::DuplicateName { name: entry.name });
+            }
+
+            // Ports the file doesn't set count up from the default, so that the routers don't
+            // all try to bind 4000
+            let port = match entry.port {
+                Some(port) => RouterPort::CliOption(port),
+                None => RouterPort::Default(DEFAULT_FIRST_ROUTER_PORT.saturating_add(index as u16)),
+            };
+            let port_number = port.get_port();
+            if let Some((_, first)) = ports.iter().find(|(existing, _)| *existing == port_number) {
+                return Err(RoutersConfigError::DuplicatePort {
+                    first: first.clone(),
+                    second: entry.name,
+                    port: port_number,
+                });
+            }
+            ports.push((port_number, entry.name.clone()));
+
+            specs.push(RouterSpec {
+                address: RouterAddress::new(entry.address.map(RouterHost::CliOption), Some(port)),
+                config_path: entry.router_config.map(|config_path| {
+                    if config_path.is_relative() {
+                        base_dir.join(config_path)
+                    } else {
+                        config_path
+                    }
+                }),
+                slice: CompositionSlice {
+                    name: entry.name.clone(),
+                    subgraphs: entry.subgraphs,
+                },
+                name: entry.name,
+            });
+        }
+        Ok(specs)
+    }
+}
+
+/// Checks that every subgraph a router is limited to is in the supergraph config
+pub fn check_subgraphs(
+    specs: &[RouterSpec],
+    available: &BTreeSet<String>,
+) -> Result<(), RoutersConfigError> {
+    for spec in specs {
+        let unknown = spec
+            .slice
+            .subgraphs
+            .iter()
+            .flatten()
+            .find(|subgraph| !available.contains(*subgraph));
+        if let Some(subgraph) = unknown {
+            return Err(RoutersConfigError::UnknownSubgraph {
+                router: spec.name.clone(),
+                subgraph: subgraph.clone(),
+                available: available.iter().cloned().collect(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that no two routers listen on the same port, once each router's config has been read.
+/// A router config can set the supergraph and health check ports, so the ports in the routers
+/// file alone aren't enough to go on.
+pub fn check_ports(routers: &[(String, Vec<u16>)]) -> Result<(), RoutersConfigError> {
+    let mut seen: Vec<(u16, &str)> = Vec::new();
+    for (name, ports) in routers {
+        for port in ports {
+            if let Some((_, first)) = seen
+                .iter()
+                .find(|(existing, first)| existing == port && first != name)
+            {
+                return Err(RoutersConfigError::DuplicatePort {
+                    first: first.to_string(),
+                    second: name.clone(),
+                    port: *port,
+                });
+            }
+            seen.push((*port, name));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn parses_routers_with_default_ports() {
+        let specs = RouterSpec::parse(
+            r#"
+routers:
+  - name: products
+    router_config: ./products-router.yaml
+    subgraphs: [products, inventory]
+  - name: everything
+    port: 5000
+    address: 0.0.0.0
+"#,
+            Utf8Path::new("/project"),
+        )
+        .unwrap();
+
+        assert_eq!(specs.len(), 2);
+        assert_eq!(specs[0].name, "products");
+        assert_eq!(
+            SocketAddr::from(specs[0].address),
+            "127.0.0.1:4000".parse().unwrap()
+        );
+        assert_eq!(
+            specs[0].config_path,
+            Some(Utf8PathBuf::from("/project/./products-router.yaml"))
+        );
+        assert!(specs[0].slice.includes("inventory"));
+        assert!(!specs[0].slice.includes("reviews"));
+
+        assert_eq!(
+            SocketAddr::from(specs[1].address),
+            "0.0.0.0:5000".parse().unwrap()
+        );
+        assert_eq!(specs[1].config_path, None);
+        assert!(specs[1].slice.includes("reviews"));
+    }
+
+    #[rstest]
+    fn rejects_duplicate_names() {
+        let err = RouterSpec::parse(
+            "routers: [{name: a, port: 4001}, {name: a, port: 4002}]",
+            Utf8Path::new("."),
+        )
+        .unwrap_err();
+        assert!(matches!(err, RoutersConfigError::DuplicateName { name } if name == "a"));
+    }
+
+    #[rstest]
+    fn rejects_duplicate_ports() {
+        let err = RouterSpec::parse(
+            "routers: [{name: a}, {name: b, port: 4000}]",
+            Utf8Path::new("."),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            RoutersConfigError::DuplicatePort { port: 4000, .. }
+        ));
+    }
+
+    #[rstest]
+    fn rejects_empty_file() {
+        let err = RouterSpec::parse("routers: []", Utf8Path::new(".")).unwrap_err();
+        assert!(matches!(err, RoutersConfigError::Empty { .. }));
+    }
+
+    #[rstest]
+    fn rejects_unknown_subgraphs() {
+        let specs = RouterSpec::parse(
+            "routers: [{name: a, subgraphs: [products, reviewz]}]",
+            Utf8Path::new("."),
+        )
+        .unwrap();
+        let available = BTreeSet::from(["products".to_string(), "reviews".to_string()]);
+
+        let err = check_subgraphs(&specs, &available).unwrap_err();
+
+        assert!(matches!(
+            err,
+            RoutersConfigError::UnknownSubgraph { router, subgraph, .. }
+                if router == "a" && subgraph == "reviewz"
+        ));
+    }
+
+    #[rstest]
+    fn rejects_ports_set_in_router_configs() {
+        let routers = vec![
+            ("a".to_string(), vec![4000, 8088]),
+            ("b".to_string(), vec![4001, 8088]),
+        ];
+
+        let err = check_ports(&routers).unwrap_err();
+
+        assert!(matches!(
+            err,
+            RoutersConfigError::DuplicatePort { first, second, port: 8088 }
+                if first == "a" && second == "b"
+        ));
+        assert!(check_ports(&[("a".to_string(), vec![4000, 4000])]).is_ok());
+    }
+}
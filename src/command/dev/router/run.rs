@@ -43,6 +43,7 @@ use crate::{
     },
 };
 
+#[derive(Clone)]
 pub struct RunRouter<S> {
     pub(crate) state: S,
 }
@@ -503,6 +504,7 @@ mod state {
 
     #[derive(Default)]
     pub struct Install {}
+    #[derive(Clone)]
     pub struct LoadLocalConfig {
         pub binary: RouterBinary,
     }
@@ -4,7 +4,7 @@ use rover_client::{
         check::{self, CheckSchemaAsyncInput},
         check_workflow::{self, CheckWorkflowInput},
     },
-    shared::{CheckConfig, GitContext},
+    shared::{CheckConfig, GitContext, ProposedSchema},
 };
 use rover_std::Style;
 use serde::Serialize;
@@ -39,17 +39,19 @@ impl Check {
         checks_timeout_seconds: u64,
     ) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
-        let proposed_schema = self
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let file_with_metadata = self
             .schema
-            .read_file_descriptor("SDL", &mut std::io::stdin())?;
+            .read_file_descriptor_with_metadata("SDL", &mut std::io::stdin())?;
+        let proposed_schema = file_with_metadata.schema.clone();
 
         eprintln!(
             "Checking the proposed schema against {}",
-            Style::Link.paint(self.graph.graph_ref.to_string())
+            Style::Link.paint(graph_ref.to_string())
         );
         let workflow_res = check::run(
             CheckSchemaAsyncInput {
-                graph_ref: self.graph.graph_ref.clone(),
+                graph_ref: graph_ref.clone(),
                 proposed_schema,
                 git_context,
                 config: CheckConfig {
@@ -66,9 +68,13 @@ impl Check {
         } else {
             let check_res = check_workflow::run(
                 CheckWorkflowInput {
-                    graph_ref: self.graph.graph_ref.clone(),
+                    graph_ref,
                     workflow_id: workflow_res.workflow_id,
                     checks_timeout_seconds,
+                    proposed_schema: Some(ProposedSchema {
+                        file_name: file_with_metadata.file_path,
+                        sdl: file_with_metadata.schema,
+                    }),
                 },
                 &client,
             )
@@ -0,0 +1,100 @@
+use clap::Parser;
+use rover_client::{
+    operations::graph::check_workflow::{self, CheckWorkflowInput},
+    shared::CheckWorkflowSnapshot,
+};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct CheckStatus {
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// The workflow ID printed by `rover graph check --background`
+    #[arg(long)]
+    #[serde(skip_serializing)]
+    workflow_id: String,
+
+    /// Wait for the check to finish before reporting its result. This is the default.
+    #[arg(long, overrides_with = "no_wait")]
+    wait: bool,
+
+    /// Report the check's result if it has finished, or that it's still running if it hasn't,
+    /// instead of waiting for it to finish
+    #[arg(long, overrides_with = "wait")]
+    no_wait: bool,
+}
+
+impl CheckStatus {
+    /// Whether to wait for the check to finish, which the last of `--wait` and `--no-wait` decides
+    const fn waits(&self) -> bool {
+        !self.no_wait
+    }
+
+    pub async fn run(
+        &self,
+        client_config: StudioClientConfig,
+        checks_timeout_seconds: u64,
+    ) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let input = CheckWorkflowInput {
+            graph_ref: graph_ref.clone(),
+            workflow_id: self.workflow_id.clone(),
+            checks_timeout_seconds,
+            proposed_schema: None,
+        };
+
+        eprintln!(
+            "Fetching the status of check {} on {}",
+            Style::Command.paint(&self.workflow_id),
+            Style::Link.paint(graph_ref.to_string())
+        );
+        if !self.waits() {
+            match check_workflow::snapshot(input, &client).await? {
+                CheckWorkflowSnapshot::Pending { target_url } => {
+                    Ok(RoverOutput::CheckWorkflowPending {
+                        workflow_id: self.workflow_id.clone(),
+                        target_url,
+                    })
+                }
+                CheckWorkflowSnapshot::Completed(check_res) => {
+                    Ok(RoverOutput::CheckWorkflowResponse(*check_res))
+                }
+            }
+        } else {
+            let check_res = check_workflow::run(input, &client).await?;
+            Ok(RoverOutput::CheckWorkflowResponse(check_res))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use rstest::rstest;
+
+    use super::CheckStatus;
+
+    #[rstest]
+    #[case::default(&[], true)]
+    #[case::wait(&["--wait"], true)]
+    #[case::no_wait(&["--no-wait"], false)]
+    #[case::no_wait_last(&["--wait", "--no-wait"], false)]
+    #[case::wait_last(&["--no-wait", "--wait"], true)]
+    fn it_picks_waiting_or_a_snapshot_from_the_flags(#[case] flags: &[&str], #[case] waits: bool) {
+        let args = ["check-status", "mygraph@current", "--workflow-id", "abc123"];
+        let check_status = CheckStatus::try_parse_from(args.iter().chain(flags)).unwrap();
+        assert_eq!(check_status.waits(), waits);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use apollo_federation_types::config::{SchemaSource, SubgraphConfig};
+use camino::Utf8PathBuf;
+use clap::Parser;
+use rover_client::{
+    RoverClientError,
+    operations::{
+        cloud::config::{fetch as cloud_config_fetch, types::CloudConfigFetchInput},
+        contract::describe::{self as contract_describe, ContractDescribeInput},
+        readme::fetch::{self as readme_fetch, ReadmeFetchInput},
+        subgraph::fetch_all::{self, SubgraphFetchAllInput, SubgraphFetchAllResponse},
+        supergraph::fetch::{self as supergraph_fetch, SupergraphFetchInput},
+    },
+};
+use rover_std::{Fs, Style};
+use rover_studio::types::GraphRef;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverOutput, RoverResult, config::SupergraphConfigYaml,
+    options::ProfileOpt, utils::client::StudioClientConfig,
+};
+
+pub(super) const SUPERGRAPH_CONFIG_FILE: &str = "supergraph.yaml";
+pub(super) const SUPERGRAPH_SCHEMA_FILE: &str = "supergraph.graphql";
+pub(super) const SUBGRAPHS_DIR: &str = "subgraphs";
+pub(super) const README_FILE: &str = "README.md";
+pub(super) const ROUTER_CONFIG_FILE: &str = "router.yaml";
+pub(super) const CONTRACT_CONFIG_FILE: &str = "contract.yaml";
+
+/// The settings of a contract variant, as written to `contract.yaml`
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct ContractConfig {
+    pub(super) source_variant: String,
+    #[serde(default)]
+    pub(super) include_tags: Vec<String>,
+    #[serde(default)]
+    pub(super) exclude_tags: Vec<String>,
+    // GraphOS doesn't report this setting, so it's always exported as `false`
+    #[serde(default)]
+    pub(super) hide_unreachable_types: bool,
+}
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Export {
+    /// <NAME>@<VARIANT> of the graph to export.
+    /// @<VARIANT> may be left off, defaulting to @current
+    #[arg(value_name = "GRAPH_REF")]
+    #[serde(skip_serializing)]
+    graph_ref: GraphRef,
+
+    /// The directory to export to. It's created if it doesn't exist, and must be empty if it does.
+    #[arg(value_name = "DIR")]
+    #[serde(skip_serializing)]
+    directory: Utf8PathBuf,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Export {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        // leftover files from another export would be imported along with this one
+        if self.directory.exists() && Fs::get_dir_entries(&self.directory)?.next().is_some() {
+            let mut err = RoverError::new(anyhow!("'{}' is not empty", self.directory));
+            err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                "Export to a new or empty directory.".to_string(),
+            ));
+            return Err(err);
+        }
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph_ref.clone();
+
+        eprintln!(
+            "Exporting {} to {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Path.paint(self.directory.as_str()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let mut files = Vec::new();
+        let mut write = |file: Utf8PathBuf, contents: &str| -> RoverResult<()> {
+            Fs::write_file(self.directory.join(&file), contents)?;
+            files.push(file);
+            Ok(())
+        };
+
+        let contract = match contract_describe::run(
+            ContractDescribeInput {
+                graph_ref: graph_ref.clone(),
+            },
+            &client,
+        )
+        .await
+        {
+            Ok(contract) => Some(contract),
+            Err(RoverClientError::ExpectedContractVariant { .. }) => None,
+            Err(err) => return Err(err.into()),
+        };
+        if let Some(contract) = contract {
+            // a contract has no subgraphs of its own; it's rebuilt from its source variant
+            let contract_config = ContractConfig {
+                source_variant: contract.source_variant,
+                include_tags: contract.include_tags,
+                exclude_tags: contract.exclude_tags,
+                hide_unreachable_types: false,
+            };
+            write(
+                CONTRACT_CONFIG_FILE.into(),
+                &serde_yaml::to_string(&contract_config)?,
+            )?;
+        } else {
+            let fetch_all_response = fetch_all::run(
+                SubgraphFetchAllInput {
+                    graph_ref: graph_ref.clone(),
+                },
+                &client,
+            )
+            .await?;
+            write_subgraphs(fetch_all_response, &mut write)?;
+        }
+
+        let supergraph = supergraph_fetch::run(
+            SupergraphFetchInput {
+                graph_ref: graph_ref.clone(),
+            },
+            &client,
+        )
+        .await?;
+        write(SUPERGRAPH_SCHEMA_FILE.into(), &supergraph.sdl.contents)?;
+
+        let readme = readme_fetch::run(
+            ReadmeFetchInput {
+                graph_ref: graph_ref.clone(),
+            },
+            &client,
+        )
+        .await?;
+        if !readme.content.is_empty() {
+            write(README_FILE.into(), &readme.content)?;
+        }
+
+        match cloud_config_fetch::run(
+            CloudConfigFetchInput {
+                graph_ref: graph_ref.clone(),
+            },
+            &client,
+        )
+        .await
+        {
+            Ok(cloud_config) => write(ROUTER_CONFIG_FILE.into(), &cloud_config.config)?,
+            Err(RoverClientError::NonCloudGraphRef { .. }) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        Ok(RoverOutput::GraphExport {
+            graph_ref,
+            directory: self.directory.clone(),
+            files,
+        })
+    }
+}
+
+/// Writes each subgraph's schema to `subgraphs/`, and a `supergraph.yaml` that lists them
+pub(super) fn write_subgraphs(
+    fetch_all_response: SubgraphFetchAllResponse,
+    write: &mut impl FnMut(Utf8PathBuf, &str) -> RoverResult<()>,
+) -> RoverResult<()> {
+    let mut subgraphs = BTreeMap::new();
+    for subgraph in fetch_all_response.subgraphs {
+        let schema_file =
+            Utf8PathBuf::from(SUBGRAPHS_DIR).join(format!("{}.graphql", subgraph.name()));
+        write(schema_file.clone(), subgraph.sdl())?;
+        subgraphs.insert(
+            subgraph.name().clone(),
+            SubgraphConfig {
+                routing_url: subgraph.url().clone(),
+                schema: SchemaSource::File {
+                    file: schema_file.into_std_path_buf(),
+                },
+            },
+        );
+    }
+    let supergraph_config = SupergraphConfigYaml {
+        subgraphs,
+        federation_version: fetch_all_response.federation_version,
+    };
+    write(
+        SUPERGRAPH_CONFIG_FILE.into(),
+        &serde_yaml::to_string(&supergraph_config)?,
+    )
+}
@@ -0,0 +1,250 @@
+use anyhow::anyhow;
+use apollo_federation_types::config::SchemaSource;
+use camino::Utf8PathBuf;
+use clap::Parser;
+use rover_client::operations::{
+    cloud::config::{types::CloudConfigInput, update as cloud_config_update},
+    contract::publish::{self as contract_publish, ContractPublishInput},
+    graph::import::{self, GraphImportInput, ImportSubgraph},
+    readme::publish::{self as readme_publish, ReadmePublishInput},
+};
+use rover_std::{Fs, Style};
+use serde::Serialize;
+
+use super::export::{
+    CONTRACT_CONFIG_FILE, ContractConfig, README_FILE, ROUTER_CONFIG_FILE, SUPERGRAPH_CONFIG_FILE,
+};
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverOutput, RoverResult,
+    config::SupergraphConfigYaml,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Import {
+    /// A directory written by `rover graph export`
+    #[arg(value_name = "DIR")]
+    #[serde(skip_serializing)]
+    directory: Utf8PathBuf,
+
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Import {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        // read everything up front, so a malformed export fails before anything is published
+        let contract_config = self
+            .read_optional(CONTRACT_CONFIG_FILE)?
+            .map(|contents| serde_yaml::from_str::<ContractConfig>(&contents))
+            .transpose()?;
+        let import_input = match contract_config {
+            Some(_) => None,
+            None => Some(self.read_subgraphs()?),
+        };
+        let router_config = self.read_optional(ROUTER_CONFIG_FILE)?;
+        let readme = self.read_optional(README_FILE)?;
+
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Importing {} into {} using credentials from the {} profile.",
+            Style::Path.paint(self.directory.as_str()),
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        // the variant's schema comes first, since the variant doesn't exist until it's published
+        let import_response = match import_input {
+            Some((subgraphs, federation_version)) => Some(
+                import::run(
+                    GraphImportInput {
+                        graph_ref: graph_ref.clone(),
+                        subgraphs,
+                        federation_version,
+                        revision: format!("imported from {}", self.directory),
+                    },
+                    &client,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let contract_response = match contract_config {
+            Some(contract_config) => Some(
+                contract_publish::run(
+                    ContractPublishInput {
+                        graph_ref: graph_ref.clone(),
+                        source_variant: Some(contract_config.source_variant),
+                        include_tags: contract_config.include_tags,
+                        exclude_tags: contract_config.exclude_tags,
+                        hide_unreachable_types: contract_config.hide_unreachable_types,
+                        no_launch: false,
+                    },
+                    &client,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+
+        let router_config_updated = router_config.is_some();
+        if let Some(config) = router_config {
+            cloud_config_update::run(
+                CloudConfigInput {
+                    graph_ref: graph_ref.clone(),
+                    config,
+                },
+                &client,
+            )
+            .await?;
+        }
+        let readme_updated = readme.is_some();
+        if let Some(readme) = readme {
+            readme_publish::run(
+                ReadmePublishInput {
+                    graph_ref: graph_ref.clone(),
+                    readme,
+                },
+                &client,
+            )
+            .await?;
+        }
+
+        Ok(RoverOutput::GraphImport {
+            graph_ref,
+            import_response,
+            contract_response,
+            router_config_updated,
+            readme_updated,
+        })
+    }
+
+    fn read_optional(&self, file: &str) -> RoverResult<Option<String>> {
+        let path = self.directory.join(file);
+        if path.exists() {
+            Ok(Some(Fs::read_file(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the subgraphs listed in `supergraph.yaml`, along with the federation version to
+    /// set on the variant
+    fn read_subgraphs(&self) -> RoverResult<(Vec<ImportSubgraph>, Option<String>)> {
+        let supergraph_config = self.read_optional(SUPERGRAPH_CONFIG_FILE)?.ok_or_else(|| {
+            let mut err = RoverError::new(anyhow!(
+                "'{}' has neither a {} nor a {}",
+                self.directory,
+                SUPERGRAPH_CONFIG_FILE,
+                CONTRACT_CONFIG_FILE
+            ));
+            err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                "Pass a directory written by `rover graph export`.".to_string(),
+            ));
+            err
+        })?;
+        let supergraph_config: SupergraphConfigYaml = serde_yaml::from_str(&supergraph_config)?;
+
+        let subgraphs = supergraph_config
+            .subgraphs
+            .into_iter()
+            .map(|(name, subgraph)| {
+                let sdl = match subgraph.schema {
+                    SchemaSource::File { file } => {
+                        let file = Utf8PathBuf::try_from(file)?;
+                        Fs::read_file(self.directory.join(file))?
+                    }
+                    SchemaSource::Sdl { sdl } => sdl,
+                    _ => {
+                        return Err(RoverError::new(anyhow!(
+                            "The schema for subgraph '{name}' must be a file or inline SDL"
+                        )));
+                    }
+                };
+                Ok(ImportSubgraph {
+                    name,
+                    url: subgraph.routing_url,
+                    sdl,
+                })
+            })
+            .collect::<RoverResult<Vec<_>>>()?;
+        // GraphOS takes a `major.minor` federation version and picks the latest patch itself
+        let federation_version = supergraph_config
+            .federation_version
+            .as_ref()
+            .and_then(|version| version.get_exact())
+            .map(|version| format!("{}.{}", version.major, version.minor));
+        Ok((subgraphs, federation_version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use apollo_federation_types::config::FederationVersion;
+    use assert_fs::TempDir;
+    use rover_client::operations::subgraph::fetch_all::{Subgraph, SubgraphFetchAllResponse};
+    use semver::Version;
+
+    use super::*;
+    use crate::command::graph::export::write_subgraphs;
+
+    #[test]
+    fn it_reads_back_the_subgraphs_an_export_wrote() {
+        let temp_dir = TempDir::new().unwrap();
+        let directory = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        let mut write = |file: Utf8PathBuf, contents: &str| -> RoverResult<()> {
+            Fs::write_file(directory.join(file), contents)?;
+            Ok(())
+        };
+        write_subgraphs(
+            SubgraphFetchAllResponse {
+                subgraphs: vec![
+                    Subgraph::builder()
+                        .name("products")
+                        .url("http://products")
+                        .sdl("type Query { products: [String] }")
+                        .build(),
+                    Subgraph::builder()
+                        .name("reviews")
+                        .sdl("type Query { reviews: [String] }")
+                        .build(),
+                ],
+                federation_version: Some(FederationVersion::ExactFedTwo(Version::new(2, 9, 3))),
+            },
+            &mut write,
+        )
+        .unwrap();
+
+        let import = Import {
+            directory,
+            graph: GraphRefOpt { graph_ref: None },
+            profile: ProfileOpt {
+                profile_name: "default".to_string(),
+            },
+        };
+        let (subgraphs, federation_version) = import.read_subgraphs().unwrap();
+
+        assert_eq!(
+            subgraphs,
+            vec![
+                ImportSubgraph {
+                    name: "products".to_string(),
+                    url: Some("http://products".to_string()),
+                    sdl: "type Query { products: [String] }".to_string(),
+                },
+                ImportSubgraph {
+                    name: "reviews".to_string(),
+                    url: None,
+                    sdl: "type Query { reviews: [String] }".to_string(),
+                },
+            ]
+        );
+        assert_eq!(federation_version, Some("2.9".to_string()));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, time::Duration};
+use std::collections::HashMap;
 
 use clap::Parser;
 use reqwest::Client;
@@ -6,6 +6,7 @@ use rover_client::{
     blocking::GraphQLClient,
     operations::graph::introspect::{self, GraphIntrospectInput},
 };
+use rover_http::RetryConfig;
 use serde::Serialize;
 
 use crate::{
@@ -24,13 +25,12 @@ impl Introspect {
         &self,
         client: Client,
         output_opts: &OutputOpts,
-        retry_period: Duration,
+        retry: RetryConfig,
     ) -> RoverResult<RoverOutput> {
         if self.opts.watch {
-            self.exec_and_watch(&client, output_opts, retry_period)
-                .await
+            self.exec_and_watch(&client, output_opts, &retry).await
         } else {
-            let sdl = self.exec(&client, true, retry_period).await?;
+            let sdl = self.exec(&client, true, &retry).await?;
             Ok(RoverOutput::Introspection(sdl))
         }
     }
@@ -39,9 +39,9 @@ impl Introspect {
         &self,
         client: &Client,
         should_retry: bool,
-        retry_period: Duration,
+        retry: &RetryConfig,
     ) -> RoverResult<String> {
-        let client = GraphQLClient::new(self.opts.endpoint.as_ref(), client.clone(), retry_period);
+        let client = GraphQLClient::new(self.opts.endpoint.as_ref(), client.clone(), retry.clone());
 
         // add the flag headers to a hashmap to pass along to rover-client
         let mut headers = HashMap::new();
@@ -63,10 +63,10 @@ impl Introspect {
         client: &Client,
         output_opts: &OutputOpts,
 
-        retry_period: Duration,
+        retry: &RetryConfig,
     ) -> ! {
         self.opts
-            .exec_and_watch(|| self.exec(client, false, retry_period), output_opts)
+            .exec_and_watch(|| self.exec(client, false, retry), output_opts)
             .await
     }
 }
@@ -0,0 +1,47 @@
+use clap::Parser;
+use rover_client::operations::graph::launches::{self, GraphLaunchesInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Launches {
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// The maximum number of launches to list, most recent first
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(i64).range(1..=100))]
+    limit: i64,
+}
+
+impl Launches {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Listing launches for {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let launches = launches::run(
+            GraphLaunchesInput {
+                graph_ref,
+                limit: self.limit,
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::GraphLaunches(launches))
+    }
+}
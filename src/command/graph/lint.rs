@@ -27,6 +27,7 @@ pub struct Lint {
 impl Lint {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
 
         let file_with_metadata = self
             .schema
@@ -34,7 +35,7 @@ impl Lint {
 
         let lint_result: rover_client::shared::LintResponse = lint::run(
             LintGraphInput {
-                graph_ref: self.graph.graph_ref.clone(),
+                graph_ref,
                 file_name: file_with_metadata.file_path,
                 proposed_schema: file_with_metadata.schema,
                 ignore_existing: self.lint.ignore_existing_lint_violations,
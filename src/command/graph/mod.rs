@@ -1,9 +1,14 @@
 mod check;
+mod check_status;
 mod delete;
+mod export;
 mod fetch;
+mod import;
 mod introspect;
+mod launches;
 mod lint;
 mod publish;
+mod variant;
 
 use clap::Parser;
 use rover_client::shared::GitContext;
@@ -23,12 +28,25 @@ pub enum Command {
     /// against a graph schema in the Apollo graph registry
     Check(check::Check),
 
+    /// Fetch the result of a graph check started with `--background`, by its workflow ID
+    CheckStatus(check_status::CheckStatus),
+
     /// Delete a graph schema from the Apollo graph registry
     Delete(delete::Delete),
 
+    /// Export a variant's subgraph schemas, supergraph schema, README, cloud router config, and
+    /// contract settings to a directory
+    Export(export::Export),
+
     /// Fetch a graph schema from the Apollo graph registry
     Fetch(fetch::Fetch),
 
+    /// Republish a directory written by `rover graph export` to a variant
+    Import(import::Import),
+
+    /// List the most recent launches of a graph variant
+    Launches(launches::Launches),
+
     /// Lint a graph schema
     Lint(lint::Lint),
 
@@ -37,6 +55,9 @@ pub enum Command {
 
     /// Introspect current graph schema.
     Introspect(introspect::Introspect),
+
+    /// Variant management commands
+    Variant(variant::Variant),
 }
 
 impl Graph {
@@ -53,16 +74,23 @@ impl Graph {
                     .run(client_config, git_context, checks_timeout_seconds)
                     .await
             }
+            Command::CheckStatus(command) => {
+                command.run(client_config, checks_timeout_seconds).await
+            }
             Command::Delete(command) => command.run(client_config).await,
+            Command::Export(command) => command.run(client_config).await,
             Command::Fetch(command) => command.run(client_config).await,
+            Command::Import(command) => command.run(client_config).await,
+            Command::Launches(command) => command.run(client_config).await,
             Command::Lint(command) => command.run(client_config).await,
             Command::Publish(command) => command.run(client_config, git_context).await,
+            Command::Variant(command) => command.run(client_config).await,
             Command::Introspect(command) => {
                 command
                     .run(
                         client_config.get_reqwest_client()?,
                         output_opts,
-                        client_config.client_timeout().get_duration(),
+                        client_config.retry_config(),
                     )
                     .await
             }
@@ -0,0 +1,52 @@
+use clap::Parser;
+use rover_client::operations::variant::create::{self, CreateVariantInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Create {
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// The existing variant of the same graph to copy subgraphs and the federation version from
+    #[arg(long = "from", value_name = "SOURCE_VARIANT")]
+    source_variant: String,
+}
+
+impl Create {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Creating {} from the {} variant using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.source_variant),
+            Style::Command.paint(&self.profile.profile_name)
+        );
+
+        let create_response = create::run(
+            CreateVariantInput {
+                graph_ref: graph_ref.clone(),
+                source_variant: self.source_variant.clone(),
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::VariantCreate {
+            graph_ref,
+            source_variant: self.source_variant.clone(),
+            create_response,
+        })
+    }
+}
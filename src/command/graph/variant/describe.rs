@@ -0,0 +1,36 @@
+use clap::Parser;
+use rover_client::operations::variant::describe::{self, DescribeVariantInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Describe {
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Describe {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Describing {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let describe_response = describe::run(DescribeVariantInput { graph_ref }, &client).await?;
+
+        Ok(RoverOutput::VariantDescribe(describe_response))
+    }
+}
@@ -0,0 +1,43 @@
+use clap::Parser;
+use rover_client::operations::variant::list::{self, ListVariantsInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct List {
+    // only the graph ID is used, so any @<VARIANT> is ignored
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl List {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Listing variants for {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.graph_id()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let list_response = list::run(
+            ListVariantsInput {
+                graph_id: graph_ref.graph_id().to_string(),
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::VariantList(list_response))
+    }
+}
@@ -0,0 +1,41 @@
+mod create;
+mod delete;
+mod describe;
+mod list;
+
+use clap::Parser;
+use serde::Serialize;
+
+use crate::{RoverOutput, RoverResult, utils::client::StudioClientConfig};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Variant {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Serialize, Parser)]
+pub enum Command {
+    /// Create a variant by copying the subgraphs of an existing variant
+    Create(create::Create),
+
+    /// Delete a variant from the Apollo graph registry
+    Delete(delete::Delete),
+
+    /// Describe a variant's federation version, contract, persisted query list, and subgraphs
+    Describe(describe::Describe),
+
+    /// List the variants of a graph
+    List(list::List),
+}
+
+impl Variant {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        match &self.command {
+            Command::Create(command) => command.run(client_config).await,
+            Command::Delete(command) => command.run(client_config).await,
+            Command::Describe(command) => command.run(client_config).await,
+            Command::List(command) => command.run(client_config).await,
+        }
+    }
+}
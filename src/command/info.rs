@@ -4,13 +4,13 @@ use calm_io::stderrln;
 use clap::Parser;
 use serde::Serialize;
 
-use crate::{PKG_VERSION, RoverOutput, RoverResult};
+use crate::{PKG_VERSION, RoverOutput, RoverResult, utils::client::ClientBuilder};
 
 #[derive(Debug, Serialize, Parser)]
 pub struct Info {}
 
 impl Info {
-    pub fn run(&self) -> RoverResult<RoverOutput> {
+    pub fn run(&self, client_builder: &ClientBuilder) -> RoverResult<RoverOutput> {
         let os = os_info::get();
 
         // something like "/usr/bin/zsh" or "Unknown"
@@ -24,12 +24,17 @@ impl Info {
             Err(_) => "Unknown".to_string(),
         };
 
+        // a profile's `proxy` setting only applies to GraphOS requests made with that profile,
+        // so this is the proxy every other request goes through
+        let proxy = client_builder.proxy_config().to_string();
+
         stderrln!(
-            "Rover Info:\nVersion: {}\nInstall Location: {}\nOS: {}\nShell: {}",
+            "Rover Info:\nVersion: {}\nInstall Location: {}\nOS: {}\nShell: {}\nProxy: {}",
             PKG_VERSION,
             location,
             os,
-            shell
+            shell,
+            proxy
         )?;
 
         Ok(RoverOutput::EmptySuccess)
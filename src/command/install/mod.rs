@@ -38,7 +38,11 @@ impl Install {
         client_config: StudioClientConfig,
     ) -> RoverResult<RoverOutput> {
         let binary_name = PKG_NAME.to_string();
-        let rover_installer = self.get_installer(binary_name.to_string(), override_install_path)?;
+        let rover_installer = self.get_installer(
+            binary_name.to_string(),
+            override_install_path,
+            &client_config,
+        )?;
 
         if let Some(plugin) = &self.plugin {
             let requires_elv2_license = plugin.requires_elv2_license();
@@ -112,7 +116,8 @@ impl Install {
         client_config: StudioClientConfig,
         skip_update: bool,
     ) -> RoverResult<Utf8PathBuf> {
-        let rover_installer = self.get_installer(PKG_NAME.to_string(), override_install_path)?;
+        let rover_installer =
+            self.get_installer(PKG_NAME.to_string(), override_install_path, &client_config)?;
         if let Some(plugin) = &self.plugin {
             let plugin_installer = PluginInstaller::new(client_config, rover_installer, self.force);
             plugin_installer.install(plugin, skip_update).await
@@ -128,6 +133,7 @@ impl Install {
         &self,
         binary_name: String,
         override_install_path: Option<Utf8PathBuf>,
+        client_config: &StudioClientConfig,
     ) -> RoverResult<Installer> {
         if let Ok(executable_location) = env::current_exe() {
             let executable_location = Utf8PathBuf::try_from(executable_location)?;
@@ -136,6 +142,8 @@ impl Install {
                 force_install: self.force,
                 override_install_path,
                 executable_location,
+                tls: client_config.client_builder().tls_config().clone(),
+                proxy: client_config.client_builder().proxy_config().clone(),
             })
         } else {
             Err(anyhow!("Failed to get the current executable's path.").into())
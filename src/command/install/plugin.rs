@@ -248,6 +248,10 @@ impl PluginInstaller {
         }
     }
 
+    #[tracing::instrument(
+        skip_all,
+        fields(plugin = %plugin.get_name(), version = %plugin.get_tarball_version())
+    )]
     pub async fn install(&self, plugin: &Plugin, skip_update: bool) -> RoverResult<Utf8PathBuf> {
         let install_location = match plugin {
             Plugin::Router(version) => match version {
@@ -139,9 +139,16 @@ async fn run_lsp(client_config: StudioClientConfig, lsp_opts: LspOpts) -> RoverR
             });
             // Create a composition runner first, so that we can use that to drive the initial
             // set of subgraphs that get reported to the LSP
-            let composition_runner =
-                create_composition_runner(supergraph_yaml_path, None, client_config, lsp_opts)
-                    .await?;
+            let federation_version = lsp_opts
+                .plugin_opts
+                .federation_version(None, &client_config.config)?;
+            let composition_runner = create_composition_runner(
+                supergraph_yaml_path,
+                federation_version,
+                client_config,
+                lsp_opts,
+            )
+            .await?;
             let initial_subgraphs = composition_runner
                 .state
                 .initial_supergraph_config
@@ -17,6 +17,7 @@ mod license;
 mod lsp;
 pub(crate) mod output;
 mod persisted_queries;
+mod proposal;
 mod readme;
 mod schema;
 pub(crate) mod subgraph;
@@ -43,6 +44,7 @@ pub use license::License;
 pub use lsp::Lsp;
 pub use output::{CliOutput, RoverOutput};
 pub use persisted_queries::PersistedQueries;
+pub use proposal::Proposal;
 pub use readme::Readme;
 pub use schema::Schema;
 pub use subgraph::Subgraph;
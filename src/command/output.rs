@@ -4,6 +4,7 @@ use std::{
     io::{self, IsTerminal},
 };
 
+use apollo_federation_types::rover::BuildHint;
 use calm_io::{stderr, stderrln};
 use camino::Utf8PathBuf;
 use comfy_table::{Attribute::Bold, Cell, CellAlignment::Center};
@@ -12,13 +13,21 @@ use rover_client::{
     operations::{
         api_key::list::ApiKey,
         contract::{describe::ContractDescribeResponse, publish::ContractPublishResponse},
-        graph::publish::GraphPublishResponse,
+        graph::{
+            import::GraphImportResponse, launches::GraphLaunchesResponse,
+            publish::GraphPublishResponse,
+        },
         init::memberships::InitMembershipsResponse,
         persisted_queries::publish::PersistedQueriesPublishResponse,
+        proposal::{list::ListProposalsResponse, types::ProposalInfo},
         subgraph::{
             delete::SubgraphDeleteResponse, list::SubgraphListResponse,
             publish::SubgraphPublishResponse,
         },
+        variant::{
+            create::CreateVariantResponse, describe::DescribeVariantResponse,
+            list::ListVariantsResponse,
+        },
     },
     shared::{
         CheckRequestSuccessResult, CheckWorkflowResponse, FetchResponse, LintResponse, SdlType,
@@ -36,12 +45,13 @@ use crate::command::{
 };
 use crate::{
     RoverError,
+    cli::RoverOutputFormatKind,
     command::{
         docs::shortlinks::ShortlinkInfo,
         template::queries::list_templates_for_language::ListTemplatesForLanguageTemplates,
     },
     options::{JsonVersion, ProjectLanguage},
-    utils::table,
+    utils::{project_config::EffectiveValue, sdl::SdlDiff, table},
 };
 
 /// Trait for command output types that can render themselves in multiple formats.
@@ -78,6 +88,7 @@ pub enum RoverOutput {
     InitMembershipsOutput(InitMembershipsResponse),
     ContractDescribe(ContractDescribeResponse),
     ContractPublish(ContractPublishResponse),
+    ContractSchema(String),
     DocsList(BTreeMap<&'static str, ShortlinkInfo>),
     FetchResponse(FetchResponse),
     SupergraphSchema(String),
@@ -85,8 +96,34 @@ pub enum RoverOutput {
     #[cfg(feature = "composition-js")]
     CompositionResult(CompositionOutput),
     SubgraphList(SubgraphListResponse),
+    GraphLaunches(GraphLaunchesResponse),
+    VariantList(ListVariantsResponse),
+    VariantDescribe(DescribeVariantResponse),
+    VariantCreate {
+        graph_ref: GraphRef,
+        source_variant: String,
+        create_response: CreateVariantResponse,
+    },
+    ProposalList(ListProposalsResponse),
+    ProposalDescribe(ProposalInfo),
+    GraphExport {
+        graph_ref: GraphRef,
+        directory: Utf8PathBuf,
+        files: Vec<Utf8PathBuf>,
+    },
+    GraphImport {
+        graph_ref: GraphRef,
+        import_response: Option<GraphImportResponse>,
+        contract_response: Option<ContractPublishResponse>,
+        router_config_updated: bool,
+        readme_updated: bool,
+    },
     CheckWorkflowResponse(CheckWorkflowResponse),
     AsyncCheckResponse(CheckRequestSuccessResult),
+    CheckWorkflowPending {
+        workflow_id: String,
+        target_url: Option<String>,
+    },
     LintResponse(LintResponse),
     GraphPublishResponse {
         graph_ref: GraphRef,
@@ -97,6 +134,16 @@ pub enum RoverOutput {
         subgraph: String,
         publish_response: SubgraphPublishResponse,
     },
+    SubgraphPublishUnchanged {
+        graph_ref: GraphRef,
+        subgraph: String,
+    },
+    SubgraphPublishDryRun {
+        graph_ref: GraphRef,
+        subgraph: String,
+        hints: Vec<BuildHint>,
+        supergraph_diff: SdlDiff,
+    },
     SubgraphDeleteResponse {
         graph_ref: GraphRef,
         subgraph: String,
@@ -109,6 +156,16 @@ pub enum RoverOutput {
         path: Utf8PathBuf,
     },
     Profiles(Vec<String>),
+    ProfileSetting {
+        key: String,
+        value: Option<String>,
+    },
+    ProfileSettings(Vec<(String, String)>),
+    EffectiveConfig(Vec<EffectiveValue>),
+    TelemetryPayload {
+        payload: Value,
+        enabled: bool,
+    },
     Introspection(String),
     ErrorExplanation(String),
     ReadmeFetchResponse {
@@ -301,6 +358,50 @@ impl RoverOutput {
                 }
                 None
             }
+            RoverOutput::SubgraphPublishUnchanged {
+                graph_ref,
+                subgraph,
+            } => {
+                stderrln!(
+                    "The '{}' subgraph in '{}' was NOT published because its schema is unchanged",
+                    subgraph,
+                    graph_ref
+                )?;
+                None
+            }
+            RoverOutput::SubgraphPublishDryRun {
+                graph_ref,
+                subgraph,
+                hints,
+                supergraph_diff,
+            } => {
+                stderrln!(
+                    "The proposed schema for the '{}' subgraph composes with the other subgraphs in '{}'. Nothing was published.",
+                    subgraph,
+                    graph_ref
+                )?;
+                let hint_prefix = Style::HintPrefix.paint("HINT:");
+                for hint in hints {
+                    stderrln!("{} {}", hint_prefix, hint.message)?;
+                }
+                if supergraph_diff.is_empty() {
+                    stderrln!("The supergraph schema would not change")?;
+                    None
+                } else {
+                    stderrln!("The supergraph schema would change:")?;
+                    let mut diff = String::new();
+                    for (marker, definitions) in [
+                        ("+", &supergraph_diff.added),
+                        ("-", &supergraph_diff.removed),
+                        ("~", &supergraph_diff.changed),
+                    ] {
+                        for definition in definitions {
+                            let _ = writeln!(diff, "{marker} {definition}");
+                        }
+                    }
+                    Some(diff.trim_end().to_string())
+                }
+            }
             RoverOutput::SubgraphDeleteResponse {
                 graph_ref,
                 subgraph,
@@ -362,6 +463,7 @@ impl RoverOutput {
                 }
             }
             RoverOutput::SupergraphSchema(csdl) => Some((csdl).to_string()),
+            RoverOutput::ContractSchema(api_schema) => Some(api_schema.to_string()),
             RoverOutput::JsonSchema(schema) => Some(schema.clone()),
             #[cfg(feature = "composition-js")]
             RoverOutput::CompositionResult(composition_output) => {
@@ -415,6 +517,285 @@ impl RoverOutput {
                     details.graph_ref.graph_id()
                 ))
             }
+            RoverOutput::GraphLaunches(details) => {
+                let mut table = table::get_table();
+
+                table.set_header(
+                    vec!["Launch ID", "Created", "Subgraph Changes", "Status"]
+                        .into_iter()
+                        .map(|s| Cell::new(s).set_alignment(Center).add_attribute(Bold)),
+                );
+
+                for launch in &details.launches {
+                    let created_at = launch.created_at.map_or_else(
+                        || "N/A".to_string(),
+                        |dt| {
+                            dt.with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M:%S %Z")
+                                .to_string()
+                        },
+                    );
+                    let subgraph_changes = launch
+                        .subgraph_changes
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    table.add_row(vec![
+                        launch.id.clone(),
+                        created_at,
+                        subgraph_changes,
+                        launch.status.to_string(),
+                    ]);
+                }
+                Some(format!(
+                    "{}\n View full details at {}/graph/{}/launches?variant={}",
+                    table,
+                    details.root_url,
+                    details.graph_ref.graph_id(),
+                    details.graph_ref.variant()
+                ))
+            }
+            RoverOutput::VariantList(details) => {
+                let mut table = table::get_table();
+
+                table.set_header(
+                    vec![
+                        "Name",
+                        "Federation Version",
+                        "Contract Of",
+                        "Persisted Query List",
+                        "Subgraphs",
+                        "Created",
+                    ]
+                    .into_iter()
+                    .map(|s| Cell::new(s).set_alignment(Center).add_attribute(Bold)),
+                );
+
+                for variant in &details.variants {
+                    let created_at = variant.created_at.map_or_else(
+                        || "N/A".to_string(),
+                        |dt| {
+                            dt.with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M:%S %Z")
+                                .to_string()
+                        },
+                    );
+                    table.add_row(vec![
+                        variant.name.clone(),
+                        variant
+                            .federation_version
+                            .clone()
+                            .unwrap_or_else(|| "N/A".to_string()),
+                        variant
+                            .contract
+                            .as_ref()
+                            .map_or_else(String::new, |contract| contract.source_variant.clone()),
+                        variant
+                            .persisted_query_list
+                            .as_ref()
+                            .map_or_else(String::new, |list| list.name.clone()),
+                        variant.subgraphs.len().to_string(),
+                        created_at,
+                    ]);
+                }
+                Some(format!("{table}"))
+            }
+            RoverOutput::VariantDescribe(details) => {
+                let variant = &details.variant;
+                let contract = variant.contract.as_ref().map_or_else(
+                    || "no".to_string(),
+                    |contract| match &contract.filter_description {
+                        Some(description) => format!(
+                            "filtered from '{}'\n{}",
+                            contract.source_variant, description
+                        ),
+                        None => format!("filtered from '{}'", contract.source_variant),
+                    },
+                );
+                let persisted_query_list = variant.persisted_query_list.as_ref().map_or_else(
+                    || "none".to_string(),
+                    |list| format!("{} ({})", list.name, list.id),
+                );
+                let created_at = variant.created_at.map_or_else(
+                    || "N/A".to_string(),
+                    |dt| {
+                        dt.with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M:%S %Z")
+                            .to_string()
+                    },
+                );
+                Some(format!(
+                    "Variant: {graph_ref}\nCreated: {created_at}\nFederation version: {federation_version}\nContract: {contract}\nPersisted query list: {persisted_query_list}\nSubgraphs ({subgraph_count}): {subgraphs}\nView the variant's full configuration at {variant_config}",
+                    graph_ref = details.graph_ref,
+                    federation_version = variant.federation_version.as_deref().unwrap_or("N/A"),
+                    subgraph_count = variant.subgraphs.len(),
+                    subgraphs = variant.subgraphs.join(", "),
+                    variant_config = Style::Link.paint(format!(
+                        "{}/graph/{}/settings/variant?variant={}",
+                        details.frontend_url_root,
+                        details.graph_ref.graph_id(),
+                        details.graph_ref.variant(),
+                    ))
+                ))
+            }
+            RoverOutput::VariantCreate {
+                graph_ref,
+                source_variant,
+                create_response,
+            } => {
+                stderrln!(
+                    "Created '{}' with {} subgraphs copied from the '{}' variant",
+                    graph_ref,
+                    create_response.subgraphs_created.len(),
+                    source_variant
+                )?;
+                if let Some(federation_version) = &create_response.federation_version {
+                    stderrln!("The federation version was set to {}", federation_version)?;
+                }
+                if let Some(launch_url) = &create_response.launch_url {
+                    stderrln!("Monitor your launch at {}", Style::Link.paint(launch_url))?;
+                }
+                if !create_response.build_errors.is_empty() {
+                    let warn_prefix = Style::WarningPrefix.paint("WARN:");
+                    stderrln!("{} The following build errors occurred:", warn_prefix)?;
+                    stderrln!("{}", &create_response.build_errors)?;
+                }
+                None
+            }
+            RoverOutput::ProposalList(details) => {
+                let mut table = table::get_table();
+
+                table.set_header(
+                    vec![
+                        "Name",
+                        "Proposal Variant",
+                        "Source Variant",
+                        "Status",
+                        "Reviews",
+                        "Created",
+                    ]
+                    .into_iter()
+                    .map(|s| Cell::new(s).set_alignment(Center).add_attribute(Bold)),
+                );
+
+                for proposal in &details.proposals {
+                    let created_at = proposal.created_at.map_or_else(
+                        || "N/A".to_string(),
+                        |dt| {
+                            dt.with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M:%S %Z")
+                                .to_string()
+                        },
+                    );
+                    table.add_row(vec![
+                        proposal.display_name.clone(),
+                        proposal.variant.clone(),
+                        proposal.source_variant.clone(),
+                        proposal.status.clone(),
+                        proposal.review_status(),
+                        created_at,
+                    ]);
+                }
+                if details.total_count > details.proposals.len() as i64 {
+                    stderrln!(
+                        "Showing {} of {} proposals. Pass a higher `--limit` to list more.",
+                        details.proposals.len(),
+                        details.total_count
+                    )?;
+                }
+                Some(format!("{table}"))
+            }
+            RoverOutput::ProposalDescribe(proposal) => {
+                let created_at = proposal.created_at.map_or_else(
+                    || "N/A".to_string(),
+                    |dt| {
+                        dt.with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M:%S %Z")
+                            .to_string()
+                    },
+                );
+                let mut output = format!(
+                    "Proposal: {display_name}\nVariant: {variant} (proposes changes to '{source_variant}')\nCreated: {created_at}\nStatus: {status}\nReviews: {reviews}\n",
+                    display_name = proposal.display_name,
+                    variant = proposal.variant,
+                    source_variant = proposal.source_variant,
+                    status = proposal.status,
+                    reviews = proposal.review_status(),
+                );
+                if !proposal.description.is_empty() {
+                    let _ = writeln!(output, "\n{}\n", proposal.description);
+                }
+                let _ = write!(
+                    output,
+                    "View the proposal at {}",
+                    Style::Link.paint(&proposal.url)
+                );
+                Some(output)
+            }
+            RoverOutput::GraphExport {
+                graph_ref,
+                directory,
+                files,
+            } => {
+                stderrln!(
+                    "Exported '{}' to {}",
+                    graph_ref,
+                    Style::Path.paint(directory.as_str())
+                )?;
+                Some(
+                    files
+                        .iter()
+                        .map(|file| file.to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+            RoverOutput::GraphImport {
+                graph_ref,
+                import_response,
+                contract_response,
+                router_config_updated,
+                readme_updated,
+            } => {
+                if let Some(import_response) = import_response {
+                    stderrln!("Published the subgraphs to '{}'", graph_ref)?;
+                    if !import_response.subgraphs_created.is_empty() {
+                        stderrln!(
+                            "Created subgraphs: {}",
+                            import_response.subgraphs_created.join(", ")
+                        )?;
+                    }
+                    if let Some(federation_version) = &import_response.federation_version {
+                        stderrln!("The federation version was set to {}", federation_version)?;
+                    }
+                    if let Some(launch_url) = &import_response.launch_url {
+                        stderrln!("Monitor your launch at {}", Style::Link.paint(launch_url))?;
+                    }
+                    if !import_response.build_errors.is_empty() {
+                        let warn_prefix = Style::WarningPrefix.paint("WARN:");
+                        stderrln!("{} The following build errors occurred:", warn_prefix)?;
+                        stderrln!("{}", &import_response.build_errors)?;
+                    }
+                }
+                if let Some(contract_response) = contract_response {
+                    stderrln!(
+                        "Published the contract configuration to '{}': {}",
+                        graph_ref,
+                        contract_response.config_description
+                    )?;
+                    if let Some(launch_cli_copy) = &contract_response.launch_cli_copy {
+                        stderrln!("{}", launch_cli_copy)?;
+                    }
+                }
+                if *router_config_updated {
+                    stderrln!("Updated the cloud router config")?;
+                }
+                if *readme_updated {
+                    stderrln!("Updated the README")?;
+                }
+                None
+            }
             RoverOutput::TemplateList(templates) => {
                 let mut table = table::get_table();
 
@@ -453,6 +834,16 @@ impl RoverOutput {
                 "Check successfully started with workflow ID: {}\nView full details at {}",
                 check_response.workflow_id, check_response.target_url
             )),
+            RoverOutput::CheckWorkflowPending {
+                workflow_id,
+                target_url,
+            } => {
+                let mut output = format!("Check with workflow ID {workflow_id} is still running");
+                if let Some(target_url) = target_url {
+                    output.push_str(&format!("\nView full details at {target_url}"));
+                }
+                Some(output)
+            }
             RoverOutput::LintResponse(lint_response) => Some(lint_response.get_ariadne()?),
             RoverOutput::Profiles(profiles) => {
                 if profiles.is_empty() {
@@ -460,6 +851,49 @@ impl RoverOutput {
                 }
                 Some(profiles.join("\n"))
             }
+            RoverOutput::ProfileSetting { key, value } => {
+                if value.is_none() {
+                    stderrln!("{key} is not set.")?;
+                }
+                value.clone()
+            }
+            RoverOutput::ProfileSettings(settings) => {
+                if settings.is_empty() {
+                    stderrln!("No settings found.")?;
+                }
+                Some(
+                    settings
+                        .iter()
+                        .map(|(key, value)| format!("{key} = {value}"))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            }
+            RoverOutput::EffectiveConfig(values) => {
+                let mut table = table::get_table();
+
+                table.set_header(
+                    vec!["Option", "Value", "Source"]
+                        .into_iter()
+                        .map(|s| Cell::new(s).set_alignment(Center).add_attribute(Bold)),
+                );
+
+                for value in values {
+                    table.add_row(vec![
+                        value.key.clone(),
+                        value.value.clone().unwrap_or_else(|| "-".to_string()),
+                        value.source.clone(),
+                    ]);
+                }
+
+                Some(format!("{table}"))
+            }
+            RoverOutput::TelemetryPayload { payload, enabled } => {
+                if !enabled {
+                    stderrln!("Telemetry is disabled, so this would not be reported.")?;
+                }
+                Some(serde_json::to_string_pretty(payload)?)
+            }
             RoverOutput::Introspection(introspection_response) => {
                 Some((introspection_response).to_string())
             }
@@ -631,6 +1065,7 @@ impl RoverOutput {
             }
             RoverOutput::FetchResponse(fetch_response) => json!(fetch_response),
             RoverOutput::SupergraphSchema(csdl) => json!({ "core_schema": csdl }),
+            RoverOutput::ContractSchema(api_schema) => json!({ "api_schema": api_schema }),
             RoverOutput::JsonSchema(schema) => Value::String(schema.clone()),
             #[cfg(feature = "composition-js")]
             RoverOutput::CompositionResult(composition_output) => {
@@ -651,6 +1086,27 @@ impl RoverOutput {
                 graph_ref: _,
                 publish_response,
             } => json!(publish_response),
+            RoverOutput::SubgraphPublishDryRun {
+                graph_ref: _,
+                subgraph,
+                hints,
+                supergraph_diff,
+            } => json!({
+                "subgraph": subgraph,
+                "dry_run": true,
+                "hints": hints,
+                "supergraph_diff": supergraph_diff,
+            }),
+            RoverOutput::SubgraphPublishUnchanged {
+                graph_ref: _,
+                subgraph,
+            } => json!({
+                "subgraph": subgraph,
+                "unchanged": true,
+                "subgraph_was_created": false,
+                "subgraph_was_updated": false,
+                "supergraph_was_updated": false,
+            }),
             RoverOutput::SubgraphPublishResponse {
                 graph_ref: _,
                 subgraph: _,
@@ -665,14 +1121,62 @@ impl RoverOutput {
                 json!(delete_response)
             }
             RoverOutput::SubgraphList(list_response) => json!(list_response),
+            RoverOutput::GraphLaunches(launches_response) => json!(launches_response),
+            RoverOutput::VariantList(list_response) => json!(list_response),
+            RoverOutput::VariantDescribe(describe_response) => json!(describe_response),
+            RoverOutput::VariantCreate {
+                graph_ref: _,
+                source_variant,
+                create_response,
+            } => {
+                let mut create_json = json!(create_response);
+                create_json["source_variant"] = json!(source_variant);
+                create_json
+            }
+            RoverOutput::ProposalList(list_response) => json!(list_response),
+            RoverOutput::ProposalDescribe(proposal) => json!(proposal),
+            RoverOutput::GraphExport {
+                graph_ref: _,
+                directory,
+                files,
+            } => json!({ "directory": directory, "files": files }),
+            RoverOutput::GraphImport {
+                graph_ref: _,
+                import_response,
+                contract_response,
+                router_config_updated,
+                readme_updated,
+            } => json!({
+                "subgraphs": import_response,
+                "contract": contract_response,
+                "router_config_updated": router_config_updated,
+                "readme_updated": readme_updated,
+            }),
             RoverOutput::TemplateList(templates) => json!({ "templates": templates }),
             RoverOutput::TemplateUseSuccess { template_id, path } => {
                 json!({ "template_id": template_id, "path": path })
             }
             RoverOutput::CheckWorkflowResponse(check_response) => check_response.get_json(),
             RoverOutput::AsyncCheckResponse(check_response) => check_response.get_json(),
+            RoverOutput::CheckWorkflowPending {
+                workflow_id,
+                target_url,
+            } => {
+                json!({ "workflow_id": workflow_id, "target_url": target_url, "status": "PENDING" })
+            }
             RoverOutput::LintResponse(lint_response) => lint_response.get_json(),
             RoverOutput::Profiles(profiles) => json!({ "profiles": profiles }),
+            RoverOutput::ProfileSetting { key, value } => json!({ "key": key, "value": value }),
+            RoverOutput::ProfileSettings(settings) => json!({
+                "settings": settings
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+                    .collect::<serde_json::Map<String, Value>>()
+            }),
+            RoverOutput::EffectiveConfig(values) => json!({ "options": values }),
+            RoverOutput::TelemetryPayload { payload, enabled } => {
+                json!({ "payload": payload, "enabled": enabled })
+            }
             RoverOutput::Introspection(introspection_response) => {
                 json!({ "introspection_response": introspection_response })
             }
@@ -788,11 +1292,47 @@ impl RoverOutput {
                     None
                 }
             }
+            RoverOutput::GraphImport {
+                import_response: Some(import_response),
+                ..
+            } => {
+                if !import_response.build_errors.is_empty() {
+                    Some(RoverError::from(RoverClientError::BuildErrors {
+                        source: import_response.build_errors.clone(),
+                        num_subgraphs: import_response.subgraphs_created.len(),
+                    }))
+                } else {
+                    None
+                }
+            }
+            RoverOutput::VariantCreate {
+                create_response, ..
+            } => {
+                if !create_response.build_errors.is_empty() {
+                    Some(RoverError::from(RoverClientError::BuildErrors {
+                        source: create_response.build_errors.clone(),
+                        num_subgraphs: create_response.subgraphs_created.len(),
+                    }))
+                } else {
+                    None
+                }
+            }
             _ => None,
         };
         json!(rover_error)
     }
 
+    /// Renders a check or lint result in one of the report formats, like SARIF or Markdown
+    pub(crate) fn get_report(&self, format_kind: RoverOutputFormatKind) -> Option<String> {
+        match self {
+            RoverOutput::CheckWorkflowResponse(check_response) => {
+                format_kind.render_check(check_response)
+            }
+            RoverOutput::LintResponse(lint_response) => format_kind.render_lint(lint_response),
+            _ => None,
+        }
+    }
+
     pub(crate) fn get_json_version(&self) -> JsonVersion {
         match &self {
             Self::CheckWorkflowResponse(_) => JsonVersion::Two,
@@ -820,6 +1360,7 @@ impl RoverOutput {
         match &self {
             RoverOutput::ContractDescribe(_) => Some("Configuration Description"),
             RoverOutput::ContractPublish(_) => Some("New Configuration Description"),
+            RoverOutput::ContractSchema(_) => Some("Contract Schema"),
             RoverOutput::FetchResponse(fetch_response) => match fetch_response.sdl.r#type {
                 SdlType::Graph | SdlType::Subgraph { .. } => Some("Schema"),
                 SdlType::Supergraph => Some("Supergraph Schema"),
@@ -830,7 +1371,9 @@ impl RoverOutput {
             }
             RoverOutput::TemplateUseSuccess { .. } => Some("Project generated"),
             RoverOutput::AsyncCheckResponse(_) => Some("Check Started"),
+            RoverOutput::CheckWorkflowPending { .. } => Some("Check Pending"),
             RoverOutput::Profiles(_) => Some("Profiles"),
+            RoverOutput::TelemetryPayload { .. } => Some("Telemetry Payload"),
             RoverOutput::Introspection(_) => Some("Introspection Response"),
             RoverOutput::ReadmeFetchResponse { .. } => Some("Readme"),
             RoverOutput::GraphPublishResponse { .. } => Some("Schema Hash"),
@@ -850,12 +1393,17 @@ mod tests {
     use console::strip_ansi_codes;
     use rover_client::{
         operations::{
-            graph::publish::{ChangeSummary, FieldChanges, TypeChanges},
+            graph::{
+                launches::{LaunchInfo, LaunchState, LaunchSubgraphChange},
+                publish::{ChangeSummary, FieldChanges, TypeChanges},
+            },
             persisted_queries::publish::PersistedQueriesOperationCounts,
+            proposal::types::ProposalReviewInfo,
             subgraph::{
                 delete::SubgraphDeleteResponse,
                 list::{SubgraphInfo, SubgraphUpdatedAt},
             },
+            variant::list::{VariantContract, VariantInfo},
         },
         shared::{
             ChangeSeverity, CheckTaskStatus, CheckWorkflowResponse, CustomCheckResponse,
@@ -998,6 +1546,190 @@ mod tests {
         assert_json_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn graph_launches_json() {
+        let created_at: DateTime<Utc> = "2024-03-01T10:00:00Z".parse().unwrap();
+        let mock_launches_response = GraphLaunchesResponse {
+            launches: vec![LaunchInfo {
+                id: "launch-1".to_string(),
+                created_at: Some(created_at),
+                status: LaunchState::Superseded,
+                subgraph_changes: vec![LaunchSubgraphChange {
+                    name: "products".to_string(),
+                    change: "modified".to_string(),
+                }],
+            }],
+            root_url: "https://studio.apollographql.com/".to_string(),
+            graph_ref: GraphRef::new("graph", Some("current")).unwrap(),
+        };
+        let actual_json = JsonOutput::from(&RoverOutput::GraphLaunches(mock_launches_response));
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "launches": [
+                    {
+                        "id": "launch-1",
+                        "created_at": created_at,
+                        "status": "superseded",
+                        "subgraph_changes": [
+                            {
+                                "name": "products",
+                                "change": "modified"
+                            }
+                        ]
+                    }
+                ],
+                "success": true
+          },
+          "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn variant_describe_json() {
+        let created_at: DateTime<Utc> = "2024-03-01T10:00:00Z".parse().unwrap();
+        let mock_describe_response = DescribeVariantResponse {
+            graph_ref: GraphRef::new("graph", Some("public")).unwrap(),
+            variant: VariantInfo {
+                name: "public".to_string(),
+                created_at: Some(created_at),
+                federation_version: Some("2.9".to_string()),
+                contract: Some(VariantContract {
+                    source_variant: "current".to_string(),
+                    filter_description: Some("Excludes @internal".to_string()),
+                }),
+                persisted_query_list: None,
+                subgraphs: vec!["products".to_string(), "reviews".to_string()],
+            },
+            frontend_url_root: "https://studio.apollographql.com".to_string(),
+        };
+        let actual_json = JsonOutput::from(&RoverOutput::VariantDescribe(mock_describe_response));
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "name": "public",
+                "created_at": created_at,
+                "federation_version": "2.9",
+                "contract": {
+                    "source_variant": "current",
+                    "filter_description": "Excludes @internal"
+                },
+                "persisted_query_list": null,
+                "subgraphs": ["products", "reviews"],
+                "success": true
+          },
+          "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn proposal_describe_json() {
+        let created_at: DateTime<Utc> = "2024-03-01T10:00:00Z".parse().unwrap();
+        let mock_proposal = ProposalInfo {
+            id: "3f1c".to_string(),
+            display_name: "Add reviews".to_string(),
+            description: "Adds the reviews subgraph".to_string(),
+            status: "open".to_string(),
+            created_at: Some(created_at),
+            source_variant: "current".to_string(),
+            variant: "p-101".to_string(),
+            reviews: vec![ProposalReviewInfo {
+                reviewer: Some("Ada".to_string()),
+                decision: "approved".to_string(),
+            }],
+            url: "https://studio.apollographql.com/graph/graph/proposal/p-101/home".to_string(),
+        };
+        let actual_json = JsonOutput::from(&RoverOutput::ProposalDescribe(mock_proposal));
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "id": "3f1c",
+                "display_name": "Add reviews",
+                "description": "Adds the reviews subgraph",
+                "status": "open",
+                "created_at": created_at,
+                "source_variant": "current",
+                "variant": "p-101",
+                "reviews": [
+                    {
+                        "reviewer": "Ada",
+                        "decision": "approved"
+                    }
+                ],
+                "url": "https://studio.apollographql.com/graph/graph/proposal/p-101/home",
+                "success": true
+          },
+          "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn graph_export_json() {
+        let actual_json = JsonOutput::from(&RoverOutput::GraphExport {
+            graph_ref: GraphRef::new("graph", Some("current")).unwrap(),
+            directory: Utf8PathBuf::from("backup"),
+            files: vec![
+                Utf8PathBuf::from("subgraphs/products.graphql"),
+                Utf8PathBuf::from("supergraph.yaml"),
+                Utf8PathBuf::from("supergraph.graphql"),
+            ],
+        });
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "directory": "backup",
+                "files": [
+                    "subgraphs/products.graphql",
+                    "supergraph.yaml",
+                    "supergraph.graphql"
+                ],
+                "success": true
+          },
+          "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn graph_import_json() {
+        let actual_json = JsonOutput::from(&RoverOutput::GraphImport {
+            graph_ref: GraphRef::new("graph", Some("current")).unwrap(),
+            import_response: Some(GraphImportResponse {
+                subgraphs_created: vec!["products".to_string()],
+                federation_version: Some("2.9".to_string()),
+                build_errors: BuildErrors::new(),
+                launch_url: Some("https://studio.apollographql.com/launches/1".to_string()),
+            }),
+            contract_response: None,
+            router_config_updated: true,
+            readme_updated: false,
+        });
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "subgraphs": {
+                    "subgraphs_created": ["products"],
+                    "federation_version": "2.9",
+                    "launch_url": "https://studio.apollographql.com/launches/1"
+                },
+                "contract": null,
+                "router_config_updated": true,
+                "readme_updated": false,
+                "success": true
+          },
+          "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
     #[test]
     fn subgraph_delete_success_json() {
         let mock_subgraph_delete = SubgraphDeleteResponse {
@@ -1149,11 +1881,13 @@ mod tests {
                         code: "SOMETHING_HAPPENED".to_string(),
                         description: "beeg yoshi".to_string(),
                         severity: ChangeSeverity::PASS,
+                        coordinate: None,
                     },
                     SchemaChange {
                         code: "WOW".to_string(),
                         description: "that was so cool".to_string(),
                         severity: ChangeSeverity::PASS,
+                        coordinate: None,
                     },
                 ],
             )),
@@ -1197,6 +1931,7 @@ mod tests {
                 ],
             }),
             maybe_downstream_response: None,
+            maybe_proposed_schema: None,
         };
 
         let actual_json =
@@ -1302,6 +2037,7 @@ mod tests {
                 violations: vec![],
             }),
             maybe_downstream_response: None,
+            maybe_proposed_schema: None,
         };
 
         let actual_text = RoverOutput::CheckWorkflowResponse(mock_check_response)
@@ -1340,11 +2076,13 @@ View custom check details at: https://studio.apollographql.com/graph/my-graph/va
                         code: "SOMETHING_HAPPENED".to_string(),
                         description: "beeg yoshi".to_string(),
                         severity: ChangeSeverity::FAIL,
+                        coordinate: None,
                     },
                     SchemaChange {
                         code: "WOW".to_string(),
                         description: "that was so cool".to_string(),
                         severity: ChangeSeverity::FAIL,
+                        coordinate: None,
                     },
                 ],
             )),
@@ -1400,6 +2138,7 @@ View custom check details at: https://studio.apollographql.com/graph/my-graph/va
                 ],
             }),
             maybe_downstream_response: None,
+            maybe_proposed_schema: None,
         };
 
         let actual_json =
@@ -1570,6 +2309,59 @@ View custom check details at: https://studio.apollographql.com/graph/my-graph/va
         assert_json_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn subgraph_publish_skipped_unchanged_response_json() {
+        let actual_json = JsonOutput::from(&RoverOutput::SubgraphPublishUnchanged {
+            graph_ref: GraphRef::new("graph", Some("variant")).unwrap(),
+            subgraph: "subgraph".to_string(),
+        });
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "subgraph": "subgraph",
+                "unchanged": true,
+                "subgraph_was_created": false,
+                "subgraph_was_updated": false,
+                "supergraph_was_updated": false,
+                "success": true
+            },
+            "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
+    #[test]
+    fn subgraph_publish_dry_run_response_json() {
+        let actual_json = JsonOutput::from(&RoverOutput::SubgraphPublishDryRun {
+            graph_ref: GraphRef::new("graph", Some("variant")).unwrap(),
+            subgraph: "reviews".to_string(),
+            hints: vec![],
+            supergraph_diff: SdlDiff {
+                added: vec!["type Review".to_string()],
+                removed: vec![],
+                changed: vec!["type Product".to_string()],
+            },
+        });
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "subgraph": "reviews",
+                "dry_run": true,
+                "hints": [],
+                "supergraph_diff": {
+                    "added": ["type Review"],
+                    "removed": [],
+                    "changed": ["type Product"]
+                },
+                "success": true
+            },
+            "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
     #[test]
     fn subgraph_publish_failure_response_json() {
         let mock_publish_response = SubgraphPublishResponse {
@@ -1691,6 +2483,26 @@ View custom check details at: https://studio.apollographql.com/graph/my-graph/va
         assert_json_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn check_workflow_pending_json() {
+        let actual_json = JsonOutput::from(&RoverOutput::CheckWorkflowPending {
+            workflow_id: "1234".to_string(),
+            target_url: Some("https://studio.apollographql.com/graph/mygraph/checks".to_string()),
+        });
+        let expected_json = json!(
+        {
+            "json_version": "1",
+            "data": {
+                "workflow_id": "1234",
+                "target_url": "https://studio.apollographql.com/graph/mygraph/checks",
+                "status": "PENDING",
+                "success": true
+            },
+            "error": null
+        });
+        assert_json_eq!(expected_json, actual_json);
+    }
+
     #[test]
     fn introspection_json() {
         let actual_json = JsonOutput::from(&RoverOutput::Introspection(
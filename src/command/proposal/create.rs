@@ -0,0 +1,80 @@
+use clap::Parser;
+use rover_client::operations::proposal::create::{self, CreateProposalInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use super::{ProposalStatusOpt, ProposalSubgraphOpts};
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Create {
+    // the variant the proposal suggests changes to
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// The title of the proposal
+    #[arg(long)]
+    display_name: String,
+
+    /// A description of the proposal, which can use Markdown
+    #[arg(long)]
+    description: Option<String>,
+
+    #[clap(flatten)]
+    subgraphs: ProposalSubgraphOpts,
+
+    /// The status to create the proposal with. New proposals are drafts by default.
+    #[arg(long, value_enum)]
+    status: Option<ProposalStatusOpt>,
+}
+
+impl Create {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let status = self
+            .status
+            .map(ProposalStatusOpt::into_update_enum)
+            .transpose()?;
+
+        eprintln!(
+            "Creating a proposal against {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let subgraphs = self
+            .subgraphs
+            .resolve(&graph_ref, &client_config, &self.profile, &client)
+            .await?;
+        if subgraphs.is_empty() && !self.subgraphs.is_empty() {
+            eprintln!(
+                "{} No subgraph schemas differ from the ones in {}, so the proposal has no schema changes yet.",
+                Style::WarningPrefix.paint("WARN:"),
+                Style::Link.paint(graph_ref.to_string())
+            );
+        }
+
+        let proposal = create::run(
+            CreateProposalInput {
+                graph_ref,
+                display_name: self.display_name.clone(),
+                description: self.description.clone(),
+                summary: self.subgraphs.summary(&subgraphs),
+                subgraphs,
+                status,
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::ProposalDescribe(proposal))
+    }
+}
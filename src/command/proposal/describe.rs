@@ -0,0 +1,37 @@
+use clap::Parser;
+use rover_client::operations::proposal::describe::{self, DescribeProposalInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Describe {
+    // the proposal's own variant, like `my-graph@p-101`
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+}
+
+impl Describe {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Describing proposal {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let proposal = describe::run(DescribeProposalInput { graph_ref }, &client).await?;
+
+        Ok(RoverOutput::ProposalDescribe(proposal))
+    }
+}
@@ -0,0 +1,65 @@
+use clap::Parser;
+use rover_client::operations::proposal::list::{self, ListProposalsInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use super::ProposalStatusOpt;
+use crate::{
+    RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct List {
+    // only the graph ID is used, so any @<VARIANT> is ignored
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// Only list proposals against this variant. Can be passed more than once.
+    #[arg(long = "source-variant")]
+    source_variants: Vec<String>,
+
+    /// Only list proposals with this status. Can be passed more than once.
+    #[arg(long = "status", value_enum)]
+    statuses: Vec<ProposalStatusOpt>,
+
+    /// The maximum number of proposals to list
+    #[arg(long, default_value_t = 25, value_parser = clap::value_parser!(i64).range(1..=50))]
+    limit: i64,
+}
+
+impl List {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+
+        eprintln!(
+            "Listing proposals for {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.graph_id()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let list_response = list::run(
+            ListProposalsInput {
+                graph_id: graph_ref.graph_id().to_string(),
+                source_variants: (!self.source_variants.is_empty())
+                    .then(|| self.source_variants.clone()),
+                statuses: (!self.statuses.is_empty()).then(|| {
+                    self.statuses
+                        .iter()
+                        .map(|status| status.into_list_enum())
+                        .collect()
+                }),
+                limit: self.limit,
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::ProposalList(list_response))
+    }
+}
@@ -0,0 +1,338 @@
+mod create;
+mod describe;
+mod list;
+mod update;
+
+use std::io;
+
+use anyhow::anyhow;
+use clap::{Parser, ValueEnum};
+use rover_client::{
+    blocking::StudioClient,
+    operations::{
+        proposal::{
+            list::ProposalStatus as ListStatus,
+            update::{ProposalStatus as UpdateStatus, ProposalSubgraph},
+        },
+        subgraph::fetch_all::{self, SubgraphFetchAllInput},
+    },
+};
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverOutput, RoverResult,
+    command::subgraph::{BatchSubgraph, resolve_subgraphs},
+    options::ProfileOpt,
+    utils::{client::StudioClientConfig, parsers::FileDescriptorType, sdl::normalize_sdl},
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Proposal {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Serialize, Parser)]
+pub enum Command {
+    /// Open a proposal against a variant with locally changed subgraph schemas
+    Create(create::Create),
+
+    /// Describe a proposal, including its status and reviews
+    Describe(describe::Describe),
+
+    /// List the proposals for a graph
+    List(list::List),
+
+    /// Propose new subgraph schemas, or change the description or status of a proposal
+    Update(update::Update),
+}
+
+impl Proposal {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        match &self.command {
+            Command::Create(command) => command.run(client_config).await,
+            Command::Describe(command) => command.run(client_config).await,
+            Command::List(command) => command.run(client_config).await,
+            Command::Update(command) => command.run(client_config).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalStatusOpt {
+    Draft,
+    Open,
+    Approved,
+    Closed,
+    Implemented,
+}
+
+impl ProposalStatusOpt {
+    const fn into_list_enum(self) -> ListStatus {
+        match self {
+            Self::Draft => ListStatus::DRAFT,
+            Self::Open => ListStatus::OPEN,
+            Self::Approved => ListStatus::APPROVED,
+            Self::Closed => ListStatus::CLOSED,
+            Self::Implemented => ListStatus::IMPLEMENTED,
+        }
+    }
+
+    /// Only some statuses can be set by hand. A proposal becomes approved through its reviews,
+    /// and implemented once its changes are published to the source variant.
+    fn into_update_enum(self) -> RoverResult<UpdateStatus> {
+        match self {
+            Self::Draft => Ok(UpdateStatus::DRAFT),
+            Self::Open => Ok(UpdateStatus::OPEN),
+            Self::Closed => Ok(UpdateStatus::CLOSED),
+            Self::Approved | Self::Implemented => {
+                let mut err = RoverError::new(anyhow!(
+                    "A proposal's status can't be set to '{}'",
+                    format!("{self:?}").to_lowercase()
+                ));
+                err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                    "Pass `--status draft`, `--status open`, or `--status closed`. Proposals are approved through reviews in GraphOS Studio.".to_string(),
+                ));
+                Err(err)
+            }
+        }
+    }
+}
+
+/// The subgraph schemas to propose, either one subgraph or every subgraph in a supergraph config
+#[derive(Debug, Serialize, Parser)]
+pub struct ProposalSubgraphOpts {
+    /// The name of the subgraph to propose a schema for.
+    #[arg(long = "name", requires = "schema")]
+    #[serde(skip_serializing)]
+    subgraph_name: Option<String>,
+
+    /// The schema file to propose. You can pass `-` to use stdin instead of a file.
+    #[arg(long, short = 's', requires = "subgraph_name")]
+    #[serde(skip_serializing)]
+    schema: Option<FileDescriptorType>,
+
+    /// The path to a supergraph config file listing the subgraphs to propose, instead of
+    /// passing `--name` and `--schema`. Only subgraphs whose schemas differ from the ones
+    /// already published are proposed.
+    #[arg(long = "supergraph-config", conflicts_with_all = ["subgraph_name", "schema"])]
+    #[serde(skip_serializing)]
+    supergraph_config: Option<FileDescriptorType>,
+
+    /// A summary of the proposed schema changes, shown in the proposal's revision history.
+    /// Defaults to listing the changed subgraphs.
+    #[arg(long)]
+    summary: Option<String>,
+}
+
+impl ProposalSubgraphOpts {
+    const fn is_empty(&self) -> bool {
+        self.subgraph_name.is_none() && self.schema.is_none() && self.supergraph_config.is_none()
+    }
+
+    /// Reads the local subgraph schemas, keeping only the ones that differ from the schemas
+    /// currently published to `graph_ref`
+    async fn resolve(
+        &self,
+        graph_ref: &GraphRef,
+        client_config: &StudioClientConfig,
+        profile: &ProfileOpt,
+        client: &StudioClient,
+    ) -> RoverResult<Vec<ProposalSubgraph>> {
+        let local_subgraphs = match (&self.subgraph_name, &self.schema, &self.supergraph_config) {
+            (None, None, None) => return Ok(Vec::new()),
+            (_, _, Some(supergraph_config)) => {
+                let (subgraphs, failures) =
+                    resolve_subgraphs(supergraph_config, client_config, profile).await?;
+                if let Some(failure) = failures.first() {
+                    return Err(RoverError::new(anyhow!(
+                        "Subgraph '{}' {}",
+                        failure.subgraph,
+                        failure.message
+                    )));
+                }
+                subgraphs
+            }
+            (Some(subgraph_name), Some(schema), None) => vec![BatchSubgraph {
+                name: subgraph_name.clone(),
+                schema: schema.read_file_descriptor("SDL", &mut io::stdin())?,
+                routing_url: None,
+            }],
+            _ => unreachable!("clap should require `--name` and `--schema` together"),
+        };
+
+        let published_subgraphs = fetch_all::run(
+            SubgraphFetchAllInput {
+                graph_ref: graph_ref.clone(),
+            },
+            client,
+        )
+        .await?
+        .subgraphs;
+        Ok(local_subgraphs
+            .into_iter()
+            .filter_map(|subgraph| {
+                let published = published_subgraphs
+                    .iter()
+                    .find(|published| published.name() == &subgraph.name);
+                if let Some(published) = published
+                    && normalize_sdl(published.sdl()) == normalize_sdl(&subgraph.schema)
+                {
+                    return None;
+                }
+                Some(ProposalSubgraph {
+                    url: subgraph
+                        .routing_url
+                        .or_else(|| published.and_then(|published| published.url().clone())),
+                    name: subgraph.name,
+                    sdl: subgraph.schema,
+                })
+            })
+            .collect())
+    }
+
+    fn summary(&self, subgraphs: &[ProposalSubgraph]) -> String {
+        self.summary.clone().unwrap_or_else(|| {
+            let names: Vec<&str> = subgraphs
+                .iter()
+                .map(|subgraph| subgraph.name.as_str())
+                .collect();
+            format!("Updated {}", names.join(", "))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::TempDir;
+    use camino::Utf8PathBuf;
+    use clap::{Parser, error::ErrorKind};
+    use houston::Config;
+    use httpmock::{Method::POST, MockServer};
+    use rover_client::{blocking::StudioClient, operations::proposal::update::ProposalSubgraph};
+    use rover_studio::types::GraphRef;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::ProposalSubgraphOpts;
+    use crate::{
+        RoverResult,
+        options::{ProfileOpt, RetryOpts},
+        utils::client::{ClientBuilder, ClientTimeout, StudioClientConfig},
+    };
+
+    const PUBLISHED_SDL: &str = "type Query { reviews: [String] }";
+
+    fn profile() -> ProfileOpt {
+        ProfileOpt {
+            profile_name: "default".to_string(),
+        }
+    }
+
+    fn client(
+        home: &TempDir,
+        uri: Option<String>,
+    ) -> RoverResult<(StudioClientConfig, StudioClient)> {
+        let client_config = StudioClientConfig::new(
+            uri,
+            Config {
+                home: Utf8PathBuf::from_path_buf(home.path().to_path_buf()).unwrap(),
+                override_api_key: Some("user:gh.foo:key".to_string()),
+                credential_passphrase: None,
+            },
+            false,
+            ClientBuilder::default(),
+            Some(ClientTimeout::default()),
+            RetryOpts::default(),
+        );
+        let client = client_config.get_authenticated_client(&profile())?;
+        Ok((client_config, client))
+    }
+
+    /// Resolves `--name reviews --schema <file>` against a variant whose `reviews` subgraph
+    /// is published with `PUBLISHED_SDL`
+    async fn resolve(local_sdl: &str) -> RoverResult<Vec<ProposalSubgraph>> {
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(POST).body_includes("SubgraphFetchAllQuery");
+            then.status(200).json_body(json!({
+                "data": {
+                    "variant": {
+                        "__typename": "GraphVariant",
+                        "subgraphs": [{
+                            "name": "reviews",
+                            "url": "http://reviews",
+                            "activePartialSchema": { "sdl": PUBLISHED_SDL }
+                        }],
+                        "latestLaunch": null,
+                        "sourceVariant": null
+                    }
+                }
+            }));
+        });
+
+        let home = TempDir::new()?;
+        let schema_path = home.path().join("reviews.graphql");
+        std::fs::write(&schema_path, local_sdl)?;
+        let (client_config, client) = client(&home, Some(server.url("/api/graphql")))?;
+        let opts = ProposalSubgraphOpts::parse_from([
+            "proposal",
+            "--name",
+            "reviews",
+            "--schema",
+            schema_path.to_str().unwrap(),
+        ]);
+        let graph_ref = GraphRef::new("mygraph", Some("current"))?;
+        let subgraphs = opts
+            .resolve(&graph_ref, &client_config, &profile(), &client)
+            .await;
+        mock.assert();
+        subgraphs
+    }
+
+    #[tokio::test]
+    async fn it_proposes_changed_subgraphs_at_their_published_url() -> RoverResult<()> {
+        let local_sdl = "type Query { reviews: [String] rating: Int }";
+        let subgraphs = resolve(local_sdl).await?;
+        assert_eq!(
+            subgraphs,
+            vec![ProposalSubgraph {
+                name: "reviews".to_string(),
+                url: Some("http://reviews".to_string()),
+                sdl: local_sdl.to_string(),
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_skips_subgraphs_matching_the_published_schema() -> RoverResult<()> {
+        let subgraphs = resolve("type Query {\n  reviews: [String]\n}\n").await?;
+        assert!(subgraphs.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_proposes_nothing_without_subgraph_options() -> RoverResult<()> {
+        let home = TempDir::new()?;
+        let (client_config, client) = client(&home, None)?;
+        let opts = ProposalSubgraphOpts::parse_from(["proposal"]);
+        let graph_ref = GraphRef::new("mygraph", Some("current"))?;
+        let subgraphs = opts
+            .resolve(&graph_ref, &client_config, &profile(), &client)
+            .await?;
+        assert!(subgraphs.is_empty());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::name_without_schema(&["--name", "reviews"])]
+    #[case::schema_without_name(&["--schema", "reviews.graphql"])]
+    fn it_needs_both_name_and_schema(#[case] args: &[&str]) {
+        let err = ProposalSubgraphOpts::try_parse_from(std::iter::once(&"proposal").chain(args))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::anyhow;
+use clap::Parser;
+use rover_client::operations::proposal::update::{self, UpdateProposalInput};
+use rover_std::Style;
+use serde::Serialize;
+
+use super::{ProposalStatusOpt, ProposalSubgraphOpts};
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverOutput, RoverResult,
+    options::{GraphRefOpt, ProfileOpt},
+    utils::client::StudioClientConfig,
+};
+
+#[derive(Debug, Serialize, Parser)]
+pub struct Update {
+    // the proposal's own variant, like `my-graph@p-101`
+    #[clap(flatten)]
+    graph: GraphRefOpt,
+
+    #[clap(flatten)]
+    profile: ProfileOpt,
+
+    /// A new description for the proposal, which can use Markdown
+    #[arg(long)]
+    description: Option<String>,
+
+    #[clap(flatten)]
+    subgraphs: ProposalSubgraphOpts,
+
+    /// A new status for the proposal
+    #[arg(long, value_enum)]
+    status: Option<ProposalStatusOpt>,
+}
+
+impl Update {
+    pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
+        if self.subgraphs.is_empty() && self.description.is_none() && self.status.is_none() {
+            let mut err = RoverError::new(anyhow!("There is nothing to update"));
+            err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                "Pass subgraph schemas with `--name` and `--schema` or `--supergraph-config`, a new `--description`, or a new `--status`.".to_string(),
+            ));
+            return Err(err);
+        }
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let status = self
+            .status
+            .map(ProposalStatusOpt::into_update_enum)
+            .transpose()?;
+
+        eprintln!(
+            "Updating proposal {} using credentials from the {} profile.",
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Link.paint(&self.profile.profile_name)
+        );
+
+        let subgraphs = self
+            .subgraphs
+            .resolve(&graph_ref, &client_config, &self.profile, &client)
+            .await?;
+        if subgraphs.is_empty() && !self.subgraphs.is_empty() {
+            eprintln!(
+                "{} No subgraph schemas differ from the ones already proposed in {}, so no new revision was created.",
+                Style::WarningPrefix.paint("WARN:"),
+                Style::Link.paint(graph_ref.to_string())
+            );
+        }
+
+        let proposal = update::run(
+            UpdateProposalInput {
+                graph_ref,
+                description: self.description.clone(),
+                summary: self.subgraphs.summary(&subgraphs),
+                subgraphs,
+                status,
+            },
+            &client,
+        )
+        .await?;
+
+        Ok(RoverOutput::ProposalDescribe(proposal))
+    }
+}
@@ -21,22 +21,22 @@ pub struct Fetch {
 impl Fetch {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
-        let graph_ref = self.graph.graph_ref.to_string();
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
 
         eprintln!(
             "Fetching README for {} using credentials from the {} profile.",
-            Style::Link.paint(graph_ref),
+            Style::Link.paint(graph_ref.to_string()),
             Style::Command.paint(&self.profile.profile_name)
         );
         let readme = fetch::run(
             ReadmeFetchInput {
-                graph_ref: self.graph.graph_ref.clone(),
+                graph_ref: graph_ref.clone(),
             },
             &client,
         )
         .await?;
         Ok(RoverOutput::ReadmeFetchResponse {
-            graph_ref: self.graph.graph_ref.clone(),
+            graph_ref,
             content: readme.content,
             last_updated_time: readme.last_updated_time,
         })
@@ -26,10 +26,10 @@ pub struct Publish {
 impl Publish {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
-        let graph_ref = self.graph.graph_ref.to_string();
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
         eprintln!(
             "Publishing README for {} using credentials from the {} profile.",
-            Style::Link.paint(graph_ref),
+            Style::Link.paint(graph_ref.to_string()),
             Style::Command.paint(&self.profile.profile_name)
         );
 
@@ -40,7 +40,7 @@ impl Publish {
 
         let publish_response = publish::run(
             ReadmePublishInput {
-                graph_ref: self.graph.graph_ref.clone(),
+                graph_ref: graph_ref.clone(),
                 readme: new_readme,
             },
             &client,
@@ -48,7 +48,7 @@ impl Publish {
         .await?;
 
         Ok(RoverOutput::ReadmePublishResponse {
-            graph_ref: self.graph.graph_ref.clone(),
+            graph_ref,
             new_content: publish_response.new_content,
             last_updated_time: publish_response.last_updated_time,
         })
@@ -0,0 +1,358 @@
+use std::{future::Future, num::NonZeroUsize};
+
+use comfy_table::{Attribute::Bold, Cell, CellAlignment::Center};
+use futures::{StreamExt, stream};
+use rover_std::Style;
+use rover_studio::types::GraphRef;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    RoverError, RoverOutput, RoverResult,
+    command::CliOutput,
+    options::ProfileOpt,
+    utils::{client::StudioClientConfig, parsers::FileDescriptorType, table},
+};
+
+/// A subgraph from a supergraph config, resolved down to its SDL
+#[derive(Debug, Clone)]
+pub(crate) struct BatchSubgraph {
+    pub(crate) name: String,
+    pub(crate) schema: String,
+    pub(crate) routing_url: Option<String>,
+}
+
+/// Resolves every subgraph in a supergraph config. Subgraphs that can't be resolved come back
+/// as failed results so they still show up in the report.
+#[cfg(feature = "composition-js")]
+pub(crate) async fn resolve_subgraphs(
+    supergraph_config: &FileDescriptorType,
+    client_config: &StudioClientConfig,
+    profile: &ProfileOpt,
+) -> RoverResult<(Vec<BatchSubgraph>, Vec<SubgraphBatchResult>)> {
+    use std::{env::current_dir, fs::canonicalize, io::stdin};
+
+    use anyhow::anyhow;
+    use camino::Utf8PathBuf;
+    use tower::ServiceExt;
+
+    use crate::composition::supergraph::config::{
+        full::introspect::MakeResolveIntrospectSubgraph,
+        resolver::{
+            SupergraphConfigResolver, fetch_remote_subgraph::MakeFetchRemoteSubgraph,
+            fetch_remote_subgraphs::MakeFetchRemoteSubgraphs,
+        },
+    };
+
+    let supergraph_config =
+        match supergraph_config {
+            FileDescriptorType::File(file) => {
+                let file = canonicalize(file)?;
+                FileDescriptorType::File(Utf8PathBuf::from_path_buf(file).map_err(|path| {
+                    anyhow!("{} is not a valid UTF-8 path", path.to_string_lossy())
+                })?)
+            }
+            FileDescriptorType::Stdin => FileDescriptorType::Stdin,
+        };
+    // relative subgraph schema paths are resolved against the directory of the supergraph config
+    let supergraph_root = match &supergraph_config {
+        FileDescriptorType::File(file) => file.parent().map(|parent| parent.to_path_buf()),
+        FileDescriptorType::Stdin => None,
+    };
+    let supergraph_root = match supergraph_root {
+        Some(supergraph_root) => supergraph_root,
+        None => Utf8PathBuf::from_path_buf(current_dir()?)
+            .map_err(|_| anyhow!("The current directory is not a valid UTF-8 path"))?,
+    };
+
+    let fetch_remote_subgraphs_factory = MakeFetchRemoteSubgraphs::builder()
+        .studio_client_config(client_config.clone())
+        .profile(profile.clone())
+        .build();
+    let fetch_remote_subgraph_factory = MakeFetchRemoteSubgraph::builder()
+        .studio_client_config(client_config.clone())
+        .profile(profile.clone())
+        .build()
+        .boxed_clone();
+    let resolve_introspect_subgraph_factory =
+        MakeResolveIntrospectSubgraph::new(client_config.service()?).boxed_clone();
+
+    let (supergraph_config, errors) =
+        SupergraphConfigResolver::load_remote_subgraphs(fetch_remote_subgraphs_factory, None)
+            .await?
+            .load_from_file_descriptor(&mut stdin(), Some(&supergraph_config))?
+            .skip_default_subgraph()
+            .fully_resolve_subgraphs(
+                resolve_introspect_subgraph_factory,
+                fetch_remote_subgraph_factory,
+                &supergraph_root,
+            )
+            .await?;
+
+    let subgraphs = supergraph_config
+        .subgraphs
+        .into_values()
+        .map(|subgraph| BatchSubgraph {
+            name: subgraph.name().to_string(),
+            schema: subgraph.schema().to_string(),
+            routing_url: subgraph.routing_url().clone(),
+        })
+        .collect();
+    let failures = errors
+        .into_iter()
+        .map(|(name, err)| {
+            SubgraphBatchResult::failed(name, format!("could not resolve the schema: {err}"), None)
+        })
+        .collect();
+    Ok((subgraphs, failures))
+}
+
+#[cfg(not(feature = "composition-js"))]
+pub(crate) async fn resolve_subgraphs(
+    _supergraph_config: &FileDescriptorType,
+    _client_config: &StudioClientConfig,
+    _profile: &ProfileOpt,
+) -> RoverResult<(Vec<BatchSubgraph>, Vec<SubgraphBatchResult>)> {
+    Err(RoverError::new(anyhow::anyhow!(
+        "--all is not supported on this platform"
+    )))
+}
+
+/// Runs `run_one` for each subgraph, with at most `max_parallelism` running at the same time
+pub(crate) async fn run_concurrently<F, Fut>(
+    subgraphs: Vec<BatchSubgraph>,
+    max_parallelism: NonZeroUsize,
+    run_one: F,
+) -> Vec<SubgraphBatchResult>
+where
+    F: Fn(BatchSubgraph) -> Fut,
+    Fut: Future<Output = SubgraphBatchResult>,
+{
+    stream::iter(subgraphs)
+        .map(run_one)
+        .buffer_unordered(max_parallelism.get())
+        .collect()
+        .await
+}
+
+/// The outcome of running a command against one subgraph with `--all`
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub(crate) struct SubgraphBatchResult {
+    pub(crate) subgraph: String,
+    pub(crate) success: bool,
+    pub(crate) message: String,
+    pub(crate) target_url: Option<String>,
+}
+
+impl SubgraphBatchResult {
+    pub(crate) fn passed(
+        subgraph: impl Into<String>,
+        message: impl Into<String>,
+        target_url: Option<String>,
+    ) -> Self {
+        Self {
+            subgraph: subgraph.into(),
+            success: true,
+            message: message.into(),
+            target_url,
+        }
+    }
+
+    pub(crate) fn failed(
+        subgraph: impl Into<String>,
+        message: impl Into<String>,
+        target_url: Option<String>,
+    ) -> Self {
+        Self {
+            subgraph: subgraph.into(),
+            success: false,
+            message: message.into(),
+            target_url,
+        }
+    }
+}
+
+/// The aggregated report for `subgraph check --all` and `subgraph publish --all`
+#[derive(Debug, Clone)]
+pub(crate) struct SubgraphBatchOutput {
+    graph_ref: GraphRef,
+    action: &'static str,
+    results: Vec<SubgraphBatchResult>,
+}
+
+impl SubgraphBatchOutput {
+    pub(crate) fn new(
+        graph_ref: GraphRef,
+        action: &'static str,
+        mut results: Vec<SubgraphBatchResult>,
+    ) -> Self {
+        results.sort_by(|a, b| a.subgraph.cmp(&b.subgraph));
+        Self {
+            graph_ref,
+            action,
+            results,
+        }
+    }
+
+    fn failure_count(&self) -> usize {
+        self.results.iter().filter(|result| !result.success).count()
+    }
+
+    /// Succeeds only when every subgraph did; otherwise the report travels with the error so
+    /// it's still printed and the process exits non-zero
+    pub(crate) fn into_result(self) -> RoverResult<RoverOutput> {
+        if self.failure_count() > 0 {
+            Err(RoverError::new(SubgraphBatchFailure { output: self }))
+        } else {
+            Ok(RoverOutput::CliOutput(Box::new(self)))
+        }
+    }
+}
+
+impl CliOutput for SubgraphBatchOutput {
+    fn text(&self) -> String {
+        let mut table = table::get_table();
+        table.set_header(
+            vec!["Subgraph", "Result", "Details"]
+                .into_iter()
+                .map(|s| Cell::new(s).set_alignment(Center).add_attribute(Bold)),
+        );
+        for result in &self.results {
+            let status = if result.success {
+                Style::Success.paint("PASS")
+            } else {
+                Style::Failure.paint("FAIL")
+            };
+            let details = match &result.target_url {
+                Some(target_url) => format!("{}\n{}", result.message, target_url),
+                None => result.message.clone(),
+            };
+            table.add_row(vec![result.subgraph.clone(), status, details]);
+        }
+        format!(
+            "{}\n{} of {} subgraphs {} successfully against {}",
+            table,
+            self.results.len() - self.failure_count(),
+            self.results.len(),
+            self.action,
+            self.graph_ref
+        )
+    }
+
+    fn json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        Ok(json!({
+            "graph_ref": self.graph_ref.to_string(),
+            "action": self.action,
+            "success": self.failure_count() == 0,
+            "subgraphs": serde_json::to_value(&self.results)?,
+        }))
+    }
+}
+
+/// Returned when at least one subgraph failed with `--all`
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "{} of {} subgraphs could not be {}",
+    .output.failure_count(),
+    .output.results.len(),
+    .output.action
+)]
+pub(crate) struct SubgraphBatchFailure {
+    pub(crate) output: SubgraphBatchOutput,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        str::FromStr,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_fails_when_any_subgraph_fails() {
+        let graph_ref = GraphRef::from_str("mygraph@current").unwrap();
+        let output = SubgraphBatchOutput::new(
+            graph_ref.clone(),
+            "checked",
+            vec![
+                SubgraphBatchResult::failed(
+                    "reviews",
+                    "check failed",
+                    Some("https://studio.apollographql.com/check".to_string()),
+                ),
+                SubgraphBatchResult::passed("products", "check passed", None),
+            ],
+        );
+
+        assert_json_eq!(
+            output.json().unwrap(),
+            json!({
+                "graph_ref": "mygraph@current",
+                "action": "checked",
+                "success": false,
+                "subgraphs": [
+                    {
+                        "subgraph": "products",
+                        "success": true,
+                        "message": "check passed",
+                        "target_url": null
+                    },
+                    {
+                        "subgraph": "reviews",
+                        "success": false,
+                        "message": "check failed",
+                        "target_url": "https://studio.apollographql.com/check"
+                    }
+                ]
+            })
+        );
+        let err = output.into_result().unwrap_err();
+        assert_eq!(err.message(), "1 of 2 subgraphs could not be checked");
+
+        let output = SubgraphBatchOutput::new(
+            graph_ref,
+            "checked",
+            vec![SubgraphBatchResult::passed(
+                "products",
+                "check passed",
+                None,
+            )],
+        );
+        assert!(output.into_result().is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_runs_at_most_max_parallelism_subgraphs_at_once() {
+        let subgraphs = (0..10)
+            .map(|i| BatchSubgraph {
+                name: format!("subgraph-{i}"),
+                schema: "type Query { hello: String }".to_string(),
+                routing_url: None,
+            })
+            .collect();
+        let running = AtomicUsize::new(0);
+        let most_running = AtomicUsize::new(0);
+
+        let results = run_concurrently(subgraphs, NonZeroUsize::new(3).unwrap(), |subgraph| {
+            let running = &running;
+            let most_running = &most_running;
+            async move {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                most_running.fetch_max(now_running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                SubgraphBatchResult::passed(subgraph.name, "done", None)
+            }
+        })
+        .await;
+
+        assert_eq!(results.len(), 10);
+        assert_eq!(most_running.load(Ordering::SeqCst), 3);
+    }
+}
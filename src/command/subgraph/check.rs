@@ -1,18 +1,21 @@
+use anyhow::anyhow;
 use clap::Parser;
 use rover_client::{
+    RoverClientError,
     operations::subgraph::{
         check::{self, SubgraphCheckAsyncInput},
         check_workflow::{self, CheckWorkflowInput},
     },
-    shared::{CheckConfig, GitContext},
+    shared::{CheckConfig, GitContext, ProposedSchema},
 };
 use rover_std::Style;
 use serde::Serialize;
 
+use super::batch::{self, SubgraphBatchOutput, SubgraphBatchResult};
 use crate::{
-    RoverOutput, RoverResult,
-    options::{CheckConfigOpts, GraphRefOpt, ProfileOpt, SchemaOpt, SubgraphOpt},
-    utils::client::StudioClientConfig,
+    RoverError, RoverOutput, RoverResult,
+    options::{CheckConfigOpts, GraphRefOpt, ProfileOpt, SubgraphBatchOpts},
+    utils::{client::StudioClientConfig, parsers::FileDescriptorType},
 };
 
 #[derive(Debug, Serialize, Parser)]
@@ -20,18 +23,24 @@ pub struct Check {
     #[clap(flatten)]
     graph: GraphRefOpt,
 
-    #[clap(flatten)]
-    subgraph: SubgraphOpt,
+    /// The name of the subgraph.
+    #[arg(long = "name", required_unless_present = "all")]
+    #[serde(skip_serializing)]
+    subgraph_name: Option<String>,
 
     #[clap(flatten)]
     profile: ProfileOpt,
 
-    #[clap(flatten)]
+    /// The schema file to check. You can pass `-` to use stdin instead of a file.
+    #[arg(long, short = 's', required_unless_present = "all")]
     #[serde(skip_serializing)]
-    schema: SchemaOpt,
+    schema: Option<FileDescriptorType>,
 
     #[clap(flatten)]
     config: CheckConfigOpts,
+
+    #[clap(flatten)]
+    batch: SubgraphBatchOpts,
 }
 
 impl Check {
@@ -41,24 +50,31 @@ impl Check {
         git_context: GitContext,
         checks_timeout_seconds: u64,
     ) -> RoverResult<RoverOutput> {
+        if self.batch.all {
+            return self
+                .run_all(client_config, git_context, checks_timeout_seconds)
+                .await;
+        }
+        let (Some(subgraph_name), Some(schema)) = (&self.subgraph_name, &self.schema) else {
+            unreachable!("clap \"required_unless_present\" should make this impossible to reach")
+        };
         let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
 
-        let proposed_schema = self
-            .schema
-            .read_file_descriptor("SDL", &mut std::io::stdin())?;
+        let proposed_schema = schema.read_file_descriptor("SDL", &mut std::io::stdin())?;
 
         eprintln!(
             "Checking the proposed schema for subgraph {} against {}",
-            &self.subgraph.subgraph_name,
-            Style::Link.paint(self.graph.graph_ref.to_string())
+            subgraph_name,
+            Style::Link.paint(graph_ref.to_string())
         );
 
         let workflow_res = check::run(
             SubgraphCheckAsyncInput {
-                graph_ref: self.graph.graph_ref.clone(),
-                subgraph: self.subgraph.subgraph_name.clone(),
+                graph_ref: graph_ref.clone(),
+                subgraph: subgraph_name.clone(),
                 git_context,
-                proposed_schema,
+                proposed_schema: proposed_schema.clone(),
                 config: CheckConfig {
                     query_count_threshold: self.config.query_count_threshold,
                     query_count_threshold_percentage: self.config.query_percentage_threshold,
@@ -73,11 +89,15 @@ impl Check {
         } else {
             let check_res = check_workflow::run(
                 CheckWorkflowInput {
-                    graph_ref: self.graph.graph_ref.clone(),
+                    graph_ref,
                     workflow_id: workflow_res.workflow_id,
                     checks_timeout_seconds,
+                    proposed_schema: Some(ProposedSchema {
+                        file_name: schema.to_string(),
+                        sdl: proposed_schema,
+                    }),
                 },
-                self.subgraph.subgraph_name.clone(),
+                subgraph_name.clone(),
                 &client,
             )
             .await?;
@@ -85,4 +105,89 @@ impl Check {
             Ok(RoverOutput::CheckWorkflowResponse(check_res))
         }
     }
+
+    async fn run_all(
+        &self,
+        client_config: StudioClientConfig,
+        git_context: GitContext,
+        checks_timeout_seconds: u64,
+    ) -> RoverResult<RoverOutput> {
+        if self.config.background {
+            return Err(RoverError::new(anyhow!(
+                "`--background` can't be combined with `--all`"
+            )));
+        }
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let supergraph_config = self.batch.supergraph_config()?;
+
+        let (subgraphs, failures) =
+            batch::resolve_subgraphs(supergraph_config, &client_config, &self.profile).await?;
+
+        eprintln!(
+            "Checking the proposed schemas for {} subgraphs against {}",
+            subgraphs.len() + failures.len(),
+            Style::Link.paint(graph_ref.to_string())
+        );
+
+        let mut results =
+            batch::run_concurrently(subgraphs, self.batch.max_parallelism, |subgraph| {
+                let client = &client;
+                let graph_ref = graph_ref.clone();
+                let git_context = git_context.clone();
+                let config = CheckConfig {
+                    query_count_threshold: self.config.query_count_threshold,
+                    query_count_threshold_percentage: self.config.query_percentage_threshold,
+                    validation_period: self.config.validation_period.clone(),
+                };
+                async move {
+                    let check_res = async {
+                        let workflow_res = check::run(
+                            SubgraphCheckAsyncInput {
+                                graph_ref: graph_ref.clone(),
+                                subgraph: subgraph.name.clone(),
+                                git_context,
+                                proposed_schema: subgraph.schema,
+                                config,
+                            },
+                            client,
+                        )
+                        .await?;
+                        check_workflow::run(
+                            CheckWorkflowInput {
+                                graph_ref,
+                                workflow_id: workflow_res.workflow_id,
+                                checks_timeout_seconds,
+                                proposed_schema: None,
+                            },
+                            subgraph.name.clone(),
+                            client,
+                        )
+                        .await
+                    }
+                    .await;
+                    match check_res {
+                        Ok(check_res) => SubgraphBatchResult::passed(
+                            subgraph.name,
+                            "check passed",
+                            Some(check_res.default_target_url),
+                        ),
+                        Err(RoverClientError::CheckWorkflowFailure { check_response, .. }) => {
+                            SubgraphBatchResult::failed(
+                                subgraph.name,
+                                "check failed",
+                                Some(check_response.default_target_url),
+                            )
+                        }
+                        Err(err) => {
+                            SubgraphBatchResult::failed(subgraph.name, err.to_string(), None)
+                        }
+                    }
+                }
+            })
+            .await;
+        results.extend(failures);
+
+        SubgraphBatchOutput::new(graph_ref, "checked", results).into_result()
+    }
 }
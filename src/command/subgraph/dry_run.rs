@@ -0,0 +1,126 @@
+use apollo_federation_types::rover::BuildHint;
+use rover_client::blocking::StudioClient;
+use rover_studio::types::GraphRef;
+
+use crate::{RoverResult, options::PluginOpts, utils::client::StudioClientConfig};
+
+/// What a supergraph would look like if a subgraph's proposed schema were published
+pub(crate) struct ComposedPreview {
+    pub(crate) supergraph_sdl: String,
+    pub(crate) hints: Vec<BuildHint>,
+}
+
+/// Composes the subgraphs currently in the variant, with `subgraph` swapped for the proposed
+/// `schema`, using the variant's federation version. Nothing is published.
+#[cfg(feature = "composition-js")]
+pub(crate) async fn compose_preview(
+    client: &StudioClient,
+    client_config: StudioClientConfig,
+    graph_ref: &GraphRef,
+    subgraph: &str,
+    schema: String,
+    routing_url: Option<String>,
+    plugin_opts: PluginOpts,
+) -> RoverResult<ComposedPreview> {
+    use std::collections::BTreeMap;
+
+    use apollo_federation_types::config::{SchemaSource, SubgraphConfig};
+    use camino::Utf8PathBuf;
+    use rover_client::operations::subgraph::fetch_all::{self, SubgraphFetchAllInput};
+
+    use crate::{
+        composition::get_supergraph_binary,
+        config::SupergraphConfigYaml,
+        utils::{
+            effect::{
+                exec::TokioCommand,
+                write_file::{FsWriteFile, WriteFile},
+            },
+            parsers::FileDescriptorType,
+        },
+    };
+
+    let fetch_all_response = fetch_all::run(
+        SubgraphFetchAllInput {
+            graph_ref: graph_ref.clone(),
+        },
+        client,
+    )
+    .await?;
+
+    let mut subgraphs: BTreeMap<String, SubgraphConfig> = fetch_all_response
+        .subgraphs
+        .into_iter()
+        .map(|remote| {
+            (
+                remote.name().clone(),
+                SubgraphConfig {
+                    routing_url: remote.url().clone(),
+                    schema: SchemaSource::Sdl {
+                        sdl: remote.sdl().clone(),
+                    },
+                },
+            )
+        })
+        .collect();
+    let routing_url = routing_url.or_else(|| {
+        subgraphs
+            .get(subgraph)
+            .and_then(|current| current.routing_url.clone())
+    });
+    subgraphs.insert(
+        subgraph.to_string(),
+        SubgraphConfig {
+            routing_url,
+            schema: SchemaSource::Sdl { sdl: schema },
+        },
+    );
+    let supergraph_config = SupergraphConfigYaml {
+        subgraphs,
+        federation_version: fetch_all_response.federation_version.clone(),
+    };
+
+    let tmp_dir = tempfile::Builder::new().prefix("supergraph").tempdir()?;
+    let supergraph_config_path = Utf8PathBuf::from_path_buf(tmp_dir.path().join("supergraph.yaml"))
+        .expect("Unable to parse path");
+    let write_file_impl = FsWriteFile::default();
+    write_file_impl
+        .write_file(
+            &supergraph_config_path,
+            serde_yaml::to_string(&supergraph_config)?.as_bytes(),
+        )
+        .await?;
+
+    let composition_pipeline = get_supergraph_binary(
+        fetch_all_response.federation_version,
+        client_config,
+        None,
+        plugin_opts,
+        Some(FileDescriptorType::File(supergraph_config_path)),
+        None,
+    )
+    .await?;
+    let composition_success = composition_pipeline
+        .compose(&TokioCommand::default(), &write_file_impl)
+        .await?;
+
+    Ok(ComposedPreview {
+        supergraph_sdl: composition_success.supergraph_sdl().clone(),
+        hints: composition_success.hints().to_vec(),
+    })
+}
+
+#[cfg(not(feature = "composition-js"))]
+pub(crate) async fn compose_preview(
+    _client: &StudioClient,
+    _client_config: StudioClientConfig,
+    _graph_ref: &GraphRef,
+    _subgraph: &str,
+    _schema: String,
+    _routing_url: Option<String>,
+    _plugin_opts: PluginOpts,
+) -> RoverResult<ComposedPreview> {
+    Err(crate::RoverError::new(anyhow::anyhow!(
+        "--dry-run is not supported on this platform"
+    )))
+}
@@ -1,5 +1,8 @@
 use clap::Parser;
-use rover_client::operations::subgraph::fetch::{self, SubgraphFetchInput};
+use rover_client::operations::subgraph::{
+    fetch::{self, SubgraphFetchInput},
+    fetch_launch::{self, SubgraphFetchLaunchInput},
+};
 use rover_std::Style;
 use serde::Serialize;
 
@@ -19,27 +22,50 @@ pub struct Fetch {
 
     #[clap(flatten)]
     profile: ProfileOpt,
+
+    /// Fetch the subgraph SDL as it was in this launch instead of the latest one.
+    /// Run `rover graph launches` to list recent launch IDs.
+    #[arg(long)]
+    launch_id: Option<String>,
 }
 
 impl Fetch {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
-        let graph_ref = self.graph.graph_ref.to_string();
-        eprintln!(
-            "Fetching SDL from {} (subgraph: {}) using credentials from the {} profile.",
-            Style::Link.paint(graph_ref),
-            Style::Link.paint(&self.subgraph.subgraph_name),
-            Style::Command.paint(&self.profile.profile_name)
-        );
-
-        let fetch_response = fetch::run(
-            SubgraphFetchInput {
-                graph_ref: self.graph.graph_ref.clone(),
-                subgraph_name: self.subgraph.subgraph_name.clone(),
-            },
-            &client,
-        )
-        .await?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let fetch_response = if let Some(launch_id) = &self.launch_id {
+            eprintln!(
+                "Fetching SDL from {} (subgraph: {}, launch: {}) using credentials from the {} profile.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Link.paint(&self.subgraph.subgraph_name),
+                Style::Link.paint(launch_id),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+            fetch_launch::run(
+                SubgraphFetchLaunchInput {
+                    graph_ref,
+                    subgraph_name: self.subgraph.subgraph_name.clone(),
+                    launch_id: launch_id.clone(),
+                },
+                &client,
+            )
+            .await?
+        } else {
+            eprintln!(
+                "Fetching SDL from {} (subgraph: {}) using credentials from the {} profile.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Link.paint(&self.subgraph.subgraph_name),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+            fetch::run(
+                SubgraphFetchInput {
+                    graph_ref,
+                    subgraph_name: self.subgraph.subgraph_name.clone(),
+                },
+                &client,
+            )
+            .await?
+        };
 
         Ok(RoverOutput::FetchResponse(fetch_response))
     }
@@ -1,8 +1,9 @@
-use std::{collections::HashMap, time::Duration};
+use std::collections::HashMap;
 
 use clap::Parser;
 use reqwest::Client;
 use rover_client::operations::subgraph::introspect::{self, SubgraphIntrospectInput};
+use rover_http::RetryConfig;
 use serde::Serialize;
 
 use crate::{
@@ -21,13 +22,12 @@ impl Introspect {
         &self,
         client: Client,
         output_opts: &OutputOpts,
-        retry_period: Duration,
+        retry: RetryConfig,
     ) -> RoverResult<RoverOutput> {
         if self.opts.watch {
-            self.exec_and_watch(&client, output_opts, retry_period)
-                .await
+            self.exec_and_watch(&client, output_opts, &retry).await
         } else {
-            let sdl = self.exec(&client, true, retry_period).await?;
+            let sdl = self.exec(&client, true, &retry).await?;
             Ok(RoverOutput::Introspection(sdl))
         }
     }
@@ -36,7 +36,7 @@ impl Introspect {
         &self,
         client: &Client,
         should_retry: bool,
-        retry_period: Duration,
+        retry: &RetryConfig,
     ) -> RoverResult<String> {
         // add the flag headers to a hashmap to pass along to rover-client
         let mut headers = HashMap::new();
@@ -50,7 +50,7 @@ impl Introspect {
             SubgraphIntrospectInput {
                 headers,
                 should_retry,
-                retry_period,
+                retry: retry.clone(),
                 endpoint: self.opts.endpoint.clone(),
             },
             client,
@@ -65,10 +65,10 @@ impl Introspect {
         &self,
         client: &Client,
         output_opts: &OutputOpts,
-        retry_period: Duration,
+        retry: &RetryConfig,
     ) -> ! {
         self.opts
-            .exec_and_watch(|| self.exec(client, false, retry_period), output_opts)
+            .exec_and_watch(|| self.exec(client, false, retry), output_opts)
             .await
     }
 }
@@ -21,20 +21,15 @@ pub struct List {
 impl List {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
 
         eprintln!(
             "Listing subgraphs for {} using credentials from the {} profile.",
-            Style::Link.paint(self.graph.graph_ref.to_string()),
+            Style::Link.paint(graph_ref.to_string()),
             Style::Link.paint(&self.profile.profile_name)
         );
 
-        let list_details = list::run(
-            SubgraphListInput {
-                graph_ref: self.graph.graph_ref.clone(),
-            },
-            &client,
-        )
-        .await?;
+        let list_details = list::run(SubgraphListInput { graph_ref }, &client).await?;
 
         Ok(RoverOutput::SubgraphList(list_details))
     }
@@ -1,11 +1,15 @@
+mod batch;
 mod check;
+mod check_status;
 mod delete;
+mod dry_run;
 mod fetch;
 pub mod introspect;
 mod lint;
 mod list;
 mod publish;
 
+pub(crate) use batch::{BatchSubgraph, SubgraphBatchFailure, resolve_subgraphs};
 use clap::Parser;
 use rover_client::shared::GitContext;
 use serde::Serialize;
@@ -21,9 +25,13 @@ pub struct Subgraph {
 #[derive(Debug, Serialize, Parser)]
 pub enum Command {
     /// Check for build errors and breaking changes caused by an updated subgraph schema
-    /// against the federated graph in the Apollo graph registry
+    /// against the federated graph in the Apollo graph registry, or use `--all` to check
+    /// every subgraph in a supergraph config
     Check(check::Check),
 
+    /// Fetch the result of a subgraph check started with `--background`, by its workflow ID
+    CheckStatus(check_status::CheckStatus),
+
     /// Delete a subgraph from the Apollo registry and trigger composition in the graph router
     Delete(delete::Delete),
 
@@ -39,7 +47,8 @@ pub enum Command {
     /// List all subgraphs for a federated graph
     List(list::List),
 
-    /// Publish an updated subgraph schema to the Apollo graph registry and trigger composition in the graph router
+    /// Publish an updated subgraph schema to the Apollo graph registry and trigger composition in the graph router,
+    /// or use `--all` to publish every subgraph in a supergraph config
     Publish(publish::Publish),
 }
 
@@ -57,13 +66,16 @@ impl Subgraph {
                     .run(client_config, git_context, checks_timeout_seconds)
                     .await
             }
+            Command::CheckStatus(command) => {
+                command.run(client_config, checks_timeout_seconds).await
+            }
             Command::Delete(command) => command.run(client_config).await,
             Command::Introspect(command) => {
                 command
                     .run(
                         client_config.get_reqwest_client()?,
                         output_opts,
-                        client_config.client_timeout().get_duration(),
+                        client_config.retry_config(),
                     )
                     .await
             }
@@ -5,19 +5,34 @@ use clap::Parser;
 use futures::Future;
 use reqwest::Url;
 use rover_client::{
-    operations::subgraph::{
-        publish::{self, SubgraphPublishInput},
-        routing_url::{self, SubgraphRoutingUrlInput},
+    RoverClientError,
+    blocking::StudioClient,
+    operations::{
+        subgraph::{
+            fetch::{self, SubgraphFetchInput},
+            publish::{self, SubgraphPublishInput},
+            routing_url::{self, SubgraphRoutingUrlInput},
+        },
+        supergraph::fetch::{self as supergraph_fetch, SupergraphFetchInput},
     },
     shared::GitContext,
 };
 use rover_std::Style;
+use rover_studio::types::GraphRef;
 use serde::Serialize;
 
+use super::{
+    batch::{self, SubgraphBatchOutput, SubgraphBatchResult},
+    dry_run,
+};
 use crate::{
     RoverError, RoverErrorSuggestion, RoverOutput, RoverResult,
-    options::{GraphRefOpt, ProfileOpt, SchemaOpt, SubgraphOpt},
-    utils::client::StudioClientConfig,
+    options::{GraphRefOpt, LicenseAccepter, PluginOpts, ProfileOpt, SubgraphBatchOpts},
+    utils::{
+        client::StudioClientConfig,
+        parsers::FileDescriptorType,
+        sdl::{diff_sdl, normalize_sdl},
+    },
 };
 
 #[derive(Debug, Serialize, Parser)]
@@ -25,15 +40,18 @@ pub struct Publish {
     #[clap(flatten)]
     graph: GraphRefOpt,
 
-    #[clap(flatten)]
-    subgraph: SubgraphOpt,
+    /// The name of the subgraph.
+    #[arg(long = "name", required_unless_present = "all")]
+    #[serde(skip_serializing)]
+    subgraph_name: Option<String>,
 
     #[clap(flatten)]
     profile: ProfileOpt,
 
-    #[clap(flatten)]
+    /// The schema file to publish. You can pass `-` to use stdin instead of a file.
+    #[arg(long, short = 's', required_unless_present = "all")]
     #[serde(skip_serializing)]
-    schema: SchemaOpt,
+    schema: Option<FileDescriptorType>,
 
     /// Indicate whether to convert a non-federated graph into a subgraph
     #[arg(short, long)]
@@ -56,6 +74,30 @@ pub struct Publish {
     /// This is shorthand for `--routing-url "" --allow-invalid-routing-url`.
     #[arg(long)]
     no_url: bool,
+
+    /// Skip the publish, and the launch it would trigger, when the schema is semantically the
+    /// same as the one already published. Differences in whitespace, comments, and the order
+    /// of definitions are ignored.
+    #[arg(long)]
+    skip_if_unchanged: bool,
+
+    /// Compose the proposed schema with the other subgraphs currently in the variant and
+    /// report build errors, hints, and how the supergraph schema would change, without
+    /// publishing anything
+    #[arg(long, conflicts_with = "all")]
+    dry_run: bool,
+
+    /// Skip the update check for the composition plugin used by `--dry-run`.
+    ///
+    /// Passing this flag will attempt to use the latest compatible version of the plugin already installed on this machine.
+    #[arg(long = "skip-update", requires = "dry_run")]
+    skip_update: bool,
+
+    #[clap(flatten)]
+    elv2_license_accepter: LicenseAccepter,
+
+    #[clap(flatten)]
+    batch: SubgraphBatchOpts,
 }
 
 impl Publish {
@@ -64,7 +106,14 @@ impl Publish {
         client_config: StudioClientConfig,
         git_context: GitContext,
     ) -> RoverResult<RoverOutput> {
+        if self.batch.all {
+            return self.run_all(client_config, git_context).await;
+        }
+        let (Some(subgraph_name), Some(schema)) = (&self.subgraph_name, &self.schema) else {
+            unreachable!("clap \"required_unless_present\" should make this impossible to reach")
+        };
         let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
 
         let url = Self::determine_routing_url(
             self.no_url,
@@ -73,8 +122,8 @@ impl Publish {
             || async {
                 Ok(routing_url::run(
                     SubgraphRoutingUrlInput {
-                        graph_ref: self.graph.graph_ref.clone(),
-                        subgraph_name: self.subgraph.subgraph_name.clone(),
+                        graph_ref: graph_ref.clone(),
+                        subgraph_name: subgraph_name.clone(),
                     },
                     &client,
                 )
@@ -86,23 +135,82 @@ impl Publish {
         )
         .await?;
 
-        eprintln!(
-            "Publishing SDL to {} (subgraph: {}) using credentials from the {} profile.",
-            Style::Link.paint(self.graph.graph_ref.to_string()),
-            Style::Link.paint(&self.subgraph.subgraph_name),
-            Style::Command.paint(&self.profile.profile_name)
-        );
+        if self.dry_run {
+            eprintln!(
+                "Composing {} with the proposed SDL for subgraph {} using credentials from the {} profile. Nothing will be published.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Link.paint(subgraph_name),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+        } else {
+            eprintln!(
+                "Publishing SDL to {} (subgraph: {}) using credentials from the {} profile.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Link.paint(subgraph_name),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+        }
+
+        let schema = schema.read_file_descriptor("SDL", &mut std::io::stdin())?;
 
-        let schema = self
-            .schema
-            .read_file_descriptor("SDL", &mut std::io::stdin())?;
+        if self.dry_run {
+            let preview = dry_run::compose_preview(
+                &client,
+                client_config,
+                &graph_ref,
+                subgraph_name,
+                schema,
+                url,
+                PluginOpts {
+                    profile: self.profile.clone(),
+                    elv2_license_accepter: self.elv2_license_accepter,
+                    skip_update: self.skip_update,
+                },
+            )
+            .await?;
+            // without a successful build there is no supergraph to compare against
+            let current_supergraph = match supergraph_fetch::run(
+                SupergraphFetchInput {
+                    graph_ref: graph_ref.clone(),
+                },
+                &client,
+            )
+            .await
+            {
+                Ok(fetch_response) => fetch_response.sdl.contents,
+                Err(RoverClientError::NoSupergraphBuilds { .. }) => String::new(),
+                Err(err) => return Err(err.into()),
+            };
+            return Ok(RoverOutput::SubgraphPublishDryRun {
+                graph_ref,
+                subgraph: subgraph_name.clone(),
+                hints: preview.hints,
+                supergraph_diff: diff_sdl(&current_supergraph, &preview.supergraph_sdl),
+            });
+        }
+
+        // an explicit routing url still needs publishing if it's different from the current one
+        let url_to_compare = if self.routing_url.is_some() || self.no_url {
+            url.as_deref()
+        } else {
+            None
+        };
+        if self.skip_if_unchanged
+            && Self::is_unchanged(&client, &graph_ref, subgraph_name, &schema, url_to_compare)
+                .await?
+        {
+            return Ok(RoverOutput::SubgraphPublishUnchanged {
+                graph_ref,
+                subgraph: subgraph_name.clone(),
+            });
+        }
 
         tracing::debug!("Publishing \n{}", &schema);
 
         let publish_response = publish::run(
             SubgraphPublishInput {
-                graph_ref: self.graph.graph_ref.clone(),
-                subgraph: self.subgraph.subgraph_name.clone(),
+                graph_ref: graph_ref.clone(),
+                subgraph: subgraph_name.clone(),
                 url,
                 schema,
                 git_context,
@@ -113,12 +221,191 @@ impl Publish {
         .await?;
 
         Ok(RoverOutput::SubgraphPublishResponse {
-            graph_ref: self.graph.graph_ref.clone(),
-            subgraph: self.subgraph.subgraph_name.clone(),
+            graph_ref,
+            subgraph: subgraph_name.clone(),
             publish_response,
         })
     }
 
+    async fn run_all(
+        &self,
+        client_config: StudioClientConfig,
+        git_context: GitContext,
+    ) -> RoverResult<RoverOutput> {
+        if self.routing_url.is_some() {
+            let mut err =
+                RoverError::new(anyhow!("`--routing-url` can't be combined with `--all`"));
+            err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                "Set `routing_url` for each subgraph in your supergraph config instead."
+                    .to_string(),
+            ));
+            return Err(err);
+        }
+        let client = client_config.get_authenticated_client(&self.profile)?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let supergraph_config = self.batch.supergraph_config()?;
+
+        let (subgraphs, failures) =
+            batch::resolve_subgraphs(supergraph_config, &client_config, &self.profile).await?;
+
+        eprintln!(
+            "Publishing SDL for {} subgraphs to {} using credentials from the {} profile.",
+            subgraphs.len() + failures.len(),
+            Style::Link.paint(graph_ref.to_string()),
+            Style::Command.paint(&self.profile.profile_name)
+        );
+
+        let mut results =
+            batch::run_concurrently(subgraphs, self.batch.max_parallelism, |subgraph| {
+                let client = &client;
+                let graph_ref = graph_ref.clone();
+                let git_context = git_context.clone();
+                async move {
+                    // there's no one to answer a prompt for each of many subgraphs, so an invalid
+                    // routing url fails that subgraph the same way it would outside of a TTY
+                    let url = if self.no_url {
+                        Ok(Some("".to_string()))
+                    } else if self.allow_invalid_routing_url {
+                        Ok(subgraph.routing_url.clone())
+                    } else {
+                        Self::handle_maybe_invalid_routing_url(
+                            &subgraph.routing_url,
+                            &mut io::stderr(),
+                            &mut io::stdin(),
+                            false,
+                        )
+                        .map(|_| subgraph.routing_url.clone())
+                    };
+                    let url = match url {
+                        Ok(url) => url,
+                        Err(err) => {
+                            return SubgraphBatchResult::failed(subgraph.name, err.message(), None);
+                        }
+                    };
+                    if self.skip_if_unchanged {
+                        match Self::is_unchanged(
+                            client,
+                            &graph_ref,
+                            &subgraph.name,
+                            &subgraph.schema,
+                            url.as_deref(),
+                        )
+                        .await
+                        {
+                            Ok(true) => {
+                                return SubgraphBatchResult::passed(
+                                    subgraph.name,
+                                    "unchanged",
+                                    None,
+                                );
+                            }
+                            Ok(false) => {}
+                            Err(err) => {
+                                return SubgraphBatchResult::failed(
+                                    subgraph.name,
+                                    err.message(),
+                                    None,
+                                );
+                            }
+                        }
+                    }
+                    let publish_res = publish::run(
+                        SubgraphPublishInput {
+                            graph_ref,
+                            subgraph: subgraph.name.clone(),
+                            url,
+                            schema: subgraph.schema,
+                            git_context,
+                            convert_to_federated_graph: self.convert,
+                        },
+                        client,
+                    )
+                    .await;
+                    match publish_res {
+                        // the schema was stored, but the supergraph didn't build, so the variant
+                        // isn't serving it yet
+                        Ok(publish_response) if !publish_response.build_errors.is_empty() => {
+                            SubgraphBatchResult::failed(
+                                subgraph.name,
+                                format!(
+                                    "published with {}",
+                                    publish_response.build_errors.length_string()
+                                ),
+                                publish_response.launch_url,
+                            )
+                        }
+                        Ok(publish_response) => {
+                            let message = if publish_response.subgraph_was_created {
+                                "created"
+                            } else if publish_response.subgraph_was_updated {
+                                "updated"
+                            } else {
+                                "no changes detected"
+                            };
+                            SubgraphBatchResult::passed(
+                                subgraph.name,
+                                message,
+                                publish_response.launch_url,
+                            )
+                        }
+                        Err(err) => {
+                            SubgraphBatchResult::failed(subgraph.name, err.to_string(), None)
+                        }
+                    }
+                }
+            })
+            .await;
+        results.extend(failures);
+
+        SubgraphBatchOutput::new(graph_ref, "published", results).into_result()
+    }
+
+    /// Whether `schema` matches the SDL already published for the subgraph and, when a routing
+    /// url is being set, whether that matches the current one too
+    async fn is_unchanged(
+        client: &StudioClient,
+        graph_ref: &GraphRef,
+        subgraph: &str,
+        schema: &str,
+        url: Option<&str>,
+    ) -> RoverResult<bool> {
+        let published = match fetch::run(
+            SubgraphFetchInput {
+                graph_ref: graph_ref.clone(),
+                subgraph_name: subgraph.to_string(),
+            },
+            client,
+        )
+        .await
+        {
+            Ok(fetch_response) => fetch_response.sdl.contents,
+            // nothing has been published yet, so there's nothing to compare against
+            Err(
+                RoverClientError::NoSubgraphInGraph { .. }
+                | RoverClientError::GraphNotFound { .. }
+                | RoverClientError::ExpectedFederatedGraph { .. },
+            ) => return Ok(false),
+            Err(err) => return Err(err.into()),
+        };
+        if normalize_sdl(&published) != normalize_sdl(schema) {
+            return Ok(false);
+        }
+        match url {
+            Some(url) => {
+                let current_url = routing_url::run(
+                    SubgraphRoutingUrlInput {
+                        graph_ref: graph_ref.clone(),
+                        subgraph_name: subgraph.to_string(),
+                    },
+                    client,
+                )
+                .await?;
+                Ok(current_url == url)
+            }
+            None => Ok(true),
+        }
+    }
+
     async fn determine_routing_url<F, G>(
         no_url: bool,
         routing_url: &Option<String>,
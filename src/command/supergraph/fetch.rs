@@ -1,5 +1,8 @@
 use clap::Parser;
-use rover_client::operations::supergraph::fetch::{self, SupergraphFetchInput};
+use rover_client::operations::supergraph::{
+    fetch::{self, SupergraphFetchInput},
+    fetch_launch::{self, SupergraphFetchLaunchInput},
+};
 use rover_std::Style;
 use serde::Serialize;
 
@@ -16,25 +19,40 @@ pub struct Fetch {
 
     #[clap(flatten)]
     profile: ProfileOpt,
+
+    /// Fetch the supergraph SDL built by this launch instead of the latest one.
+    /// Run `rover graph launches` to list recent launch IDs.
+    #[arg(long)]
+    launch_id: Option<String>,
 }
 
 impl Fetch {
     pub async fn run(&self, client_config: StudioClientConfig) -> RoverResult<RoverOutput> {
         let client = client_config.get_authenticated_client(&self.profile)?;
-        let graph_ref = self.graph.graph_ref.to_string();
-        eprintln!(
-            "Fetching supergraph SDL from {} using credentials from the {} profile.",
-            Style::Link.paint(graph_ref),
-            Style::Command.paint(&self.profile.profile_name)
-        );
-
-        let fetch_response = fetch::run(
-            SupergraphFetchInput {
-                graph_ref: self.graph.graph_ref.clone(),
-            },
-            &client,
-        )
-        .await?;
+        let graph_ref = self.graph.resolve(&self.profile, &client_config.config)?;
+        let fetch_response = if let Some(launch_id) = &self.launch_id {
+            eprintln!(
+                "Fetching supergraph SDL from {} (launch: {}) using credentials from the {} profile.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Link.paint(launch_id),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+            fetch_launch::run(
+                SupergraphFetchLaunchInput {
+                    graph_ref,
+                    launch_id: launch_id.clone(),
+                },
+                &client,
+            )
+            .await?
+        } else {
+            eprintln!(
+                "Fetching supergraph SDL from {} using credentials from the {} profile.",
+                Style::Link.paint(graph_ref.to_string()),
+                Style::Command.paint(&self.profile.profile_name)
+            );
+            fetch::run(SupergraphFetchInput { graph_ref }, &client).await?
+        };
 
         Ok(RoverOutput::FetchResponse(fetch_response))
     }
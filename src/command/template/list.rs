@@ -1,4 +1,5 @@
 use clap::Parser;
+use reqwest::Client;
 use serde::Serialize;
 
 use super::templates::list_templates;
@@ -11,8 +12,8 @@ pub struct List {
 }
 
 impl List {
-    pub async fn run(&self) -> RoverResult<RoverOutput> {
-        let templates = list_templates(self.options.language.clone()).await?;
+    pub async fn run(&self, client: &Client) -> RoverResult<RoverOutput> {
+        let templates = list_templates(client, self.options.language.clone()).await?;
         Ok(RoverOutput::TemplateList(templates))
     }
 }
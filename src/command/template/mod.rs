@@ -6,6 +6,7 @@ mod r#use;
 
 use clap::Parser;
 pub use list::List;
+use reqwest::Client;
 use serde::Serialize;
 pub use r#use::Use;
 
@@ -27,10 +28,10 @@ enum Command {
 }
 
 impl Template {
-    pub(crate) async fn run(&self) -> RoverResult<RoverOutput> {
+    pub(crate) async fn run(&self, client: Client) -> RoverResult<RoverOutput> {
         match &self.command {
-            Command::Use(use_template) => use_template.run().await,
-            Command::List(list) => list.run().await,
+            Command::Use(use_template) => use_template.run(&client).await,
+            Command::List(list) => list.run(&client).await,
         }
     }
 }
@@ -13,10 +13,13 @@ use super::queries::{
 };
 use crate::{RoverError, RoverErrorSuggestion, RoverResult, options::ProjectLanguage};
 
-async fn request<Body: Serialize, Data: DeserializeOwned>(body: &Body) -> RoverResult<Data> {
+async fn request<Body: Serialize, Data: DeserializeOwned>(
+    client: &Client,
+    body: &Body,
+) -> RoverResult<Data> {
     let uri = env::var("APOLLO_TEMPLATES_API")
         .unwrap_or_else(|_| "https://rover.apollo.dev/templates".to_string());
-    let resp = Client::new()
+    let resp = client
         .post(uri)
         .json(body)
         .send()
@@ -32,34 +35,39 @@ async fn request<Body: Serialize, Data: DeserializeOwned>(body: &Body) -> RoverR
 }
 
 /// Get a template by ID
-pub async fn get_template(template_id: &str) -> RoverResult<Option<GetTemplateByIdTemplate>> {
+pub async fn get_template(
+    client: &Client,
+    template_id: &str,
+) -> RoverResult<Option<GetTemplateByIdTemplate>> {
     use super::queries::get_template_by_id::*;
     let query = GetTemplateById::build_query(Variables {
         id: template_id.to_string(),
     });
-    let resp: ResponseData = request(&query).await?;
+    let resp: ResponseData = request(client, &query).await?;
     Ok(resp.template)
 }
 
 pub async fn get_templates_for_language(
+    client: &Client,
     language: ProjectLanguage,
 ) -> RoverResult<Vec<GetTemplatesForLanguageTemplates>> {
     use super::queries::get_templates_for_language::*;
     let query = GetTemplatesForLanguage::build_query(Variables {
         language: Some(language.into()),
     });
-    let resp: ResponseData = request(&query).await?;
+    let resp: ResponseData = request(client, &query).await?;
     error_if_empty(resp.templates)
 }
 
 pub async fn list_templates(
+    client: &Client,
     language: Option<ProjectLanguage>,
 ) -> RoverResult<Vec<ListTemplatesForLanguageTemplates>> {
     use super::queries::list_templates_for_language::*;
     let query = ListTemplatesForLanguage::build_query(Variables {
         language: language.map(Into::into),
     });
-    let resp: ResponseData = request(&query).await?;
+    let resp: ResponseData = request(client, &query).await?;
     error_if_empty(resp.templates)
 }
 
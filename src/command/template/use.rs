@@ -7,6 +7,7 @@ use anyhow::{Context, anyhow};
 use camino::Utf8PathBuf;
 use clap::{CommandFactory, Parser, error::ErrorKind as ClapErrorKind};
 use dialoguer::Input;
+use reqwest::Client;
 use serde::Serialize;
 
 use super::templates::{get_template, get_templates_for_language, selection_prompt};
@@ -33,11 +34,11 @@ pub struct Use {
 }
 
 impl Use {
-    pub async fn run(&self) -> RoverResult<RoverOutput> {
+    pub async fn run(&self, client: &Client) -> RoverResult<RoverOutput> {
         // find the template to extract
         let (template_id, download_url) = if let Some(template_id) = &self.template {
             // if they specify an ID, get it
-            let result = get_template(template_id).await?;
+            let result = get_template(client, template_id).await?;
             if let Some(result) = result {
                 (template_id.clone(), result.download_url)
             } else {
@@ -50,7 +51,7 @@ impl Use {
         } else {
             // otherwise, ask them what language they want to use
             let project_language = self.options.get_or_prompt_language()?;
-            let templates = get_templates_for_language(project_language).await?;
+            let templates = get_templates_for_language(client, project_language).await?;
             let template = selection_prompt(templates)?;
             (template.id, template.download_url)
         };
@@ -59,7 +60,7 @@ impl Use {
         let path = self.get_or_prompt_path()?;
 
         // download and extract a tarball from github
-        download_template(download_url, &path).await?;
+        download_template(client, download_url, &path).await?;
         Ok(RoverOutput::TemplateUseSuccess { template_id, path })
     }
 
@@ -52,6 +52,8 @@ pub(crate) async fn get_supergraph_binary(
     supergraph_yaml: Option<FileDescriptorType>,
     graph_ref: Option<GraphRef>,
 ) -> Result<CompositionPipeline<Run>, RoverError> {
+    let federation_version =
+        plugin_opts.federation_version(federation_version, &client_config.config)?;
     let profile = plugin_opts.profile;
 
     let fetch_remote_subgraphs_factory = MakeFetchRemoteSubgraphs::builder()
@@ -84,6 +84,7 @@ impl Default for CompositionPipeline<state::Init> {
 }
 
 impl CompositionPipeline<state::Init> {
+    #[tracing::instrument(skip_all)]
     pub async fn init<S>(
         self,
         read_stdin_impl: &mut impl ReadStdin,
@@ -151,6 +152,7 @@ impl CompositionPipeline<state::Init> {
 }
 
 impl CompositionPipeline<state::ResolveFederationVersion> {
+    #[tracing::instrument(skip_all)]
     pub async fn resolve_federation_version(
         self,
         resolve_introspect_subgraph_factory: ResolveIntrospectSubgraphFactory,
@@ -200,6 +202,10 @@ impl CompositionPipeline<state::ResolveFederationVersion> {
     }
 }
 impl CompositionPipeline<state::InstallSupergraph> {
+    #[tracing::instrument(
+        skip_all,
+        fields(federation_version = %self.state.federation_version)
+    )]
     pub async fn install_supergraph_binary(
         self,
         studio_client_config: StudioClientConfig,
@@ -225,6 +231,7 @@ impl CompositionPipeline<state::InstallSupergraph> {
 }
 
 impl CompositionPipeline<state::Run> {
+    #[tracing::instrument(skip_all)]
     pub async fn compose(
         &self,
         exec_command_impl: &impl ExecCommand,
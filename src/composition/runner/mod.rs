@@ -4,15 +4,15 @@
 #![warn(missing_docs)]
 
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Debug,
 };
 
 use camino::Utf8PathBuf;
 use futures::stream::{BoxStream, StreamExt, select};
 use rover_http::HttpService;
-use tokio::sync::broadcast;
-use tokio_stream::wrappers::BroadcastStream;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::{BroadcastStream, UnboundedReceiverStream};
 use tower::ServiceExt;
 
 use self::state::SetupSubgraphWatchers;
@@ -28,7 +28,10 @@ use super::{
             resolver::fetch_remote_subgraph::FetchRemoteSubgraphFactory,
         },
     },
-    watchers::{composition::CompositionWatcher, subgraphs::SubgraphWatchers},
+    watchers::{
+        composition::{CompositionInputEvent, CompositionWatcher},
+        subgraphs::SubgraphWatchers,
+    },
 };
 use crate::{
     composition::{
@@ -38,7 +41,13 @@ use crate::{
         },
         watchers::{
             federation::FederationWatcher,
-            watcher::{file::FileWatcher, supergraph_config::SupergraphConfigWatcher},
+            watcher::{
+                file::FileWatcher,
+                supergraph_config::{
+                    SupergraphConfigDiff, SupergraphConfigSerialisationError,
+                    SupergraphConfigWatcher,
+                },
+            },
         },
     },
     subtask::{Subtask, SubtaskRunStream},
@@ -187,6 +196,25 @@ impl Runner<state::SetupCompositionWatcher> {
     }
 }
 
+/// A subset of the supergraph's subgraphs that's composed separately from the rest, so that
+/// several routers can be served from the same set of subgraph watchers
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompositionSlice {
+    /// Tells the slices apart, and names the directory the slice is composed in
+    pub name: String,
+    /// The subgraphs to compose, or `None` for every subgraph
+    pub subgraphs: Option<BTreeSet<String>>,
+}
+
+impl CompositionSlice {
+    /// Whether the named subgraph is part of this slice
+    pub fn includes(&self, subgraph: &str) -> bool {
+        self.subgraphs
+            .as_ref()
+            .is_none_or(|subgraphs| subgraphs.contains(subgraph))
+    }
+}
+
 /// Alias for a [`Runner`] that is ready to be run
 pub(crate) type CompositionRunner<ExecC, WriteF> = Runner<state::Run<ExecC, WriteF>>;
 
@@ -215,6 +243,100 @@ where
             None,
         );
 
+        Self::run_watchers(
+            tx,
+            rx,
+            subgraph_watcher_subtask,
+            federation_watcher_subtask,
+            self.state.supergraph_config_watcher,
+        );
+
+        composition_messages.boxed()
+    }
+
+    /// Runs the [`Runner`], composing each of `slices` separately from the same set of subgraph
+    /// watchers. Returns one stream of composition events per slice, in the same order.
+    pub fn run_slices(
+        self,
+        slices: &[CompositionSlice],
+    ) -> Vec<BoxStream<'static, CompositionEvent>>
+    where
+        ExecC: Clone,
+        WriteF: Clone,
+    {
+        tracing::info!("Watching subgraphs for changes...");
+        let (tx, rx) = broadcast::channel(100);
+
+        let (subgraph_change_stream, subgraph_watcher_subtask) =
+            Subtask::new(self.state.subgraph_watchers);
+
+        let (federation_watcher_stream, federation_watcher_subtask) =
+            Subtask::new(FederationWatcher {});
+
+        let mut slice_senders = Vec::with_capacity(slices.len());
+        let mut slice_messages = Vec::with_capacity(slices.len());
+        for slice in slices {
+            let (slice_tx, slice_rx) = mpsc::unbounded_channel();
+            let (composition_messages, composition_subtask) =
+                Subtask::new(self.state.composition_watcher.clone().for_slice(slice));
+            composition_subtask.run(UnboundedReceiverStream::new(slice_rx).boxed(), None);
+            slice_senders.push((slice.clone(), slice_tx));
+            slice_messages.push(composition_messages.boxed());
+        }
+
+        // Fan the input events out to every slice they're relevant to
+        let mut input = select(subgraph_change_stream, federation_watcher_stream);
+        tokio::task::spawn(async move {
+            while let Some(event) = input.next().await {
+                match event {
+                    CompositionInputEvent::Subgraph(subgraph_event) => {
+                        for (slice, sender) in &slice_senders {
+                            if slice.includes(subgraph_event.name()) {
+                                let _ = sender
+                                    .send(CompositionInputEvent::Subgraph(subgraph_event.clone()));
+                            }
+                        }
+                    }
+                    CompositionInputEvent::Federation(federation_version) => {
+                        for (_, sender) in &slice_senders {
+                            let _ = sender.send(CompositionInputEvent::Federation(
+                                federation_version.clone(),
+                            ));
+                        }
+                    }
+                    CompositionInputEvent::Recompose() => {
+                        for (_, sender) in &slice_senders {
+                            let _ = sender.send(CompositionInputEvent::Recompose());
+                        }
+                    }
+                    // Passthrough events are informational, so only the first slice reports them
+                    CompositionInputEvent::Passthrough(event) => {
+                        if let Some((_, sender)) = slice_senders.first() {
+                            let _ = sender.send(CompositionInputEvent::Passthrough(event));
+                        }
+                    }
+                }
+            }
+        });
+
+        Self::run_watchers(
+            tx,
+            rx,
+            subgraph_watcher_subtask,
+            federation_watcher_subtask,
+            self.state.supergraph_config_watcher,
+        );
+
+        slice_messages
+    }
+
+    fn run_watchers(
+        tx: broadcast::Sender<Result<SupergraphConfigDiff, SupergraphConfigSerialisationError>>,
+        rx: broadcast::Receiver<Result<SupergraphConfigDiff, SupergraphConfigSerialisationError>>,
+        subgraph_watcher_subtask: Subtask<SubgraphWatchers, CompositionInputEvent>,
+        federation_watcher_subtask: Subtask<FederationWatcher, CompositionInputEvent>,
+        supergraph_config_watcher: Option<SupergraphConfigWatcher>,
+    ) {
         // Start subgraph watchers, listening for events from the supergraph change stream.
         subgraph_watcher_subtask.run(
             BroadcastStream::new(rx)
@@ -249,14 +371,12 @@ where
         // Only run the supergraph config watcher if a config file was provided.
         // When using --graph-ref without a local supergraph config, we still need
         // composition to work, but we won't watch for config file changes.
-        if let Some(supergraph_config_watcher) = self.state.supergraph_config_watcher {
+        if let Some(supergraph_config_watcher) = supergraph_config_watcher {
             supergraph_config_watcher.run(tx);
         } else {
             tracing::warn!(
                 "No supergraph config detected, changes to subgraph configurations will not be applied automatically"
             );
         }
-
-        composition_messages.boxed()
     }
 }
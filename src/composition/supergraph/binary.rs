@@ -36,6 +36,7 @@ pub struct SupergraphBinary {
 }
 
 impl SupergraphBinary {
+    #[tracing::instrument(skip_all, fields(exe = %self.exe))]
     pub async fn compose(
         &self,
         exec_impl: &impl ExecCommand,
@@ -126,6 +127,7 @@ impl SupergraphBinary {
             })
     }
 
+    #[tracing::instrument(skip_all, fields(exe = %self.exe))]
     pub async fn run_connector(
         &self,
         exec_impl: &impl ExecCommand,
@@ -161,6 +163,7 @@ impl SupergraphBinary {
     }
 
     #[expect(clippy::too_many_arguments)]
+    #[tracing::instrument(skip_all, fields(exe = %self.exe))]
     pub async fn test_connector(
         &self,
         exec_impl: &impl ExecCommand,
@@ -224,6 +227,7 @@ impl SupergraphBinary {
     }
 
     #[cfg(target_os = "macos")]
+    #[tracing::instrument(skip_all, fields(exe = %self.exe))]
     pub async fn generate_connector(
         &self,
         exec_impl: &impl ExecCommand,
@@ -553,6 +557,7 @@ mod tests {
     use crate::{
         command::supergraph::compose::do_compose::SupergraphComposeOpts,
         composition::{supergraph::version::SupergraphVersion, test::default_composition_json},
+        options::RetryOpts,
         utils::{
             client::{ClientBuilder, ClientTimeout, StudioClientConfig},
             effect::exec::MockExecCommand,
@@ -580,13 +585,15 @@ mod tests {
         let config = Config {
             home: Utf8PathBuf::from_path_buf(home.path().to_path_buf()).unwrap(),
             override_api_key: None,
+            credential_passphrase: None,
         };
         StudioClientConfig::new(
             None,
             config,
             false,
             ClientBuilder::default(),
-            ClientTimeout::default(),
+            Some(ClientTimeout::default()),
+            RetryOpts::default(),
         )
     }
 
@@ -12,7 +12,10 @@
 //!      from [`SupergraphBinary`]. This must be written to a file first, using the format defined
 //!      by [`SupergraphConfig`]
 
-use std::{collections::BTreeMap, io::IsTerminal};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::IsTerminal,
+};
 
 use anyhow::Context;
 use apollo_federation_types::config::{ConfigError, SchemaSource, SubgraphConfig};
@@ -280,6 +283,11 @@ pub enum ResolveSupergraphConfigError {
 pub type InitializedSupergraphConfigResolver = SupergraphConfigResolver<state::ResolveSubgraphs>;
 
 impl SupergraphConfigResolver<state::ResolveSubgraphs> {
+    /// The names of the subgraphs in the supergraph config
+    pub fn subgraph_names(&self) -> BTreeSet<String> {
+        self.state.subgraphs.keys().cloned().collect()
+    }
+
     /// Fully resolves the subgraph configurations in the supergraph config file to their SDLs
     pub async fn fully_resolve_subgraphs(
         &self,
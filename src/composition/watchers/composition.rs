@@ -18,6 +18,7 @@ use crate::{
         CompositionSubgraphAdded, CompositionSubgraphRemoved, CompositionSuccess,
         FederationUpdaterConfig,
         events::CompositionEvent,
+        runner::CompositionSlice,
         supergraph::{
             binary::SupergraphBinary,
             config::{
@@ -50,7 +51,7 @@ pub enum CompositionInputEvent {
     Passthrough(CompositionEvent),
 }
 
-#[derive(Builder, Debug)]
+#[derive(Builder, Debug, Clone)]
 pub struct CompositionWatcher<ExecC, WriteF> {
     initial_supergraph_config: FullyResolvedSupergraphConfig,
     initial_resolution_errors: BTreeMap<String, ResolveSubgraphError>,
@@ -235,6 +236,20 @@ where
     }
 }
 
+impl<ExecC, WriteF> CompositionWatcher<ExecC, WriteF> {
+    /// Narrows this watcher down to the subgraphs in `slice`, composing into a directory of its
+    /// own so that several slices can be composed side-by-side
+    pub fn for_slice(mut self, slice: &CompositionSlice) -> Self {
+        self.initial_supergraph_config
+            .subgraphs
+            .retain(|name, _| slice.includes(name));
+        self.initial_resolution_errors
+            .retain(|name, _| slice.includes(name));
+        self.temp_dir = self.temp_dir.join(&slice.name);
+        self
+    }
+}
+
 impl<ExecC, WriteF> CompositionWatcher<ExecC, WriteF>
 where
     ExecC: 'static + ExecCommand + Send + Sync,
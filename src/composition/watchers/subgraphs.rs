@@ -97,6 +97,7 @@ impl SubgraphWatchers {
 /// Events about watched subgraphs. If they're changed, the subgraph's name and changed SDL are
 /// emitted via SubgraphChanged. If they're removed, a SubgraphRemoved event is emitted with the
 /// name of the subgraph
+#[derive(Clone)]
 pub enum SubgraphEvent {
     /// A change to the watched subgraph
     SubgraphSchemaChanged(SubgraphSchemaChanged),
@@ -105,6 +106,17 @@ pub enum SubgraphEvent {
     /// The subgraph is no longer watched
     SubgraphRemoved(SubgraphSchemaRemoved),
 }
+
+impl SubgraphEvent {
+    /// The name of the subgraph the event is about
+    pub fn name(&self) -> &str {
+        match self {
+            SubgraphEvent::SubgraphSchemaChanged(changed) => changed.name(),
+            SubgraphEvent::RoutingUrlChanged(changed) => changed.name(),
+            SubgraphEvent::SubgraphRemoved(removed) => removed.name(),
+        }
+    }
+}
 /// An event denoting that the subgraph has changed, emitting its name and the SDL reflecting that
 /// change
 #[derive(derive_getters::Getters, Eq, PartialEq, Debug, Clone)]
@@ -159,14 +171,14 @@ impl From<FullyResolvedSubgraph> for SubgraphSchemaChanged {
     }
 }
 
-#[derive(derive_getters::Getters, Default)]
+#[derive(derive_getters::Getters, Default, Clone)]
 pub struct SubgraphRoutingUrlChanged {
     name: String,
     routing_url: Option<String>,
 }
 
 /// The subgraph is no longer watched
-#[derive(derive_getters::Getters, Default)]
+#[derive(derive_getters::Getters, Default, Clone)]
 pub struct SubgraphSchemaRemoved {
     /// The name of the removed subgraph
     name: String,
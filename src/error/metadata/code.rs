@@ -54,6 +54,11 @@ pub enum RoverErrorCode {
     E043,
     E044,
     E045,
+    E046,
+    E047,
+    E048,
+    E049,
+    E050,
 }
 
 impl Display for RoverErrorCode {
@@ -247,6 +252,26 @@ impl RoverErrorCode {
                 RoverErrorCode::E045,
                 include_str!("./codes/E045.md").to_string(),
             ),
+            (
+                RoverErrorCode::E046,
+                include_str!("./codes/E046.md").to_string(),
+            ),
+            (
+                RoverErrorCode::E047,
+                include_str!("./codes/E047.md").to_string(),
+            ),
+            (
+                RoverErrorCode::E048,
+                include_str!("./codes/E048.md").to_string(),
+            ),
+            (
+                RoverErrorCode::E049,
+                include_str!("./codes/E049.md").to_string(),
+            ),
+            (
+                RoverErrorCode::E050,
+                include_str!("./codes/E050.md").to_string(),
+            ),
         ];
         contents.into_iter().collect()
     }
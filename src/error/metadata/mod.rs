@@ -399,6 +399,40 @@ impl From<&mut anyhow::Error> for RoverErrorMetadata {
                     Some(RoverErrorSuggestion::SubmitIssue),
                     Some(RoverErrorCode::E026),
                 ),
+                HoustonProblem::UnknownProfileSetting(_) => (
+                    Some(RoverErrorSuggestion::Adhoc(format!(
+                        "The available settings are: {}.",
+                        houston::ProfileSettings::KEYS.join(", ")
+                    ))),
+                    Some(RoverErrorCode::E049),
+                ),
+                HoustonProblem::InvalidProfileSetting { key, .. } => (
+                    Some(RoverErrorSuggestion::Adhoc(format!(
+                        "Fix the value with `rover config set {key} <VALUE>`, or remove it with `rover config set {key} --unset`."
+                    ))),
+                    Some(RoverErrorCode::E050),
+                ),
+                HoustonProblem::CredentialStoreUnavailable { .. } => (
+                    Some(RoverErrorSuggestion::Adhoc(
+                        "Make sure the credential store is installed and working, or choose a different one with `rover config auth --store`.".to_string(),
+                    )),
+                    Some(RoverErrorCode::E046),
+                ),
+                HoustonProblem::MissingCredentialPassphrase(_) => (
+                    Some(RoverErrorSuggestion::Adhoc(format!(
+                        "Set ${} to the passphrase you chose when running `rover config auth`.",
+                        RoverEnvKey::CredentialsPassphrase
+                    ))),
+                    Some(RoverErrorCode::E047),
+                ),
+                HoustonProblem::CredentialDecryption(profile_name) => (
+                    Some(RoverErrorSuggestion::Adhoc(format!(
+                        "Check that ${} is the passphrase the API key was saved with, or run `rover config auth --profile {}` to save it again.",
+                        RoverEnvKey::CredentialsPassphrase,
+                        profile_name
+                    ))),
+                    Some(RoverErrorCode::E048),
+                ),
                 HoustonProblem::AdhocError(_) => (None, None),
                 HoustonProblem::RoverStdError(_) => (None, None),
             };
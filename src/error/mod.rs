@@ -17,7 +17,11 @@ use rover_std::Style;
 use serde::{Serialize, Serializer, ser::SerializeStruct};
 use serde_json::{Value, json};
 
-use crate::options::JsonVersion;
+use crate::{
+    cli::RoverOutputFormatKind,
+    command::{CliOutput, subgraph::SubgraphBatchFailure},
+    options::JsonVersion,
+};
 
 /// A specialized `Error` type for Rover that wraps `anyhow`
 /// and provides some extra `Metadata` for end users depending
@@ -97,11 +101,30 @@ impl RoverError {
             }
             _ => (),
         }
+        if let Some(SubgraphBatchFailure { output }) =
+            self.error.downcast_ref::<SubgraphBatchFailure>()
+        {
+            stdoutln!("{}", output.text())?;
+        }
 
         stderr!("{}", self)?;
         Ok(())
     }
 
+    /// Renders a failed check or lint in one of the report formats, like SARIF or Markdown
+    pub(crate) fn get_report(&self, format_kind: RoverOutputFormatKind) -> Option<String> {
+        match self.error.downcast_ref::<RoverClientError>() {
+            Some(RoverClientError::CheckWorkflowFailure {
+                graph_ref: _,
+                check_response,
+            }) => format_kind.render_check(check_response),
+            Some(RoverClientError::LintFailures { lint_response }) => {
+                format_kind.render_lint(lint_response)
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn get_internal_data_json(&self) -> Value {
         match self.error.downcast_ref::<RoverClientError>() {
             Some(RoverClientError::CheckWorkflowFailure {
@@ -109,7 +132,10 @@ impl RoverError {
                 check_response,
             }) => check_response.get_json(),
             Some(RoverClientError::LintFailures { lint_response }) => lint_response.get_json(),
-            _ => Value::Null,
+            _ => match self.error.downcast_ref::<SubgraphBatchFailure>() {
+                Some(SubgraphBatchFailure { output }) => output.json().unwrap_or(Value::Null),
+                None => Value::Null,
+            },
         }
     }
 
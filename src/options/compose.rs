@@ -1,8 +1,13 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use apollo_federation_types::config::FederationVersion;
 use clap::Parser;
+use houston::Profile;
 use serde::Serialize;
 
 use super::ProfileOpt;
-use crate::options::LicenseAccepter;
+use crate::{RoverResult, options::LicenseAccepter};
 
 #[cfg_attr(test, derive(Default))]
 #[derive(Debug, Clone, Serialize, Parser)]
@@ -19,3 +24,30 @@ pub struct PluginOpts {
     #[arg(long = "skip-update")]
     pub skip_update: bool,
 }
+
+impl PluginOpts {
+    /// Falls back to the profile's `federation_version` setting when no federation version
+    /// was passed in.
+    pub fn federation_version(
+        &self,
+        federation_version: Option<FederationVersion>,
+        config: &houston::Config,
+    ) -> RoverResult<Option<FederationVersion>> {
+        if federation_version.is_some() {
+            return Ok(federation_version);
+        }
+        let settings = Profile::get_settings(&self.profile.profile_name, config)?;
+        let federation_version = settings
+            .federation_version
+            .map(|version| {
+                FederationVersion::from_str(&version).with_context(|| {
+                    format!(
+                        "Invalid federation_version '{version}' in the settings for profile '{}'. Must be 'latest-0', 'latest-2', or an exact version preceded with an '='.",
+                        self.profile
+                    )
+                })
+            })
+            .transpose()?;
+        Ok(federation_version)
+    }
+}
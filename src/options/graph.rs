@@ -1,14 +1,85 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
 use clap::Parser;
+use houston::Profile;
 use rover_studio::types::GraphRef;
 use serde::{Deserialize, Serialize};
 
+use super::ProfileOpt;
+use crate::{
+    RoverError, RoverErrorSuggestion, RoverResult,
+    utils::{
+        env::RoverEnvKey,
+        project_config::{ProjectConfig, file_source},
+    },
+};
+
 #[derive(Debug, Serialize, Deserialize, Parser)]
 pub struct GraphRefOpt {
     /// <NAME>@<VARIANT> of graph in Apollo Studio.
-    /// @<VARIANT> may be left off, defaulting to @current
+    /// @<VARIANT> may be left off, defaulting to @current.
+    /// Defaults to `graph_ref` in `.rover.toml`, then to the profile's `graph_ref` setting,
+    /// when left off entirely
     #[arg(value_name = "GRAPH_REF")]
     #[serde(skip_serializing)]
-    pub graph_ref: GraphRef,
+    pub graph_ref: Option<GraphRef>,
+}
+
+impl GraphRefOpt {
+    /// Returns the graph ref to use, from the first of:
+    ///
+    /// 1. `$APOLLO_GRAPH_REF`, inside a project with a `.rover.toml`
+    /// 2. the command line
+    /// 3. `graph_ref` in `.rover.toml`
+    /// 4. the profile's `graph_ref` setting, saved with `rover config set graph_ref`
+    pub fn resolve(&self, profile: &ProfileOpt, config: &houston::Config) -> RoverResult<GraphRef> {
+        match self.resolve_with_source(profile, config)? {
+            Some((graph_ref, _)) => Ok(graph_ref),
+            None => {
+                let mut err = RoverError::new(anyhow!("No GRAPH_REF was provided."));
+                err.set_suggestion(RoverErrorSuggestion::Adhoc(format!(
+                    "Pass a graph ref like `my-graph@current`, add `graph_ref` to `.rover.toml`, or set a default for this profile with `rover config set graph_ref <GRAPH_REF> --profile {profile}`."
+                )));
+                Err(err)
+            }
+        }
+    }
+
+    /// Like [`GraphRefOpt::resolve`], but also says where the graph ref came from, and returns
+    /// `None` when there isn't one.
+    pub fn resolve_with_source(
+        &self,
+        profile: &ProfileOpt,
+        config: &houston::Config,
+    ) -> RoverResult<Option<(GraphRef, String)>> {
+        let env_key = RoverEnvKey::GraphRef;
+        if let Some(graph_ref) = ProjectConfig::env_value(env_key) {
+            let graph_ref = GraphRef::from_str(&graph_ref)
+                .map_err(|e| RoverError::new(anyhow!("Invalid graph ref in ${env_key}: {e}")))?;
+            return Ok(Some((graph_ref, format!("${env_key}"))));
+        }
+        if let Some(graph_ref) = &self.graph_ref {
+            return Ok(Some((graph_ref.clone(), "command line".to_string())));
+        }
+        // only a command that falls back to `.rover.toml` needs it to be valid
+        if let Some(project_config) = ProjectConfig::current()?
+            && let Some(graph_ref) = &project_config.graph_ref
+        {
+            let graph_ref = GraphRef::from_str(graph_ref).map_err(|e| {
+                RoverError::new(anyhow!("Invalid graph_ref in {}: {e}", project_config.path))
+            })?;
+            return Ok(Some((graph_ref, file_source(project_config))));
+        }
+        let profile_name = &profile.profile_name;
+        match Profile::get_settings(profile_name, config)?.graph_ref {
+            Some(graph_ref) => Ok(Some((
+                GraphRef::from_str(&graph_ref)?,
+                format!("profile setting ({profile_name})"),
+            ))),
+            None => Ok(None),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Parser)]
@@ -8,6 +8,7 @@ mod lint;
 mod output;
 mod persisted_queries;
 mod profile;
+mod retry;
 
 mod schema;
 mod subgraph;
@@ -23,6 +24,7 @@ pub(crate) use lint::*;
 pub(crate) use output::*;
 pub(crate) use persisted_queries::*;
 pub(crate) use profile::*;
+pub(crate) use retry::*;
 pub(crate) use schema::*;
 pub(crate) use subgraph::*;
 pub(crate) use template::*;
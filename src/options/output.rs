@@ -4,7 +4,7 @@ use std::{
     path,
 };
 
-use calm_io::{stderrln, stdoutln};
+use calm_io::{stderr, stderrln, stdoutln};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use rover_std::{Fs, Style};
@@ -20,10 +20,21 @@ pub trait RoverPrinter {
 
 impl RoverPrinter for RoverOutput {
     fn write_or_print(&self, output_opts: &OutputOpts) -> RoverResult<()> {
-        // Format the RoverOutput as either plain text or JSON.
+        // Format the RoverOutput as plain text, JSON, or a report of a check or lint result.
         let output = match output_opts.format_kind {
             RoverOutputFormatKind::Plain => self.get_stdout(),
             RoverOutputFormatKind::Json => Ok(Some(JsonOutput::from(self).to_string())),
+            format_kind => match self.get_report(format_kind) {
+                Some(report) => Ok(Some(report)),
+                None => {
+                    stderrln!(
+                        "{} --format {} doesn't apply to this command's output, printing plain output instead",
+                        Style::WarningPrefix.paint("WARN:"),
+                        format_kind
+                    )?;
+                    self.get_stdout()
+                }
+            },
         };
 
         // Print the RoverOutput to file or stdout.
@@ -59,6 +70,24 @@ impl RoverPrinter for RoverError {
     fn write_or_print(&self, output_opts: &OutputOpts) -> RoverResult<()> {
         match output_opts.format_kind {
             RoverOutputFormatKind::Plain => self.print(),
+            format_kind @ (RoverOutputFormatKind::Sarif
+            | RoverOutputFormatKind::Junit
+            | RoverOutputFormatKind::Github
+            | RoverOutputFormatKind::Markdown) => match self.get_report(format_kind) {
+                Some(report) => {
+                    match &output_opts.output_file {
+                        Some(file) => {
+                            let success_heading = Style::Heading.paint("Report was printed to");
+                            Fs::write_file(file, report)?;
+                            stderrln!("{} {}", success_heading, file)?;
+                        }
+                        None => stdoutln!("{}", report)?,
+                    }
+                    stderr!("{}", self)?;
+                    Ok(())
+                }
+                None => self.print(),
+            },
             RoverOutputFormatKind::Json => {
                 let json = JsonOutput::from(self);
                 match &output_opts.output_file {
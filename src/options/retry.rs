@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use clap::Parser;
+use houston::ProfileSettings;
+use rover_http::{RetryConfig, RetryOn};
+
+/// How Rover retries failed requests to GraphOS and to the endpoints it introspects
+#[derive(Debug, Clone, Default, PartialEq, Eq, Parser)]
+pub struct RetryOpts {
+    /// The most times to send a request, including the first attempt. Pass `1` to never retry.
+    ///
+    /// Defaults to the profile's `retry_max_attempts` setting, or no limit other than
+    /// `--retry-max-elapsed`.
+    #[arg(
+        long = "retry-max-attempts",
+        value_name = "COUNT",
+        value_parser = clap::value_parser!(u32).range(1..),
+        global = true
+    )]
+    pub max_attempts: Option<u32>,
+
+    /// How long to keep retrying a request, like `90s` or `5m`.
+    ///
+    /// Defaults to the profile's `retry_max_elapsed` setting, or the client timeout.
+    #[arg(
+        long = "retry-max-elapsed",
+        value_name = "DURATION",
+        value_parser = humantime::parse_duration,
+        global = true
+    )]
+    pub max_elapsed: Option<Duration>,
+
+    /// The delay before the first retry, which doubles for every retry after that.
+    ///
+    /// Defaults to the profile's `retry_base_delay` setting, or `500ms`.
+    #[arg(
+        long = "retry-base-delay",
+        value_name = "DURATION",
+        value_parser = humantime::parse_duration,
+        global = true
+    )]
+    pub base_delay: Option<Duration>,
+
+    /// The longest delay between two attempts.
+    ///
+    /// Defaults to the profile's `retry_max_delay` setting, or `60s`.
+    #[arg(
+        long = "retry-max-delay",
+        value_name = "DURATION",
+        value_parser = humantime::parse_duration,
+        global = true
+    )]
+    pub max_delay: Option<Duration>,
+
+    /// Whether to randomly shorten delays, so many clients don't retry at the same moment.
+    ///
+    /// Defaults to the profile's `retry_jitter` setting, or `true`.
+    #[arg(long = "retry-jitter", value_name = "BOOL", global = true)]
+    pub jitter: Option<bool>,
+
+    /// Comma-separated status codes (`429`), classes (`5xx`), ranges (`500-504`) and errors
+    /// (`timeout`, `connect`, `request`, `body`, `decode`, `closed`) to retry.
+    ///
+    /// Defaults to the profile's `retry_on` setting, or every error and status but `400`.
+    #[arg(long = "retry-on", value_name = "LIST", global = true)]
+    pub retry_on: Option<RetryOn>,
+
+    /// Whether to wait as long as a response's `Retry-After` header asks before retrying.
+    ///
+    /// Defaults to the profile's `retry_honor_retry_after` setting, or `true`.
+    #[arg(long = "retry-honor-retry-after", value_name = "BOOL", global = true)]
+    pub honor_retry_after: Option<bool>,
+}
+
+impl RetryOpts {
+    /// Fills in anything that wasn't passed as a flag from a profile's settings
+    pub fn or_profile_settings(&self, settings: &ProfileSettings) -> Result<RetryOpts> {
+        let duration = |flag: Option<Duration>, key: &str, setting: &Option<String>| match flag {
+            Some(duration) => Ok(Some(duration)),
+            None => setting
+                .as_deref()
+                .map(humantime::parse_duration)
+                .transpose()
+                .with_context(|| format!("Invalid `{key}` profile setting")),
+        };
+        let retry_on = match &self.retry_on {
+            Some(retry_on) => Some(retry_on.clone()),
+            None => settings
+                .retry_on
+                .as_deref()
+                .map(str::parse::<RetryOn>)
+                .transpose()
+                .map_err(|err| anyhow!(err))
+                .context("Invalid `retry_on` profile setting")?,
+        };
+        Ok(RetryOpts {
+            max_attempts: self.max_attempts.or(settings.retry_max_attempts),
+            max_elapsed: duration(
+                self.max_elapsed,
+                "retry_max_elapsed",
+                &settings.retry_max_elapsed,
+            )?,
+            base_delay: duration(
+                self.base_delay,
+                "retry_base_delay",
+                &settings.retry_base_delay,
+            )?,
+            max_delay: duration(self.max_delay, "retry_max_delay", &settings.retry_max_delay)?,
+            jitter: self.jitter.or(settings.retry_jitter),
+            retry_on,
+            honor_retry_after: self.honor_retry_after.or(settings.retry_honor_retry_after),
+        })
+    }
+
+    /// The policy to retry with, stopping after `default_max_elapsed` unless
+    /// `--retry-max-elapsed` says otherwise
+    pub fn retry_config(&self, default_max_elapsed: Duration) -> RetryConfig {
+        let default = RetryConfig::default();
+        RetryConfig {
+            max_attempts: self.max_attempts,
+            max_elapsed: self.max_elapsed.unwrap_or(default_max_elapsed),
+            base_delay: self.base_delay.unwrap_or(default.base_delay),
+            max_delay: self.max_delay.unwrap_or(default.max_delay),
+            jitter: self.jitter.unwrap_or(default.jitter),
+            retry_on: self.retry_on.clone().unwrap_or(default.retry_on),
+            honor_retry_after: self.honor_retry_after.unwrap_or(default.honor_retry_after),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use clap::Parser;
+    use houston::ProfileSettings;
+
+    use super::RetryOpts;
+
+    #[test]
+    fn flags_take_precedence_over_profile_settings() {
+        let opts = RetryOpts::parse_from([
+            "retry",
+            "--retry-max-attempts",
+            "2",
+            "--retry-on",
+            "5xx,timeout",
+        ]);
+        let mut settings = ProfileSettings::default();
+        settings.set("retry_max_attempts", "5").unwrap();
+        settings.set("retry_base_delay", "2s").unwrap();
+        settings.set("retry_jitter", "false").unwrap();
+
+        let config = opts
+            .or_profile_settings(&settings)
+            .unwrap()
+            .retry_config(Duration::from_secs(30));
+
+        assert_eq!(config.max_attempts, Some(2));
+        assert_eq!(config.base_delay, Duration::from_secs(2));
+        assert!(!config.jitter);
+        assert_eq!(config.retry_on.to_string(), "5xx,timeout");
+        assert_eq!(config.max_elapsed, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn it_rejects_invalid_values() {
+        assert!(RetryOpts::try_parse_from(["retry", "--retry-max-attempts", "0"]).is_err());
+        assert!(RetryOpts::try_parse_from(["retry", "--retry-max-elapsed", "soon"]).is_err());
+        assert!(RetryOpts::try_parse_from(["retry", "--retry-on", "5xx,sometimes"]).is_err());
+
+        let mut settings = ProfileSettings::default();
+        settings.set("retry_on", "teapot").unwrap();
+        assert!(RetryOpts::default().or_profile_settings(&settings).is_err());
+    }
+}
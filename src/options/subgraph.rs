@@ -1,8 +1,13 @@
+use std::num::NonZeroUsize;
+
+use anyhow::anyhow;
 use camino::Utf8PathBuf;
 use clap::{self, Parser};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::{RoverError, RoverErrorSuggestion, RoverResult, utils::parsers::FileDescriptorType};
+
 #[derive(Debug, Clone, Serialize, Deserialize, Parser)]
 pub struct SubgraphOpt {
     /// The name of the subgraph.
@@ -53,3 +58,39 @@ pub struct OptionalSubgraphOpts {
     #[serde(skip_serializing)]
     pub subgraph_retries: u64,
 }
+
+#[derive(Debug, Clone, Serialize, Parser)]
+pub struct SubgraphBatchOpts {
+    /// Run this command for every subgraph in the supergraph config passed with
+    /// `--supergraph-config` instead of a single subgraph
+    #[arg(long, conflicts_with_all = ["subgraph_name", "schema"])]
+    pub all: bool,
+
+    /// The path to a supergraph config file listing the subgraphs to use with `--all`.
+    ///
+    /// Subgraph schemas are resolved the same way `rover supergraph compose` resolves them,
+    /// whether they come from a file, introspection, or inline SDL.
+    #[arg(long = "supergraph-config")]
+    #[serde(skip_serializing)]
+    pub supergraph_config_path: Option<FileDescriptorType>,
+
+    /// The maximum number of subgraphs to work on at the same time when `--all` is passed
+    #[arg(long, default_value = "4")]
+    pub max_parallelism: NonZeroUsize,
+}
+
+impl SubgraphBatchOpts {
+    /// Returns the supergraph config to read subgraphs from, which `--all` can't do without
+    pub fn supergraph_config(&self) -> RoverResult<&FileDescriptorType> {
+        self.supergraph_config_path.as_ref().ok_or_else(|| {
+            let mut err = RoverError::new(anyhow!(
+                "--all needs a supergraph config to know which subgraphs to use"
+            ));
+            err.set_suggestion(RoverErrorSuggestion::Adhoc(
+                "Pass `--supergraph-config <PATH>`, or set `supergraph_config` in `.rover.toml`."
+                    .to_string(),
+            ));
+            err
+        })
+    }
+}
@@ -1,27 +1,36 @@
 use core::fmt;
 use std::{io, str::FromStr, time::Duration};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use camino::Utf8Path;
 use derive_getters::Getters;
 use houston as config;
 use reqwest::Client;
 use rover_client::blocking::StudioClient;
-use rover_http::{HttpService, ReqwestService};
+use rover_http::{
+    HttpService, HttpServiceConfig, HttpServiceFactory, ProxyConfig, ReqwestServiceFactory,
+    RetryConfig, TlsConfig,
+};
 use rover_studio::service::HttpStudioServiceLayer;
 use serde::Serialize;
 use tower::{ServiceBuilder, ServiceExt};
 use url::Url;
 
-use crate::{PKG_NAME, PKG_VERSION, options::ProfileOpt};
+use crate::{
+    PKG_NAME, PKG_VERSION,
+    options::{ProfileOpt, RetryOpts},
+};
 
 /// the Apollo graph registry's production API endpoint
 const STUDIO_PROD_API_ENDPOINT: &str = "https://api.apollographql.com/graphql";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClientBuilder {
     accept_invalid_certs: bool,
     accept_invalid_hostnames: bool,
     timeout: Option<std::time::Duration>,
+    tls: TlsConfig,
+    proxy: ProxyConfig,
 }
 
 impl Default for ClientBuilder {
@@ -31,36 +40,63 @@ impl Default for ClientBuilder {
 }
 
 impl ClientBuilder {
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             accept_invalid_certs: false,
             accept_invalid_hostnames: false,
             timeout: None,
+            tls: TlsConfig::default(),
+            proxy: ProxyConfig::default(),
         }
     }
 
-    pub const fn accept_invalid_certs(self, value: bool) -> Self {
-        Self {
-            accept_invalid_certs: value,
-            ..self
-        }
+    pub const fn accept_invalid_certs(mut self, value: bool) -> Self {
+        self.accept_invalid_certs = value;
+        self
     }
 
-    pub const fn accept_invalid_hostnames(self, value: bool) -> Self {
-        Self {
-            accept_invalid_hostnames: value,
-            ..self
-        }
+    pub const fn accept_invalid_hostnames(mut self, value: bool) -> Self {
+        self.accept_invalid_hostnames = value;
+        self
     }
 
-    pub const fn with_timeout(self, timeout: std::time::Duration) -> Self {
-        Self {
-            timeout: Some(timeout),
-            ..self
-        }
+    pub const fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
-    pub(crate) fn build(self) -> Result<Client> {
+    /// Certificates to trust and present on top of the system's, read with [`load_tls_config`]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub const fn tls_config(&self) -> &TlsConfig {
+        &self.tls
+    }
+
+    /// The proxy every request is sent through, unless its host is matched by `NO_PROXY`
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    pub const fn proxy_config(&self) -> &ProxyConfig {
+        &self.proxy
+    }
+
+    /// The same settings, for clients built by [`rover_http`]
+    pub(crate) fn http_service_config(&self) -> HttpServiceConfig {
+        HttpServiceConfig::builder()
+            .accept_invalid_certificates(self.accept_invalid_certs)
+            .accept_invalid_hostnames(self.accept_invalid_hostnames)
+            .and_timeout(self.timeout)
+            .tls(self.tls.clone())
+            .proxy(self.proxy.clone())
+            .build()
+    }
+
+    pub(crate) fn build(&self) -> Result<Client> {
         let mut builder = Client::builder()
             .gzip(true)
             .brotli(true)
@@ -71,7 +107,12 @@ impl ClientBuilder {
             builder = builder.timeout(timeout);
         }
 
-        let client = builder
+        let builder = self.proxy.apply(builder);
+
+        let client = self
+            .tls
+            .apply(builder)
+            .context("Could not load the TLS certificates")?
             .user_agent(format!("{PKG_NAME}/{PKG_VERSION}"))
             .build()?;
 
@@ -79,6 +120,38 @@ impl ClientBuilder {
     }
 }
 
+/// Reads the certificate files passed with `--ca-cert`, `--client-cert` and `--client-key`.
+/// This happens once, when the first client is built; every client after that shares the result.
+pub(crate) fn load_tls_config(
+    ca_cert: Option<&Utf8Path>,
+    client_cert: Option<&Utf8Path>,
+    client_key: Option<&Utf8Path>,
+) -> Result<TlsConfig> {
+    let ca_certificates = ca_cert
+        .map(|path| {
+            std::fs::read(path)
+                .with_context(|| format!("Could not read the CA certificate at {path}"))
+        })
+        .transpose()?;
+    let client_identity = client_cert
+        .map(|cert_path| {
+            let mut identity = std::fs::read(cert_path)
+                .with_context(|| format!("Could not read the client certificate at {cert_path}"))?;
+            if let Some(key_path) = client_key {
+                let key = std::fs::read(key_path)
+                    .with_context(|| format!("Could not read the client key at {key_path}"))?;
+                identity.push(b'\n');
+                identity.extend(key);
+            }
+            Ok::<_, anyhow::Error>(identity)
+        })
+        .transpose()?;
+    Ok(TlsConfig {
+        ca_certificates,
+        client_identity,
+    })
+}
+
 #[derive(Debug, Copy, Clone, Serialize)]
 pub struct ClientTimeout {
     duration: Duration,
@@ -132,16 +205,25 @@ pub struct StudioClientConfig {
     version: String,
     is_sudo: bool,
     client: Option<Client>,
-    client_timeout: ClientTimeout,
+    #[getter(skip)]
+    client_timeout: Option<ClientTimeout>,
+    #[getter(skip)]
+    retry_opts: RetryOpts,
+    /// Whether `uri` came from `$APOLLO_REGISTRY_URL` rather than the default
+    #[getter(skip)]
+    uri_overridden: bool,
 }
 
 impl StudioClientConfig {
+    /// `client_timeout` is `None` when `--client-timeout` wasn't passed, so that the profile's
+    /// `client_timeout` setting can be used instead.
     pub fn new(
         override_endpoint: Option<String>,
         config: config::Config,
         is_sudo: bool,
         client_builder: ClientBuilder,
-        client_timeout: ClientTimeout,
+        client_timeout: Option<ClientTimeout>,
+        retry_opts: RetryOpts,
     ) -> StudioClientConfig {
         let version = if cfg!(debug_assertions) {
             format!("{PKG_VERSION} (dev)")
@@ -150,6 +232,7 @@ impl StudioClientConfig {
         };
 
         StudioClientConfig {
+            uri_overridden: override_endpoint.is_some(),
             uri: override_endpoint.unwrap_or_else(|| STUDIO_PROD_API_ENDPOINT.to_string()),
             config,
             version,
@@ -157,6 +240,7 @@ impl StudioClientConfig {
             is_sudo,
             client: None,
             client_timeout,
+            retry_opts,
         }
     }
 
@@ -169,42 +253,137 @@ impl StudioClientConfig {
         }
     }
 
+    /// Creates services that share this config's client and TLS settings
+    pub fn service_factory(&self) -> Result<ReqwestServiceFactory> {
+        Ok(ReqwestServiceFactory::builder()
+            .config(self.client_builder.http_service_config())
+            .client(self.get_reqwest_client()?)
+            .build()?)
+    }
+
     pub fn service(&self) -> Result<HttpService> {
-        let client = self.get_reqwest_client()?;
-        Ok(ReqwestService::builder()
-            .client(client)
-            .build()?
-            .boxed_clone())
+        Ok(self.service_factory()?.create()?)
+    }
+
+    pub fn client_timeout(&self) -> ClientTimeout {
+        self.client_timeout.unwrap_or_default()
+    }
+
+    /// Fills in anything that wasn't set with a flag or environment variable from the settings
+    /// saved for a profile with `rover config set`.
+    pub fn with_profile_settings(&self, profile_opt: &ProfileOpt) -> Result<StudioClientConfig> {
+        let settings = config::Profile::get_settings(&profile_opt.profile_name, &self.config)?;
+        let mut client_config = self.clone();
+
+        if !self.uri_overridden
+            && let Some(registry_url) = &settings.registry_url
+        {
+            client_config.uri = registry_url.clone();
+        }
+
+        let mut client_builder = self.client_builder.clone();
+        if self.client_timeout.is_none()
+            && let Some(seconds) = settings.client_timeout
+        {
+            let client_timeout = ClientTimeout::new(seconds);
+            client_config.client_timeout = Some(client_timeout);
+            client_builder = client_builder.with_timeout(client_timeout.get_duration());
+        }
+        if settings.accept_invalid_certs == Some(true) {
+            client_builder = client_builder.accept_invalid_certs(true);
+        }
+        if settings.accept_invalid_hostnames == Some(true) {
+            client_builder = client_builder.accept_invalid_hostnames(true);
+        }
+        client_config.retry_opts = self.retry_opts.or_profile_settings(&settings)?;
+        if let Some(proxy) = settings.proxy {
+            let proxy = rover_http::proxy::parse_proxy_url(&proxy)?;
+            let proxy_config = client_builder.proxy_config().clone().or_proxy(Some(proxy));
+            client_builder = client_builder.proxy(proxy_config);
+        }
+        if client_builder != self.client_builder {
+            client_config.client_builder = client_builder;
+            client_config.client = None;
+        }
+
+        Ok(client_config)
     }
 
     pub fn get_authenticated_client(&self, profile_opt: &ProfileOpt) -> Result<StudioClient> {
-        let credential = config::Profile::get_credential(&profile_opt.profile_name, &self.config)?;
+        let client_config = self.with_profile_settings(profile_opt)?;
+        let credential =
+            config::Profile::get_credential(&profile_opt.profile_name, &client_config.config)?;
         Ok(StudioClient::new(
             credential,
-            &self.uri,
-            &self.version,
-            self.is_sudo,
-            self.get_reqwest_client()?,
-            self.client_timeout.get_duration(),
+            &client_config.uri,
+            &client_config.version,
+            client_config.is_sudo,
+            client_config.get_reqwest_client()?,
+            client_config.retry_config(),
         ))
     }
 
     pub fn authenticated_service(&self, profile_opt: &ProfileOpt) -> Result<HttpService> {
-        let client = self.get_reqwest_client()?;
-        let credential = config::Profile::get_credential(&profile_opt.profile_name, &self.config)?;
+        let client_config = self.with_profile_settings(profile_opt)?;
+        let credential =
+            config::Profile::get_credential(&profile_opt.profile_name, &client_config.config)?;
         let service = ServiceBuilder::new()
             .layer(HttpStudioServiceLayer::new(
-                Url::from_str(&self.uri)?,
+                Url::from_str(&client_config.uri)?,
                 credential,
-                self.version.clone(),
-                self.is_sudo,
+                client_config.version.clone(),
+                client_config.is_sudo,
             )?)
-            .service(ReqwestService::builder().client(client).build()?)
+            .service(client_config.service_factory()?.create()?)
             .boxed_clone();
         Ok(service)
     }
 
-    pub const fn retry_period(&self) -> Duration {
-        self.client_timeout.get_duration()
+    /// How failed requests are retried, giving up after the client timeout unless
+    /// `--retry-max-elapsed` or the profile's `retry_max_elapsed` setting says otherwise
+    pub fn retry_config(&self) -> RetryConfig {
+        self.retry_opts
+            .retry_config(self.client_timeout().get_duration())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::{TempDir, prelude::*};
+    use camino::Utf8PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn it_appends_the_client_key_to_the_client_certificate() {
+        let temp_dir = TempDir::new().unwrap();
+        let cert = temp_dir.child("client.pem");
+        cert.write_str("CERTIFICATE").unwrap();
+        let key = temp_dir.child("client.key");
+        key.write_str("KEY").unwrap();
+        let cert = Utf8PathBuf::try_from(cert.to_path_buf()).unwrap();
+        let key = Utf8PathBuf::try_from(key.to_path_buf()).unwrap();
+
+        let tls = load_tls_config(None, Some(&cert), Some(&key)).unwrap();
+
+        assert_eq!(tls.ca_certificates, None);
+        assert_eq!(tls.client_identity, Some(b"CERTIFICATE\nKEY".to_vec()));
+    }
+
+    #[test]
+    fn it_errors_on_a_missing_ca_certificate() {
+        let err = load_tls_config(Some(Utf8Path::new("missing-ca.pem")), None, None).unwrap_err();
+        assert!(err.to_string().contains("missing-ca.pem"));
+    }
+
+    #[test]
+    fn it_passes_tls_settings_to_http_services() {
+        let tls = TlsConfig {
+            ca_certificates: Some(b"CA".to_vec()),
+            client_identity: None,
+        };
+        let client_builder = ClientBuilder::new().tls(tls.clone());
+
+        assert_eq!(client_builder.http_service_config().tls(), &Some(tls));
     }
 }
@@ -47,7 +47,7 @@ impl IntrospectSubgraph for StudioClientConfig {
                 headers,
                 endpoint: endpoint.clone(),
                 should_retry: false,
-                retry_period: self.retry_period(),
+                retry: self.retry_config(),
             },
             &client,
         )
@@ -70,9 +70,12 @@ mod test {
     use speculoos::prelude::*;
 
     use super::IntrospectSubgraph;
-    use crate::utils::{
-        client::{ClientBuilder, ClientTimeout, StudioClientConfig},
-        effect::test::SUBGRAPH_INTROSPECTION_QUERY,
+    use crate::{
+        options::RetryOpts,
+        utils::{
+            client::{ClientBuilder, ClientTimeout, StudioClientConfig},
+            effect::test::SUBGRAPH_INTROSPECTION_QUERY,
+        },
     };
 
     #[fixture]
@@ -115,13 +118,15 @@ mod test {
         let config = Config {
             home: Utf8PathBuf::from_path_buf(home.path().to_path_buf()).unwrap(),
             override_api_key: None,
+            credential_passphrase: None,
         };
         let studio_client_config = StudioClientConfig::new(
             None,
             config,
             false,
             ClientBuilder::default(),
-            ClientTimeout::default(),
+            Some(ClientTimeout::default()),
+            RetryOpts::default(),
         );
         let headers = HashMap::from_iter([("x-test-name".to_string(), "x-test-value".to_string())]);
         let result = studio_client_config
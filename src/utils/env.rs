@@ -65,6 +65,8 @@ impl RoverEnv {
     fn get_debug_value(key: RoverEnvKey, value: &str) -> String {
         let value = if matches!(key, RoverEnvKey::Key) {
             houston::mask_key(value)
+        } else if matches!(key, RoverEnvKey::CredentialsPassphrase) {
+            "*".repeat(value.len())
         } else {
             value.to_string()
         };
@@ -93,10 +95,16 @@ impl RoverEnv {
 #[derive(Debug, Copy, Clone, EnumIter)]
 pub enum RoverEnvKey {
     ConfigHome,
+    CredentialsPassphrase,
+    FederationVersion,
     FireFlower,
+    GraphRef,
     Home,
     Key,
+    Profile,
     RegistryUrl,
+    RouterConfig,
+    SupergraphConfig,
     TelemetryUrl,
     TelemetryDisabled,
     VcsRemoteUrl,
@@ -3,6 +3,8 @@ pub mod effect;
 pub mod env;
 pub mod parsers;
 pub mod pkg;
+pub mod project_config;
+pub mod sdl;
 pub mod service;
 pub mod stringify;
 pub mod table;
@@ -0,0 +1,626 @@
+//! Project-level defaults read from a `.rover.toml` file.
+//!
+//! Rover looks for `.rover.toml` in the current directory and each of its parents, stopping at
+//! the root of the git repository. Values in the file are used as defaults for the matching
+//! command line options, so flags take precedence over them. Inside a project, environment
+//! variables like `$APOLLO_PROFILE` take precedence over both.
+
+use std::{collections::HashMap, ffi::OsString, sync::OnceLock};
+
+use anyhow::{Context, anyhow};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{Arg, ArgMatches, Command, builder::ArgPredicate, parser::ValueSource};
+use rover_std::Fs;
+use serde::{Deserialize, Serialize};
+
+use crate::{RoverError, RoverErrorSuggestion, RoverResult, utils::env::RoverEnvKey};
+
+pub const PROJECT_CONFIG_FILE_NAME: &str = ".rover.toml";
+
+const INVALID_CONFIG_SUGGESTION: &str = "Fix or remove the file. Supported keys are `profile`, `graph_ref`, `supergraph_config`, `federation_version`, `router_config`, and a `[check]` table.";
+
+/// The project config for the current directory, or why it couldn't be loaded
+static CURRENT: OnceLock<Result<Option<ProjectConfig>, String>> = OnceLock::new();
+
+/// Where the command that's running got each of the options `.rover.toml` can set, by clap id
+static SOURCES: OnceLock<HashMap<&'static str, ValueSource>> = OnceLock::new();
+
+/// The contents of a `.rover.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// The path the file was loaded from.
+    #[serde(skip)]
+    pub path: Utf8PathBuf,
+
+    /// Default for `--profile`
+    pub profile: Option<String>,
+
+    /// Default for the `GRAPH_REF` argument of graph and subgraph commands
+    pub graph_ref: Option<String>,
+
+    /// Default for `--supergraph-config` and `supergraph compose --config`
+    pub supergraph_config: Option<Utf8PathBuf>,
+
+    /// Default for `--federation-version`
+    pub federation_version: Option<String>,
+
+    /// Default for `rover dev --router-config`
+    pub router_config: Option<Utf8PathBuf>,
+
+    /// Defaults for `graph check` and `subgraph check`
+    #[serde(default)]
+    pub check: CheckDefaults,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckDefaults {
+    pub query_count_threshold: Option<i64>,
+    pub query_percentage_threshold: Option<i64>,
+    pub validation_period: Option<String>,
+    pub background: Option<bool>,
+}
+
+/// A command line option that `.rover.toml` can provide a default for.
+struct ArgDefault {
+    /// The option's key in `.rover.toml`
+    key: &'static str,
+    /// The clap ids of the arguments this default applies to
+    ids: &'static [&'static str],
+    env: Option<RoverEnvKey>,
+    value: Option<String>,
+}
+
+/// A resolved option, along with where its value came from.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EffectiveValue {
+    pub key: String,
+    pub value: Option<String>,
+    pub source: String,
+}
+
+impl ProjectConfig {
+    /// Looks for `.rover.toml`, starting at `dir` and walking up to the git root.
+    pub fn discover(dir: &Utf8Path) -> RoverResult<Option<Self>> {
+        for dir in dir.ancestors() {
+            let path = dir.join(PROJECT_CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Self::load(&path).map(Some);
+            }
+            if dir.join(".git").exists() {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Loads a `.rover.toml`, resolving relative paths against the directory it lives in.
+    pub fn load(path: &Utf8Path) -> RoverResult<Self> {
+        let contents = Fs::read_file(path)?;
+        let mut config: Self = toml::from_str(&contents)
+            .map_err(|e| invalid_config(format!("Could not parse {path}: {e}")))?;
+        let root = path.parent().unwrap_or_else(|| Utf8Path::new("."));
+        config.supergraph_config = config
+            .supergraph_config
+            .map(|config_path| resolve_path(root, config_path));
+        config.router_config = config
+            .router_config
+            .map(|config_path| resolve_path(root, config_path));
+        config.path = path.to_path_buf();
+        Ok(config)
+    }
+
+    /// Discovers the project config for the current directory. This is run once, before the
+    /// command line is parsed. A `.rover.toml` that can't be loaded isn't an error yet, since
+    /// commands like `rover --help` don't need anything from it.
+    pub fn init() {
+        let config = std::env::current_dir()
+            .context("Could not read the current directory")
+            .and_then(|cwd| Ok(Utf8PathBuf::try_from(cwd)?))
+            .map_err(RoverError::new)
+            .and_then(|cwd| Self::discover(&cwd))
+            .map_err(|e| e.message());
+        CURRENT.get_or_init(|| config);
+    }
+
+    /// The project config found by [`ProjectConfig::init`], if any.
+    pub fn current() -> RoverResult<Option<&'static Self>> {
+        match CURRENT.get() {
+            Some(Ok(config)) => Ok(config.as_ref()),
+            Some(Err(message)) => Err(invalid_config(message.clone())),
+            None => Ok(None),
+        }
+    }
+
+    /// Sets the values in this file as defaults for the matching arguments of `command` and all
+    /// of its subcommands, along with the environment variables that override them.
+    pub fn apply(project_config: Option<&Self>, command: Command) -> Command {
+        match project_config {
+            Some(project_config) => apply_defaults(command, &project_config.arg_defaults()),
+            None => command,
+        }
+    }
+
+    /// Parses `args` with the defaults from the current project config.
+    ///
+    /// Environment variables take precedence over flags, so the command line is parsed again
+    /// without any flag that an environment variable overrides. If the project config couldn't
+    /// be loaded, that's only an error for commands that would have used one of its values.
+    pub fn get_matches(command: Command, args: Vec<OsString>) -> RoverResult<ArgMatches> {
+        let project_config = Self::current();
+        let command = Self::apply(project_config.as_ref().ok().copied().flatten(), command);
+        let matches = command.clone().get_matches_from(&args);
+        if let Err(err) = project_config {
+            if needs_project_config(&command, &matches) {
+                return Err(err);
+            }
+            return Ok(matches);
+        }
+
+        let overridden = overridden_flags(&command, &matches);
+        let matches = if overridden.is_empty() {
+            matches
+        } else {
+            command
+                .clone()
+                .get_matches_from(drop_flags(args, &overridden))
+        };
+        SOURCES.get_or_init(|| value_sources(&command, &matches));
+        Ok(matches)
+    }
+
+    fn arg_defaults(&self) -> Vec<ArgDefault> {
+        vec![
+            ArgDefault {
+                key: "profile",
+                ids: &["profile_name"],
+                env: Some(RoverEnvKey::Profile),
+                value: self.profile.clone(),
+            },
+            ArgDefault {
+                key: "supergraph_config",
+                ids: &["supergraph_config_path", "supergraph_yaml"],
+                env: Some(RoverEnvKey::SupergraphConfig),
+                value: self.supergraph_config.as_ref().map(ToString::to_string),
+            },
+            ArgDefault {
+                key: "federation_version",
+                ids: &["federation_version"],
+                env: Some(RoverEnvKey::FederationVersion),
+                value: self.federation_version.clone(),
+            },
+            ArgDefault {
+                key: "router_config",
+                ids: &["router_config_path"],
+                env: Some(RoverEnvKey::RouterConfig),
+                value: self.router_config.as_ref().map(ToString::to_string),
+            },
+            ArgDefault {
+                key: "check.query_count_threshold",
+                ids: &["query_count_threshold"],
+                env: None,
+                value: self.check.query_count_threshold.map(|n| n.to_string()),
+            },
+            ArgDefault {
+                key: "check.query_percentage_threshold",
+                ids: &["query_percentage_threshold"],
+                env: None,
+                value: self.check.query_percentage_threshold.map(|n| n.to_string()),
+            },
+            ArgDefault {
+                key: "check.validation_period",
+                ids: &["validation_period"],
+                env: None,
+                value: self.check.validation_period.clone(),
+            },
+            ArgDefault {
+                key: "check.background",
+                ids: &["background"],
+                env: None,
+                value: self.check.background.map(|b| b.to_string()),
+            },
+        ]
+    }
+
+    /// Describes where each option `.rover.toml` can set got its value, given the values the
+    /// running command resolved for them.
+    ///
+    /// `graph_ref` is resolved separately, since it falls back to the profile's settings.
+    pub fn effective_values(
+        project_config: Option<&Self>,
+        resolved: Vec<(&'static str, Option<String>)>,
+        mut graph_ref: Option<(String, String)>,
+        profile_name: &str,
+        config: &houston::Config,
+    ) -> RoverResult<Vec<EffectiveValue>> {
+        let settings = houston::Profile::get_settings(profile_name, config)?;
+        let defaults = project_config.cloned().unwrap_or_default().arg_defaults();
+
+        let mut values = Vec::new();
+        for (id, value) in resolved {
+            let Some(default) = defaults.iter().find(|default| default.ids.contains(&id)) else {
+                continue;
+            };
+            let source = match SOURCES.get().and_then(|sources| sources.get(id)) {
+                _ if value.is_none() => None,
+                Some(ValueSource::CommandLine) => Some("command line".to_string()),
+                Some(ValueSource::EnvVariable) => default.env.map(|env| format!("${env}")),
+                Some(ValueSource::DefaultValue) if default.value.is_some() => {
+                    project_config.map(file_source)
+                }
+                _ => Some("default".to_string()),
+            };
+            let (value, source) = match (value, source) {
+                (Some(value), Some(source)) => (Some(value), source),
+                // the federation version is the only other option with a profile setting
+                _ if default.key == "federation_version" => {
+                    match settings.federation_version.clone() {
+                        Some(value) => (Some(value), format!("profile setting ({profile_name})")),
+                        None => (None, "unset".to_string()),
+                    }
+                }
+                _ => (None, "unset".to_string()),
+            };
+            values.push(EffectiveValue {
+                key: default.key.to_string(),
+                value,
+                source,
+            });
+            if default.key == "profile" {
+                let (value, source) = match graph_ref.take() {
+                    Some((graph_ref, source)) => (Some(graph_ref), source),
+                    None => (None, "unset".to_string()),
+                };
+                values.push(EffectiveValue {
+                    key: "graph_ref".to_string(),
+                    value,
+                    source,
+                });
+            }
+        }
+        Ok(values)
+    }
+
+    /// Reads an environment variable, but only inside a project. Outside of one, options keep
+    /// their usual defaults no matter what's set in the environment.
+    pub fn env_value(key: RoverEnvKey) -> Option<String> {
+        match Self::current() {
+            Ok(Some(_)) => std::env::var(key.to_string()).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Where a value from `.rover.toml` came from, for `rover config show-effective`
+pub fn file_source(project_config: &ProjectConfig) -> String {
+    format!("{} ({})", PROJECT_CONFIG_FILE_NAME, project_config.path)
+}
+
+fn invalid_config(message: String) -> RoverError {
+    let mut err = RoverError::new(anyhow!(message));
+    err.set_suggestion(RoverErrorSuggestion::Adhoc(
+        INVALID_CONFIG_SUGGESTION.to_string(),
+    ));
+    err
+}
+
+/// Finds the subcommand that's running, along with its matches
+fn leaf<'a>(command: &'a Command, matches: &'a ArgMatches) -> (&'a Command, &'a ArgMatches) {
+    let (mut command, mut matches) = (command, matches);
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        let Some(subcommand) = command.find_subcommand(name) else {
+            break;
+        };
+        command = subcommand;
+        matches = sub_matches;
+    }
+    (command, matches)
+}
+
+/// The arguments of the running subcommand that `.rover.toml` can provide a default for
+fn project_args(command: &Command) -> impl Iterator<Item = &Arg> {
+    let defaults = ProjectConfig::default().arg_defaults();
+    command.get_arguments().filter(move |arg| {
+        !arg.is_positional()
+            && defaults
+                .iter()
+                .any(|default| default.ids.contains(&arg.get_id().as_str()))
+    })
+}
+
+/// Whether the running subcommand would have taken a default from `.rover.toml`
+fn needs_project_config(command: &Command, matches: &ArgMatches) -> bool {
+    let (command, matches) = leaf(command, matches);
+    project_args(command)
+        .any(|arg| matches.value_source(arg.get_id().as_str()) != Some(ValueSource::CommandLine))
+}
+
+/// The flags passed to the running subcommand that an environment variable overrides
+fn overridden_flags(command: &Command, matches: &ArgMatches) -> Vec<Arg> {
+    let (command, matches) = leaf(command, matches);
+    project_args(command)
+        .filter(|arg| {
+            arg.get_env()
+                .is_some_and(|env| std::env::var_os(env).is_some())
+                && matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Removes `flags` and their values from `args`, so they fall back to their environment variables
+fn drop_flags(args: Vec<OsString>, flags: &[Arg]) -> Vec<OsString> {
+    let mut kept = Vec::with_capacity(args.len());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            kept.push(arg);
+            kept.extend(args);
+            break;
+        }
+        let Some(token) = arg.to_str() else {
+            kept.push(arg);
+            continue;
+        };
+        let dropped = flags.iter().find_map(|flag| {
+            let long = flag.get_long().map(|long| format!("--{long}"));
+            let short = flag.get_short().map(|short| format!("-{short}"));
+            if long.as_deref() == Some(token) || short.as_deref() == Some(token) {
+                // the value is the next argument
+                Some(true)
+            } else if long.is_some_and(|long| token.starts_with(&format!("{long}=")))
+                || short.is_some_and(|short| token.starts_with(&short))
+            {
+                Some(false)
+            } else {
+                None
+            }
+        });
+        match dropped {
+            Some(true) => {
+                args.next();
+            }
+            Some(false) => {}
+            None => kept.push(arg),
+        }
+    }
+    kept
+}
+
+fn value_sources(command: &Command, matches: &ArgMatches) -> HashMap<&'static str, ValueSource> {
+    let (command, matches) = leaf(command, matches);
+    let defaults = ProjectConfig::default().arg_defaults();
+    defaults
+        .iter()
+        .flat_map(|default| default.ids.iter().copied())
+        .filter(|id| command.get_arguments().any(|arg| arg.get_id() == id))
+        .filter_map(|id| matches.value_source(id).map(|source| (id, source)))
+        .collect()
+}
+
+fn resolve_path(root: &Utf8Path, path: Utf8PathBuf) -> Utf8PathBuf {
+    // `-` reads the supergraph config from stdin
+    if path.is_absolute() || path == "-" {
+        path
+    } else {
+        root.join(path)
+    }
+}
+
+fn apply_defaults(mut command: Command, defaults: &[ArgDefault]) -> Command {
+    for default in defaults {
+        for id in default.ids {
+            let Some(arg) = command.get_arguments().find(|arg| arg.get_id() == id) else {
+                continue;
+            };
+            // positional arguments are never given defaults, even if their ids match
+            if arg.is_positional() {
+                continue;
+            }
+            // a default would trip up any argument this one conflicts with, so drop it when one
+            // of those is passed
+            let conflicts: Vec<String> = command
+                .get_arguments()
+                .filter(|other| other.get_id() != id)
+                .filter(|other| {
+                    command
+                        .get_arg_conflicts_with(arg)
+                        .iter()
+                        .any(|conflict| conflict.get_id() == other.get_id())
+                        || command
+                            .get_arg_conflicts_with(other)
+                            .iter()
+                            .any(|conflict| conflict.get_id() == id)
+                })
+                .map(|other| other.get_id().to_string())
+                .collect();
+            let groups: Vec<String> = command
+                .get_groups()
+                .filter(|group| group.is_required_set())
+                .filter(|group| group.get_args().any(|arg| arg == id))
+                .map(|group| group.get_id().to_string())
+                .collect();
+
+            command = command.mut_arg(id, |mut arg| {
+                if let Some(env) = default.env {
+                    arg = arg.env(env.to_string());
+                }
+                if let Some(value) = &default.value {
+                    arg = arg.default_value(value.clone());
+                    for conflict in &conflicts {
+                        arg = arg.default_value_if(
+                            conflict.clone(),
+                            ArgPredicate::IsPresent,
+                            None::<&str>,
+                        );
+                    }
+                }
+                arg
+            });
+            // defaults don't count towards a required group, so the file has to stand in for it
+            if default.value.is_some() {
+                for group in groups {
+                    command = command.mut_group(group, |group| group.required(false));
+                }
+            }
+        }
+    }
+
+    let subcommands: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    for subcommand in subcommands {
+        command = command.mut_subcommand(subcommand, |subcommand| {
+            apply_defaults(subcommand, defaults)
+        });
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::{TempDir, prelude::*};
+    use clap::{CommandFactory, FromArgMatches};
+    use speculoos::prelude::*;
+
+    use super::*;
+    use crate::cli::Rover;
+
+    fn temp_dir() -> (TempDir, Utf8PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let path = Utf8PathBuf::try_from(temp_dir.path().canonicalize().unwrap()).unwrap();
+        (temp_dir, path)
+    }
+
+    fn parse(project_config: &ProjectConfig, args: &[&str]) -> Rover {
+        let command = ProjectConfig::apply(Some(project_config), Rover::command());
+        let mut matches = command.try_get_matches_from(args).unwrap();
+        Rover::from_arg_matches_mut(&mut matches).unwrap()
+    }
+
+    #[test]
+    fn it_finds_a_config_in_a_parent_directory() {
+        let (temp_dir, root) = temp_dir();
+        temp_dir.child(".git").create_dir_all().unwrap();
+        temp_dir
+            .child(".rover.toml")
+            .write_str("profile = \"staging\"\nsupergraph_config = \"supergraph.yaml\"\n")
+            .unwrap();
+        temp_dir
+            .child("services/products")
+            .create_dir_all()
+            .unwrap();
+
+        let config = ProjectConfig::discover(&root.join("services/products"))
+            .unwrap()
+            .unwrap();
+        assert_that!(config.profile).is_equal_to(Some("staging".to_string()));
+        assert_that!(config.supergraph_config).is_equal_to(Some(root.join("supergraph.yaml")));
+        assert_that!(config.path).is_equal_to(root.join(".rover.toml"));
+    }
+
+    #[test]
+    fn it_stops_at_the_git_root() {
+        let (temp_dir, root) = temp_dir();
+        temp_dir
+            .child(".rover.toml")
+            .write_str("profile = \"staging\"\n")
+            .unwrap();
+        temp_dir.child("repo/.git").create_dir_all().unwrap();
+
+        assert_that!(ProjectConfig::discover(&root.join("repo")).unwrap()).is_none();
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        let (temp_dir, root) = temp_dir();
+        temp_dir
+            .child(".rover.toml")
+            .write_str("proflie = \"staging\"\n")
+            .unwrap();
+
+        assert_that!(ProjectConfig::load(&root.join(".rover.toml"))).is_err();
+    }
+
+    #[test]
+    fn flags_override_file_values() {
+        let project_config = ProjectConfig {
+            profile: Some("staging".to_string()),
+            ..Default::default()
+        };
+
+        let rover = parse(&project_config, &["rover", "config", "get"]);
+        assert_that!(format!("{rover:?}")).contains("profile_name: \"staging\"");
+
+        let rover = parse(
+            &project_config,
+            &["rover", "config", "get", "--profile", "prod"],
+        );
+        assert_that!(format!("{rover:?}")).contains("profile_name: \"prod\"");
+    }
+
+    #[test]
+    fn it_satisfies_required_groups() {
+        let project_config = ProjectConfig {
+            supergraph_config: Some(Utf8PathBuf::from("/tmp/supergraph.yaml")),
+            ..Default::default()
+        };
+
+        let rover = parse(&project_config, &["rover", "supergraph", "compose"]);
+        assert_that!(format!("{rover:?}")).contains("/tmp/supergraph.yaml");
+    }
+
+    #[test]
+    fn it_drops_overridden_flags_and_their_values() {
+        let command = Rover::command();
+        let profile = command
+            .find_subcommand("config")
+            .and_then(|config| config.find_subcommand("get"))
+            .and_then(|get| {
+                get.get_arguments()
+                    .find(|arg| arg.get_id() == "profile_name")
+            })
+            .unwrap()
+            .clone();
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+
+        assert_that!(drop_flags(
+            args(&[
+                "rover",
+                "config",
+                "get",
+                "--profile",
+                "prod",
+                "--format",
+                "json"
+            ]),
+            std::slice::from_ref(&profile),
+        ))
+        .is_equal_to(args(&["rover", "config", "get", "--format", "json"]));
+        assert_that!(drop_flags(
+            args(&["rover", "config", "get", "--profile=prod"]),
+            std::slice::from_ref(&profile),
+        ))
+        .is_equal_to(args(&["rover", "config", "get"]));
+        assert_that!(drop_flags(
+            args(&["rover", "config", "get", "--", "--profile"]),
+            std::slice::from_ref(&profile),
+        ))
+        .is_equal_to(args(&["rover", "config", "get", "--", "--profile"]));
+    }
+
+    #[test]
+    fn only_commands_using_the_file_need_it() {
+        let command = Rover::command();
+        let needs = |args: &[&str]| {
+            let matches = command.clone().try_get_matches_from(args).unwrap();
+            needs_project_config(&command, &matches)
+        };
+
+        assert_that!(needs(&["rover", "config", "get"])).is_true();
+        assert_that!(needs(&["rover", "config", "get", "--profile", "prod"])).is_false();
+        assert_that!(needs(&["rover", "docs", "list"])).is_false();
+    }
+}
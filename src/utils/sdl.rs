@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use apollo_parser::{Parser, SyntaxKind, SyntaxNode, cst::CstNode};
+use serde::Serialize;
+
+/// Reduces SDL to a canonical form so that schemas differing only in formatting compare equal.
+/// Whitespace, commas, and comments are dropped and top-level definitions are sorted. SDL that
+/// doesn't parse is left as-is.
+pub fn normalize_sdl(sdl: &str) -> String {
+    match definitions(sdl) {
+        Some(definitions) => {
+            let mut definitions: Vec<String> =
+                definitions.into_iter().map(|(_, tokens)| tokens).collect();
+            definitions.sort();
+            definitions.join("\n")
+        }
+        None => sdl.to_string(),
+    }
+}
+
+/// The top-level definitions that were added, removed, or changed between two schemas, by name
+#[derive(Debug, Default, Clone, Serialize, Eq, PartialEq)]
+pub struct SdlDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl SdlDiff {
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares two schemas definition by definition, ignoring the same formatting differences as
+/// [`normalize_sdl`]. A schema that doesn't parse is treated as empty.
+pub fn diff_sdl(before: &str, after: &str) -> SdlDiff {
+    let before = keyed_definitions(before);
+    let after = keyed_definitions(after);
+
+    let mut diff = SdlDiff::default();
+    for (key, tokens) in &after {
+        match before.get(key) {
+            None => diff.added.push(key.clone()),
+            Some(before_tokens) if before_tokens != tokens => diff.changed.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = before
+        .keys()
+        .filter(|key| !after.contains_key(*key))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Each definition keyed by its kind and name, like `type Product` or `directive @key`
+fn keyed_definitions(sdl: &str) -> BTreeMap<String, String> {
+    let mut keyed = BTreeMap::new();
+    for (key, tokens) in definitions(sdl).unwrap_or_default() {
+        // definitions without a name of their own, like schema extensions, can repeat
+        let mut unique_key = key.clone();
+        let mut count = 1;
+        while keyed.contains_key(&unique_key) {
+            count += 1;
+            unique_key = format!("{key} #{count}");
+        }
+        keyed.insert(unique_key, tokens);
+    }
+    keyed
+}
+
+/// Every top-level definition as `(key, tokens)`, or `None` if the SDL doesn't parse
+fn definitions(sdl: &str) -> Option<Vec<(String, String)>> {
+    let tree = Parser::new(sdl).parse();
+    if tree.errors().next().is_some() {
+        return None;
+    }
+    Some(
+        tree.document()
+            .definitions()
+            .map(|definition| {
+                let syntax = definition.syntax();
+                (definition_key(syntax), significant_tokens(syntax).join(" "))
+            })
+            .collect(),
+    )
+}
+
+/// The keywords and name that start a definition, skipping its description
+fn definition_key(definition: &SyntaxNode) -> String {
+    let mut key = Vec::new();
+    for token in definition
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+    {
+        if is_trivia(token.kind())
+            || token.parent().is_some_and(|parent| {
+                parent
+                    .ancestors()
+                    .any(|node| node.kind() == SyntaxKind::DESCRIPTION)
+            })
+        {
+            continue;
+        }
+        if token.kind() == SyntaxKind::L_CURLY {
+            break;
+        }
+        key.push(token.text().to_string());
+        if token
+            .parent()
+            .is_some_and(|parent| parent.kind() == SyntaxKind::NAME)
+        {
+            break;
+        }
+    }
+    key.join(" ").replace("@ ", "@")
+}
+
+fn significant_tokens(node: &SyntaxNode) -> Vec<String> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !is_trivia(token.kind()))
+        .map(|token| token.text().to_string())
+        .collect()
+}
+
+const fn is_trivia(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::COMMA
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_sdl_ignores_formatting_comments_and_ordering() {
+        let published = r#"
+            type Query {
+              products: [Product]
+            }
+
+            type Product @key(fields: "id") {
+              id: ID!
+              name: String
+            }
+        "#;
+        let reformatted = r#"
+            # products are keyed by id
+            type Product @key(fields: "id") { id: ID!, name: String }
+            type Query { products: [Product] }
+        "#;
+        let changed = r#"
+            type Query { products: [Product] }
+            type Product @key(fields: "id") { id: ID! name: String! }
+        "#;
+
+        assert_eq!(normalize_sdl(published), normalize_sdl(reformatted));
+        assert_ne!(normalize_sdl(published), normalize_sdl(changed));
+    }
+
+    #[test]
+    fn diff_sdl_reports_definitions_by_name() {
+        let before = r#"
+            directive @key(fields: String!) on OBJECT
+            type Query { products: [Product] }
+            type Product { id: ID! }
+            enum Color { RED }
+        "#;
+        let after = r#"
+            directive @key(fields: String!) on OBJECT
+            "The top-level query type"
+            type Query { products: [Product] }
+            type Product { id: ID! name: String }
+            type Review { body: String }
+        "#;
+
+        assert_eq!(
+            diff_sdl(before, after),
+            SdlDiff {
+                added: vec!["type Review".to_string()],
+                removed: vec!["enum Color".to_string()],
+                changed: vec!["type Product".to_string(), "type Query".to_string()],
+            }
+        );
+        assert!(diff_sdl(before, before).is_empty());
+    }
+}
@@ -81,12 +81,23 @@ impl Report for Rover {
 
     fn is_telemetry_enabled(&self) -> Result<bool, SputnikError> {
         let value = self.get_env_var(RoverEnvKey::TelemetryDisabled)?;
-        let is_telemetry_disabled = value.is_some();
+        // if the opt-out can't be read, assume it was set rather than report anyway
+        let is_telemetry_disabled = value.is_some()
+            || match self
+                .get_rover_config()
+                .and_then(|config| Ok(config.telemetry_settings()?))
+            {
+                Ok(settings) => settings.disabled,
+                Err(err) => {
+                    tracing::debug!("Could not read telemetry settings: {err}");
+                    true
+                }
+            };
         if is_telemetry_disabled {
             tracing::info!("Telemetry has been disabled.");
         } else {
             tracing::info!(
-                "Telemetry is enabled. To disable, set ${}=true",
+                "Telemetry is enabled. To disable, run `rover config telemetry disable` or set ${}=true",
                 RoverEnvKey::TelemetryDisabled.to_string()
             )
         }
@@ -118,6 +129,13 @@ impl Report for Rover {
     fn client(&self) -> anyhow::Result<Client, SputnikError> {
         self.get_reqwest_client().map_err(SputnikError::from)
     }
+
+    fn telemetry_log(&self) -> Option<Utf8PathBuf> {
+        self.get_rover_config()
+            .ok()
+            .and_then(|config| config.telemetry_settings().ok())
+            .and_then(|settings| settings.log)
+    }
 }
 
 #[cfg(test)]
@@ -2,12 +2,13 @@ use std::io::Cursor;
 
 use anyhow::{Result, anyhow};
 use camino::Utf8Path;
+use reqwest::Client;
 use rover_std::Fs;
 use url::Url;
 
-pub async fn download_template(url: Url, to_path: &Utf8Path) -> Result<()> {
+pub async fn download_template(client: &Client, url: Url, to_path: &Utf8Path) -> Result<()> {
     tracing::debug!("Downloading from {}", &url);
-    let res = reqwest::Client::new()
+    let res = client
         .get(url)
         .header(reqwest::header::ACCEPT, "application/octet-stream")
         .header(reqwest::header::USER_AGENT, "rover-client")
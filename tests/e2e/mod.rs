@@ -102,6 +102,7 @@ fn clone_retail_supergraph_repo() -> TempDir {
         let handle = tokio::runtime::Handle::current();
         handle.block_on(async {
             download_template(
+                &Client::new(),
                 "https://github.com/apollosolutions/retail-supergraph/archive/refs/heads/main.tar.gz"
                     .parse()
                     .unwrap(),
@@ -7,6 +7,7 @@ use rover_client::{
     blocking::GraphQLClient,
     operations::graph::introspect::{self, GraphIntrospectInput},
 };
+use rover_http::RetryConfig;
 use rover_std::Fs;
 
 const SCHEMA_PATH: &str = "./src/command/template/schema.graphql";
@@ -32,8 +33,14 @@ async fn introspect() -> Result<String> {
         "fetching the latest templates schema by introspecting {}...",
         &graphql_endpoint
     );
-    let graphql_client =
-        GraphQLClient::new(graphql_endpoint, Client::new(), Duration::from_secs(10));
+    let graphql_client = GraphQLClient::new(
+        graphql_endpoint,
+        Client::new(),
+        RetryConfig {
+            max_elapsed: Duration::from_secs(10),
+            ..Default::default()
+        },
+    );
     introspect::run(
         GraphIntrospectInput {
             headers: HashMap::new(),